@@ -0,0 +1,255 @@
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use reqwest::header::{ETAG, IF_NONE_MATCH};
+use reqwest::{RequestBuilder, Response, StatusCode};
+use serde_json::Value;
+
+use crate::error::Result;
+
+/// Default number of GET responses kept for ETag revalidation (`GITX_CACHE_ENTRIES`).
+pub const DEFAULT_CACHE_ENTRIES: usize = 128;
+
+/// How long a looked-up default branch is trusted before the repo is refetched.
+pub const DEFAULT_BRANCH_TTL: Duration = Duration::from_secs(300);
+
+/// Default branch of each `owner/repo` a client has looked up, remembered for
+/// [`DEFAULT_BRANCH_TTL`] so ref-less file and tree reads don't refetch the
+/// repo. Tools that rename or delete branches evict the entry themselves, so
+/// the TTL only covers changes made outside this server.
+#[derive(Debug)]
+pub(crate) struct DefaultBranchCache {
+    ttl: Duration,
+    branches: Mutex<HashMap<String, (String, Instant)>>,
+}
+
+impl Default for DefaultBranchCache {
+    fn default() -> Self {
+        Self::with_ttl(DEFAULT_BRANCH_TTL)
+    }
+}
+
+impl DefaultBranchCache {
+    pub(crate) fn with_ttl(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            branches: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The cached default branch of `owner/repo`, or the one in the repository
+    /// object returned by `fetch` once the entry is missing or expired.
+    pub(crate) async fn get_or_fetch<F, Fut>(&self, owner: &str, repo: &str, fetch: F) -> Result<String>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<Value>>,
+    {
+        let key = format!("{owner}/{repo}");
+        if let Some((branch, fetched)) = self.branches.lock().unwrap_or_else(|e| e.into_inner()).get(&key) {
+            if fetched.elapsed() < self.ttl {
+                return Ok(branch.clone());
+            }
+        }
+        let branch = default_branch_of(&fetch().await?);
+        self.branches
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(key, (branch.clone(), Instant::now()));
+        Ok(branch)
+    }
+
+    /// Drop the cached default branch of `owner/repo`.
+    pub(crate) fn invalidate(&self, owner: &str, repo: &str) {
+        self.branches
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&format!("{owner}/{repo}"));
+    }
+}
+
+/// The `default_branch` of a repository object, falling back to "main".
+pub(crate) fn default_branch_of(repo: &Value) -> String {
+    repo.get("default_branch")
+        .and_then(|v| v.as_str())
+        .unwrap_or("main")
+        .to_string()
+}
+
+/// Small LRU of GET responses keyed by full URL (including the query).
+///
+/// Entries are never served blindly: each hit is revalidated with
+/// `If-None-Match`, so a 304 saves the transfer (and, on GitHub, the rate
+/// limit) while changed resources are always fetched fresh.
+#[derive(Debug)]
+pub(crate) struct ResponseCache {
+    capacity: usize,
+    /// Most recently used first.
+    entries: Mutex<VecDeque<CacheEntry>>,
+}
+
+#[derive(Debug, Clone)]
+struct CacheEntry {
+    key: String,
+    etag: String,
+    body: Value,
+}
+
+impl ResponseCache {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// The cached ETag and body for `key`, marking it most recently used.
+    fn lookup(&self, key: &str) -> Option<(String, Value)> {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let pos = entries.iter().position(|e| e.key == key)?;
+        let entry = entries.remove(pos)?;
+        let hit = (entry.etag.clone(), entry.body.clone());
+        entries.push_front(entry);
+        Some(hit)
+    }
+
+    fn insert(&self, key: String, etag: String, body: Value) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.retain(|e| e.key != key);
+        entries.push_front(CacheEntry { key, etag, body });
+        entries.truncate(self.capacity);
+    }
+
+    /// Send a GET, revalidating a cached copy with `If-None-Match`.
+    ///
+    /// `handle` is the client's own response handling (status checks, JSON
+    /// parsing); it runs for every response except a 304.
+    pub(crate) async fn get<F, Fut>(&self, http: &reqwest::Client, builder: RequestBuilder, handle: F) -> Result<Value>
+    where
+        F: FnOnce(Response) -> Fut,
+        Fut: Future<Output = Result<Value>>,
+    {
+        if self.capacity == 0 {
+            return handle(builder.send().await?).await;
+        }
+
+        let mut request = builder.build()?;
+        let key = request.url().to_string();
+        let cached = self.lookup(&key);
+        if let Some((etag, _)) = &cached {
+            if let Ok(value) = etag.parse() {
+                request.headers_mut().insert(IF_NONE_MATCH, value);
+            }
+        }
+
+        let resp = http.execute(request).await?;
+        if resp.status() == StatusCode::NOT_MODIFIED {
+            if let Some((_, body)) = cached {
+                return Ok(body);
+            }
+        }
+
+        let etag = resp
+            .headers()
+            .get(ETAG)
+            .and_then(|v| v.to_str().ok())
+            .map(String::from);
+        let body = handle(resp).await?;
+        if let Some(etag) = etag {
+            self.insert(key, etag, body.clone());
+        }
+        Ok(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use axum::http::{HeaderMap, StatusCode as AxumStatus};
+    use axum::response::IntoResponse;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let cache = ResponseCache::new(2);
+        cache.insert("a".into(), "\"1\"".into(), Value::from(1));
+        cache.insert("b".into(), "\"2\"".into(), Value::from(2));
+        assert!(cache.lookup("a").is_some()); // "a" is now most recent
+        cache.insert("c".into(), "\"3\"".into(), Value::from(3));
+        assert!(cache.lookup("b").is_none());
+        assert_eq!(cache.lookup("a").map(|(_, v)| v), Some(Value::from(1)));
+    }
+
+    #[tokio::test]
+    async fn default_branch_is_fetched_once_per_repo() {
+        let cache = DefaultBranchCache::default();
+        let fetches = AtomicUsize::new(0);
+        let fetch = || async {
+            fetches.fetch_add(1, Ordering::SeqCst);
+            Ok(serde_json::json!({ "default_branch": "develop" }))
+        };
+        assert_eq!(cache.get_or_fetch("acme", "widgets", fetch).await.unwrap(), "develop");
+        assert_eq!(cache.get_or_fetch("acme", "widgets", fetch).await.unwrap(), "develop");
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+
+        let other = cache.get_or_fetch("acme", "gadgets", || async { Ok(serde_json::json!({})) }).await;
+        assert_eq!(other.unwrap(), "main");
+
+        cache.invalidate("acme", "widgets");
+        assert_eq!(cache.get_or_fetch("acme", "widgets", fetch).await.unwrap(), "develop");
+        assert_eq!(fetches.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn expired_default_branch_is_refetched() {
+        let cache = DefaultBranchCache::with_ttl(Duration::ZERO);
+        let fetches = AtomicUsize::new(0);
+        let fetch = || async {
+            fetches.fetch_add(1, Ordering::SeqCst);
+            Ok(serde_json::json!({ "default_branch": "trunk" }))
+        };
+        assert_eq!(cache.get_or_fetch("acme", "widgets", fetch).await.unwrap(), "trunk");
+        assert_eq!(cache.get_or_fetch("acme", "widgets", fetch).await.unwrap(), "trunk");
+        assert_eq!(fetches.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn not_modified_returns_cached_body() {
+        // Mock API: full body the first time, 304 once the client revalidates.
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+        let app = axum::Router::new().route(
+            "/labels",
+            axum::routing::get(move |headers: HeaderMap| {
+                let counter = counter.clone();
+                async move {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    if headers.get("if-none-match").and_then(|v| v.to_str().ok()) == Some("\"v1\"") {
+                        AxumStatus::NOT_MODIFIED.into_response()
+                    } else {
+                        ([("etag", "\"v1\"")], axum::Json(serde_json::json!([{ "name": "bug" }]))).into_response()
+                    }
+                }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/labels", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let http = reqwest::Client::new();
+        let cache = ResponseCache::new(8);
+        let handle = |resp: Response| async move {
+            assert_eq!(resp.status(), StatusCode::OK, "only full responses reach the handler");
+            Ok(resp.json::<Value>().await?)
+        };
+
+        let first = cache.get(&http, http.get(&url), handle).await.unwrap();
+        let second = cache.get(&http, http.get(&url), handle).await.unwrap();
+        assert_eq!(first, serde_json::json!([{ "name": "bug" }]));
+        assert_eq!(second, first);
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+    }
+}
@@ -0,0 +1,136 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::config::ToolSettings;
+use crate::error::{GitxError, Result};
+use crate::platform::Platform;
+
+use super::{GitClient, RawMedia};
+
+/// Client wrapper for `GITX_DRY_RUN`.
+///
+/// Reads go through to the wrapped client so tools can still look things up
+/// (SHAs, label IDs, ...). The first write is not sent; it fails with
+/// [`GitxError::DryRun`] describing the request instead, which the server
+/// reports back as a successful tool result.
+#[derive(Debug, Clone)]
+pub struct DryRunClient {
+    inner: Arc<dyn GitClient>,
+}
+
+impl DryRunClient {
+    pub fn new(inner: Arc<dyn GitClient>) -> Self {
+        Self { inner }
+    }
+}
+
+/// Describe a write that was not sent.
+fn preview(method: &str, path: &str, body: Option<&Value>) -> GitxError {
+    let text = match body {
+        Some(body) => format!(
+            "DRY RUN: would {method} to {path} with {}",
+            serde_json::to_string_pretty(body).unwrap_or_else(|_| body.to_string())
+        ),
+        None => format!("DRY RUN: would {method} {path}"),
+    };
+    GitxError::DryRun(text)
+}
+
+/// The method `post_no_content` is sent with: GitHub's merge endpoint takes a PUT.
+fn no_content_method(platform: Platform) -> &'static str {
+    match platform {
+        Platform::GitHub => "PUT",
+        Platform::Gitea | Platform::GitLab => "POST",
+    }
+}
+
+#[async_trait]
+impl GitClient for DryRunClient {
+    fn platform(&self) -> Platform {
+        self.inner.platform()
+    }
+
+    fn settings(&self) -> ToolSettings {
+        self.inner.settings()
+    }
+
+    async fn get_json(&self, path: &str) -> Result<Value> {
+        self.inner.get_json(path).await
+    }
+
+    async fn get_json_with_query(&self, path: &str, query: &[(&str, &str)]) -> Result<Value> {
+        self.inner.get_json_with_query(path, query).await
+    }
+
+    async fn get_raw(&self, path: &str, query: &[(&str, &str)], media: RawMedia) -> Result<String> {
+        self.inner.get_raw(path, query, media).await
+    }
+
+    async fn get_json_with_timeout(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+        timeout: Option<Duration>,
+    ) -> Result<Value> {
+        self.inner.get_json_with_timeout(path, query, timeout).await
+    }
+
+    async fn get_raw_with_timeout(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+        media: RawMedia,
+        timeout: Option<Duration>,
+    ) -> Result<String> {
+        self.inner.get_raw_with_timeout(path, query, media, timeout).await
+    }
+
+    async fn default_branch(&self, owner: &str, repo: &str) -> Result<String> {
+        self.inner.default_branch(owner, repo).await
+    }
+
+    async fn post_json(&self, path: &str, body: &Value) -> Result<Value> {
+        Err(preview("POST", path, Some(body)))
+    }
+
+    async fn post_no_content(&self, path: &str, body: &Value) -> Result<()> {
+        Err(preview(no_content_method(self.platform()), path, Some(body)))
+    }
+
+    async fn put_json(&self, path: &str, body: &Value) -> Result<Value> {
+        Err(preview("PUT", path, Some(body)))
+    }
+
+    async fn patch_json(&self, path: &str, body: &Value) -> Result<Value> {
+        Err(preview("PATCH", path, Some(body)))
+    }
+
+    async fn delete(&self, path: &str) -> Result<()> {
+        Err(preview("DELETE", path, None))
+    }
+
+    async fn delete_with_body(&self, path: &str, body: &Value) -> Result<()> {
+        Err(preview("DELETE", path, Some(body)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn preview_describes_the_request() {
+        let err = preview("POST", "/repos/o/r/issues", Some(&serde_json::json!({ "title": "Bug" })));
+        assert_eq!(err.to_string(), "DRY RUN: would POST to /repos/o/r/issues with {\n  \"title\": \"Bug\"\n}");
+        assert_eq!(preview("DELETE", "/repos/o/r/labels/3", None).to_string(), "DRY RUN: would DELETE /repos/o/r/labels/3");
+    }
+
+    #[test]
+    fn no_content_preview_uses_the_platform_method() {
+        assert_eq!(no_content_method(Platform::GitHub), "PUT");
+        assert_eq!(no_content_method(Platform::Gitea), "POST");
+    }
+}
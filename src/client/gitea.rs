@@ -1,18 +1,25 @@
+use std::sync::Arc;
+use std::time::Duration;
+
 use async_trait::async_trait;
 use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, AUTHORIZATION};
 use serde_json::Value;
 
-use crate::config::Config;
+use crate::config::{Config, ToolSettings};
 use crate::error::{GitxError, Result};
 use crate::platform::Platform;
 
-use super::GitClient;
+use super::cache::{DefaultBranchCache, ResponseCache};
+use super::{GitClient, RawMedia};
 
 /// HTTP client wrapper for the Gitea/Forgejo REST API v1.
 #[derive(Debug, Clone)]
 pub struct GiteaClient {
     http: reqwest::Client,
     base_api: String,
+    cache: Arc<ResponseCache>,
+    branches: Arc<DefaultBranchCache>,
+    settings: ToolSettings,
 }
 
 impl GiteaClient {
@@ -26,7 +33,7 @@ impl GiteaClient {
         );
         headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
 
-        let http = reqwest::Client::builder()
+        let http = super::http::client_builder(config)?
             .default_headers(headers)
             .build()
             .map_err(|e| GitxError::Api(format!("Failed to build HTTP client: {e}")))?;
@@ -34,6 +41,9 @@ impl GiteaClient {
         Ok(Self {
             http,
             base_api: format!("{}/api/v1", config.base_url),
+            cache: Arc::new(ResponseCache::new(config.cache_entries)),
+            branches: Arc::default(),
+            settings: config.tool_settings(),
         })
     }
 
@@ -56,9 +66,14 @@ impl GiteaClient {
         }
         if !status.is_success() {
             let body = resp.text().await.unwrap_or_default();
-            return Err(GitxError::Api(format!("HTTP {status}: {body}")));
+            return Err(GitxError::Status { status: status.as_u16(), body });
+        }
+        // 202/204 responses (dispatches, async jobs) carry no body.
+        let text = resp.text().await?;
+        if text.trim().is_empty() {
+            return Ok(Value::Null);
         }
-        let body = resp.json::<Value>().await?;
+        let body = serde_json::from_str::<Value>(&text)?;
         Ok(body)
     }
 }
@@ -69,29 +84,60 @@ impl GitClient for GiteaClient {
         Platform::Gitea
     }
 
+    fn settings(&self) -> ToolSettings {
+        self.settings
+    }
+
     async fn get_json(&self, path: &str) -> Result<Value> {
-        let resp = self.http.get(self.url(path)).send().await?;
-        self.handle_response(resp).await
+        let builder = self.http.get(self.url(path));
+        self.cache.get(&self.http, builder, |resp| self.handle_response(resp)).await
     }
 
     async fn get_json_with_query(&self, path: &str, query: &[(&str, &str)]) -> Result<Value> {
-        let resp = self
-            .http
-            .get(self.url(path))
-            .query(query)
-            .send()
-            .await?;
-        self.handle_response(resp).await
+        self.get_json_with_timeout(path, query, None).await
+    }
+
+    async fn get_json_with_timeout(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+        timeout: Option<Duration>,
+    ) -> Result<Value> {
+        let mut builder = self.http.get(self.url(path)).query(query);
+        if let Some(timeout) = timeout {
+            builder = builder.timeout(timeout);
+        }
+        self.cache.get(&self.http, builder, |resp| self.handle_response(resp)).await
+    }
+
+    async fn default_branch(&self, owner: &str, repo: &str) -> Result<String> {
+        self.branches
+            .get_or_fetch(owner, repo, || async { self.get_json(&format!("/repos/{owner}/{repo}")).await })
+            .await
     }
 
-    async fn get_raw(&self, path: &str) -> Result<String> {
+    fn forget_default_branch(&self, owner: &str, repo: &str) {
+        self.branches.invalidate(owner, repo);
+    }
+
+    async fn get_raw(&self, path: &str, query: &[(&str, &str)], media: RawMedia) -> Result<String> {
+        self.get_raw_with_timeout(path, query, media, None).await
+    }
+
+    // Gitea's raw, diff, and log endpoints each serve one type, so `media` needs no header of its own.
+    async fn get_raw_with_timeout(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+        _media: RawMedia,
+        timeout: Option<Duration>,
+    ) -> Result<String> {
         let url = self.url(path);
-        let resp = self
-            .http
-            .get(&url)
-            .header(ACCEPT, "text/plain")
-            .send()
-            .await?;
+        let mut builder = self.http.get(&url).query(query).header(ACCEPT, "text/plain");
+        if let Some(timeout) = timeout {
+            builder = builder.timeout(timeout);
+        }
+        let resp = builder.send().await?;
 
         let status = resp.status();
         if status == reqwest::StatusCode::UNAUTHORIZED
@@ -104,7 +150,7 @@ impl GitClient for GiteaClient {
         }
         if !status.is_success() {
             let body = resp.text().await.unwrap_or_default();
-            return Err(GitxError::Api(format!("HTTP {status}: {body}")));
+            return Err(GitxError::Status { status: status.as_u16(), body });
         }
         Ok(resp.text().await?)
     }
@@ -117,17 +163,20 @@ impl GitClient for GiteaClient {
     async fn post_no_content(&self, path: &str, body: &Value) -> Result<()> {
         let resp = self.http.post(self.url(path)).json(body).send().await?;
         let status = resp.status();
-        if status == reqwest::StatusCode::UNAUTHORIZED
-            || status == reqwest::StatusCode::FORBIDDEN
-        {
+        if status == reqwest::StatusCode::UNAUTHORIZED {
             return Err(GitxError::Auth);
         }
+        if status == reqwest::StatusCode::FORBIDDEN {
+            // Keep the body: merges report why they were refused with a 403.
+            let body = resp.text().await.unwrap_or_default();
+            return Err(GitxError::Status { status: 403, body });
+        }
         if status == reqwest::StatusCode::NOT_FOUND {
             return Err(GitxError::NotFound(self.url(path)));
         }
         if !status.is_success() {
             let body = resp.text().await.unwrap_or_default();
-            return Err(GitxError::Api(format!("HTTP {status}: {body}")));
+            return Err(GitxError::Status { status: status.as_u16(), body });
         }
         Ok(())
     }
@@ -155,7 +204,7 @@ impl GitClient for GiteaClient {
         }
         if !status.is_success() {
             let body = resp.text().await.unwrap_or_default();
-            return Err(GitxError::Api(format!("HTTP {status}: {body}")));
+            return Err(GitxError::Status { status: status.as_u16(), body });
         }
         Ok(())
     }
@@ -178,7 +227,7 @@ impl GitClient for GiteaClient {
         }
         if !status.is_success() {
             let text = resp.text().await.unwrap_or_default();
-            return Err(GitxError::Api(format!("HTTP {status}: {text}")));
+            return Err(GitxError::Status { status: status.as_u16(), body: text });
         }
         Ok(())
     }
@@ -1,18 +1,127 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
 use async_trait::async_trait;
 use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, AUTHORIZATION};
+use reqwest::{Method, RequestBuilder};
 use serde_json::Value;
+use tokio::sync::Mutex;
 
-use crate::config::Config;
+use crate::config::{Config, GitHubAppConfig, ToolSettings};
 use crate::error::{GitxError, Result};
 use crate::platform::Platform;
 
-use super::GitClient;
+use super::cache::{DefaultBranchCache, ResponseCache};
+use super::{GitClient, RawMedia};
+
+/// Longest `retry-after` a write waits out before retrying once; longer
+/// waits are returned to the caller as [`GitxError::RateLimited`].
+const MAX_SECONDARY_LIMIT_WAIT: u64 = 60;
+
+/// Installation tokens live for an hour; refresh a little before that.
+const INSTALLATION_TOKEN_LIFETIME: Duration = Duration::from_secs(55 * 60);
 
 /// HTTP client wrapper for the GitHub REST API.
 #[derive(Debug, Clone)]
 pub struct GitHubClient {
     http: reqwest::Client,
     base_api: String,
+    auth: Auth,
+    cache: Arc<ResponseCache>,
+    branches: Arc<DefaultBranchCache>,
+    settings: ToolSettings,
+}
+
+/// How requests are authenticated.
+#[derive(Debug, Clone)]
+enum Auth {
+    /// A static personal access token.
+    Token(String),
+    /// A GitHub App installation; tokens are minted on demand.
+    App(Arc<AppAuth>),
+}
+
+struct AppAuth {
+    app_id: String,
+    installation_id: String,
+    key: jsonwebtoken::EncodingKey,
+    /// Current installation token and when it should be replaced.
+    cached: Mutex<Option<(String, Instant)>>,
+}
+
+impl std::fmt::Debug for AppAuth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AppAuth")
+            .field("app_id", &self.app_id)
+            .field("installation_id", &self.installation_id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl AppAuth {
+    fn new(app: &GitHubAppConfig) -> Result<Self> {
+        let key = jsonwebtoken::EncodingKey::from_rsa_pem(app.private_key.as_bytes())
+            .map_err(|e| GitxError::MissingParam(format!("GITHUB_APP_PRIVATE_KEY is not a valid RSA PEM key: {e}")))?;
+        Ok(Self {
+            app_id: app.app_id.clone(),
+            installation_id: app.installation_id.clone(),
+            key,
+            cached: Mutex::new(None),
+        })
+    }
+
+    /// Sign a short-lived JWT identifying the app.
+    fn jwt(&self) -> Result<String> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        // Backdate `iat` to tolerate clock drift; GitHub caps `exp` at 10 minutes.
+        let claims = serde_json::json!({
+            "iat": now.saturating_sub(60),
+            "exp": now + 9 * 60,
+            "iss": self.app_id,
+        });
+        jsonwebtoken::encode(
+            &jsonwebtoken::Header::new(jsonwebtoken::Algorithm::RS256),
+            &claims,
+            &self.key,
+        )
+        .map_err(|e| GitxError::Api(format!("Failed to sign GitHub App JWT: {e}")))
+    }
+
+    /// Return a valid installation token, exchanging a fresh JWT when the
+    /// cached one is missing or about to expire.
+    async fn token(&self, http: &reqwest::Client, base_api: &str) -> Result<String> {
+        let mut cached = self.cached.lock().await;
+        if let Some((token, refresh_at)) = cached.as_ref() {
+            if Instant::now() < *refresh_at {
+                return Ok(token.clone());
+            }
+        }
+
+        let resp = http
+            .post(format!("{base_api}/app/installations/{}/access_tokens", self.installation_id))
+            .bearer_auth(self.jwt()?)
+            .send()
+            .await?;
+        let status = resp.status();
+        if !status.is_success() {
+            let body = resp.text().await.unwrap_or_default();
+            return Err(GitxError::Api(format!(
+                "Failed to obtain GitHub App installation token (HTTP {status}): {body}"
+            )));
+        }
+        let body: Value = resp.json().await?;
+        let token = body
+            .get("token")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| GitxError::Api("Installation token response had no token".to_string()))?
+            .to_string();
+
+        *cached = Some((token.clone(), Instant::now() + INSTALLATION_TOKEN_LIFETIME));
+        Ok(token)
+    }
 }
 
 impl GitHubClient {
@@ -20,13 +129,21 @@ impl GitHubClient {
     ///
     /// For github.com the base API is `https://api.github.com`.
     /// For GitHub Enterprise, it is `{base_url}/api/v3`.
+    ///
+    /// With `config.github_app` set, requests use GitHub App installation
+    /// tokens (refreshed lazily before expiry) instead of `config.token`.
     pub fn new(config: &Config) -> Result<Self> {
+        let auth = match &config.github_app {
+            Some(app) => Auth::App(Arc::new(AppAuth::new(app)?)),
+            None => {
+                // Reject tokens that can't be sent as a header up front.
+                HeaderValue::from_str(&format!("Bearer {}", config.token))
+                    .map_err(|e| GitxError::Api(format!("Invalid token header: {e}")))?;
+                Auth::Token(config.token.clone())
+            }
+        };
+
         let mut headers = HeaderMap::new();
-        headers.insert(
-            AUTHORIZATION,
-            HeaderValue::from_str(&format!("Bearer {}", config.token))
-                .map_err(|e| GitxError::Api(format!("Invalid token header: {e}")))?,
-        );
         headers.insert(
             ACCEPT,
             HeaderValue::from_static("application/vnd.github+json"),
@@ -36,9 +153,8 @@ impl GitHubClient {
             HeaderValue::from_static("2022-11-28"),
         );
 
-        let http = reqwest::Client::builder()
+        let http = super::http::client_builder(config)?
             .default_headers(headers)
-            .user_agent("gitx-mcp")
             .build()
             .map_err(|e| GitxError::Api(format!("Failed to build HTTP client: {e}")))?;
 
@@ -50,21 +166,82 @@ impl GitHubClient {
             format!("{}/api/v3", config.base_url)
         };
 
-        Ok(Self { http, base_api })
+        let cache = Arc::new(ResponseCache::new(config.cache_entries));
+        Ok(Self { http, base_api, auth, cache, branches: Arc::default(), settings: config.tool_settings() })
     }
 
     /// Build the full API URL for a given path.
     fn url(&self, path: &str) -> String {
+        // Enterprise serves GraphQL at {base_url}/api/graphql, beside (not under) /api/v3.
+        if path == "/graphql" {
+            if let Some(api_root) = self.base_api.strip_suffix("/v3") {
+                return format!("{api_root}/graphql");
+            }
+        }
         format!("{}{}", self.base_api, path)
     }
 
+    /// Start an authenticated request for `path`.
+    async fn request(&self, method: Method, path: &str) -> Result<RequestBuilder> {
+        let token = match &self.auth {
+            Auth::Token(token) => token.clone(),
+            Auth::App(app) => app.token(&self.http, &self.base_api).await?,
+        };
+        Ok(self
+            .http
+            .request(method, self.url(path))
+            .header(AUTHORIZATION, format!("Bearer {token}")))
+    }
+
+    /// A GET for raw text of kind `media`, with the matching `Accept` header.
+    async fn raw_request(&self, path: &str, query: &[(&str, &str)], media: RawMedia) -> Result<RequestBuilder> {
+        Ok(self.request(Method::GET, path).await?.query(query).header(ACCEPT, raw_accept(media)))
+    }
+
+    /// Run a write, retrying it once after the server-given delay when a GitHub
+    /// rate limit rejects it. Reads are never retried.
+    async fn retry_write<T, F, Fut>(&self, mut write: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: std::future::Future<Output = Result<T>>,
+    {
+        match write().await {
+            Err(GitxError::RateLimited { retry_after }) if retry_after <= MAX_SECONDARY_LIMIT_WAIT => {
+                tracing::warn!("GitHub rate limit hit; retrying in {retry_after}s");
+                tokio::time::sleep(Duration::from_secs(retry_after)).await;
+                write().await
+            }
+            other => other,
+        }
+    }
+
+    /// Check the status of a write whose response body is ignored.
+    async fn handle_no_content(&self, resp: reqwest::Response, path: &str) -> Result<()> {
+        let status = resp.status();
+        if status == reqwest::StatusCode::UNAUTHORIZED
+            || status == reqwest::StatusCode::FORBIDDEN
+            || status == reqwest::StatusCode::TOO_MANY_REQUESTS
+        {
+            return Err(auth_error(resp).await);
+        }
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Err(GitxError::NotFound(self.url(path)));
+        }
+        if !status.is_success() {
+            let body = resp.text().await.unwrap_or_default();
+            return Err(GitxError::Status { status: status.as_u16(), body });
+        }
+        Ok(())
+    }
+
     /// Handle a response: check status, deserialize JSON to Value.
     async fn handle_response(&self, resp: reqwest::Response) -> Result<Value> {
         let status = resp.status();
         if status == reqwest::StatusCode::UNAUTHORIZED
             || status == reqwest::StatusCode::FORBIDDEN
+            || status == reqwest::StatusCode::TOO_MANY_REQUESTS
         {
-            return Err(GitxError::Auth);
+            return Err(auth_error(resp).await);
         }
         if status == reqwest::StatusCode::NOT_FOUND {
             let url = resp.url().to_string();
@@ -72,132 +249,335 @@ impl GitHubClient {
         }
         if !status.is_success() {
             let body = resp.text().await.unwrap_or_default();
-            return Err(GitxError::Api(format!("HTTP {status}: {body}")));
+            return Err(GitxError::Status { status: status.as_u16(), body });
+        }
+        // 202/204 responses (dispatches, async jobs) carry no body.
+        let text = resp.text().await?;
+        if text.trim().is_empty() {
+            return Ok(Value::Null);
         }
-        let body = resp.json::<Value>().await?;
+        let body = serde_json::from_str::<Value>(&text)?;
         Ok(body)
     }
 }
 
+/// Map a 401/403/429 response to an error. An exhausted hourly budget is
+/// `RateLimited` until `x-ratelimit-reset`. Otherwise a 403 is either GitHub's
+/// secondary rate limit or a permission problem (typically a fine-grained
+/// token missing a scope); for the latter keep GitHub's explanation instead of
+/// the generic token hint.
+async fn auth_error(resp: reqwest::Response) -> GitxError {
+    let status = resp.status();
+    if status == reqwest::StatusCode::UNAUTHORIZED {
+        return GitxError::Auth;
+    }
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+    if let Some(retry_after) = primary_limit_wait(resp.headers(), now) {
+        return GitxError::RateLimited { retry_after };
+    }
+    let header = |name: &str| resp.headers().get(name).and_then(|v| v.to_str().ok()).map(str::to_string);
+    let accepted = header("x-accepted-github-permissions");
+    let retry_after = header("retry-after");
+    let body = resp.text().await.unwrap_or_default();
+    if let Some(retry_after) = secondary_limit_wait(retry_after.as_deref(), &body) {
+        return GitxError::RateLimited { retry_after };
+    }
+    if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        return GitxError::Status { status: 429, body };
+    }
+    GitxError::Status { status: 403, body: forbidden_detail(&body, accepted.as_deref()) }
+}
+
+/// Seconds to wait when a 403/429 is GitHub's secondary rate limit: its
+/// `retry-after` header, or a minute (GitHub's advice) when only the message
+/// identifies it. `None` for any other denial.
+fn secondary_limit_wait(retry_after: Option<&str>, body: &str) -> Option<u64> {
+    if let Some(secs) = retry_after.and_then(|v| v.trim().parse().ok()) {
+        return Some(secs);
+    }
+    body.to_ascii_lowercase().contains("secondary rate limit").then_some(60)
+}
+
+/// Seconds until the hourly budget resets when the response reports it
+/// exhausted (`x-ratelimit-remaining: 0`), counted from `now` (Unix seconds)
+/// to `x-ratelimit-reset`. A minute when the reset time is missing.
+fn primary_limit_wait(headers: &HeaderMap, now: u64) -> Option<u64> {
+    let header = |name: &str| headers.get(name).and_then(|v| v.to_str().ok()).map(str::trim);
+    if header("x-ratelimit-remaining") != Some("0") {
+        return None;
+    }
+    let wait = match header("x-ratelimit-reset").and_then(|v| v.parse::<u64>().ok()) {
+        Some(reset) => reset.saturating_sub(now),
+        None => 60,
+    };
+    Some(wait)
+}
+
+/// GitHub's `message` from a 403 body (falling back to the raw body), plus the
+/// permissions the endpoint accepts when GitHub reports them.
+fn forbidden_detail(body: &str, accepted_permissions: Option<&str>) -> String {
+    let message = serde_json::from_str::<Value>(body)
+        .ok()
+        .and_then(|v| v.get("message").and_then(|m| m.as_str()).map(str::to_string))
+        .unwrap_or_else(|| body.trim().to_string());
+    match accepted_permissions.filter(|p| !p.trim().is_empty()) {
+        Some(perms) => format!("{message} (token needs one of: {perms})"),
+        None => message,
+    }
+}
+
+/// The `Accept` header asking GitHub for `media`: raw file bytes, a unified
+/// diff, or the default type for endpoints such as job logs that redirect to text.
+fn raw_accept(media: RawMedia) -> &'static str {
+    match media {
+        RawMedia::File => "application/vnd.github.raw",
+        RawMedia::Diff => "application/vnd.github.diff",
+        RawMedia::Text => "application/vnd.github+json",
+    }
+}
+
 #[async_trait]
 impl GitClient for GitHubClient {
     fn platform(&self) -> Platform {
         Platform::GitHub
     }
 
+    fn settings(&self) -> ToolSettings {
+        self.settings
+    }
+
     async fn get_json(&self, path: &str) -> Result<Value> {
-        let resp = self.http.get(self.url(path)).send().await?;
-        self.handle_response(resp).await
+        let builder = self.request(Method::GET, path).await?;
+        self.cache.get(&self.http, builder, |resp| self.handle_response(resp)).await
     }
 
     async fn get_json_with_query(&self, path: &str, query: &[(&str, &str)]) -> Result<Value> {
-        let resp = self
-            .http
-            .get(self.url(path))
-            .query(query)
-            .send()
-            .await?;
-        self.handle_response(resp).await
+        self.get_json_with_timeout(path, query, None).await
+    }
+
+    async fn get_json_with_timeout(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+        timeout: Option<Duration>,
+    ) -> Result<Value> {
+        let mut builder = self.request(Method::GET, path).await?.query(query);
+        if let Some(timeout) = timeout {
+            builder = builder.timeout(timeout);
+        }
+        self.cache.get(&self.http, builder, |resp| self.handle_response(resp)).await
+    }
+
+    async fn default_branch(&self, owner: &str, repo: &str) -> Result<String> {
+        self.branches
+            .get_or_fetch(owner, repo, || async { self.get_json(&format!("/repos/{owner}/{repo}")).await })
+            .await
+    }
+
+    fn forget_default_branch(&self, owner: &str, repo: &str) {
+        self.branches.invalidate(owner, repo);
+    }
+
+    async fn get_raw(&self, path: &str, query: &[(&str, &str)], media: RawMedia) -> Result<String> {
+        self.get_raw_with_timeout(path, query, media, None).await
     }
 
-    async fn get_raw(&self, path: &str) -> Result<String> {
+    async fn get_raw_with_timeout(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+        media: RawMedia,
+        timeout: Option<Duration>,
+    ) -> Result<String> {
         let url = self.url(path);
-        let resp = self
-            .http
-            .get(&url)
-            .header(ACCEPT, "application/vnd.github.diff")
-            .send()
-            .await?;
+        let mut builder = self.raw_request(path, query, media).await?;
+        if let Some(timeout) = timeout {
+            builder = builder.timeout(timeout);
+        }
+        let resp = builder.send().await?;
 
         let status = resp.status();
         if status == reqwest::StatusCode::UNAUTHORIZED
             || status == reqwest::StatusCode::FORBIDDEN
+            || status == reqwest::StatusCode::TOO_MANY_REQUESTS
         {
-            return Err(GitxError::Auth);
+            return Err(auth_error(resp).await);
         }
         if status == reqwest::StatusCode::NOT_FOUND {
             return Err(GitxError::NotFound(url));
         }
         if !status.is_success() {
             let body = resp.text().await.unwrap_or_default();
-            return Err(GitxError::Api(format!("HTTP {status}: {body}")));
+            return Err(GitxError::Status { status: status.as_u16(), body });
         }
         Ok(resp.text().await?)
     }
 
     async fn post_json(&self, path: &str, body: &Value) -> Result<Value> {
-        let resp = self.http.post(self.url(path)).json(body).send().await?;
-        self.handle_response(resp).await
+        self.retry_write(|| async {
+            let resp = self.request(Method::POST, path).await?.json(body).send().await?;
+            self.handle_response(resp).await
+        })
+        .await
     }
 
     async fn post_no_content(&self, path: &str, body: &Value) -> Result<()> {
-        let resp = self.http.put(self.url(path)).json(body).send().await?;
-        let status = resp.status();
-        if status == reqwest::StatusCode::UNAUTHORIZED {
-            return Err(GitxError::Auth);
-        }
-        if status == reqwest::StatusCode::FORBIDDEN {
-            let body = resp.text().await.unwrap_or_default();
-            return Err(GitxError::Api(format!("Forbidden (403): {body}")));
-        }
-        if status == reqwest::StatusCode::NOT_FOUND {
-            return Err(GitxError::NotFound(self.url(path)));
-        }
-        if !status.is_success() {
-            let body = resp.text().await.unwrap_or_default();
-            return Err(GitxError::Api(format!("HTTP {status}: {body}")));
-        }
-        Ok(())
+        self.retry_write(|| async {
+            let resp = self.request(Method::PUT, path).await?.json(body).send().await?;
+            self.handle_no_content(resp, path).await
+        })
+        .await
     }
 
     async fn put_json(&self, path: &str, body: &Value) -> Result<Value> {
-        let resp = self.http.put(self.url(path)).json(body).send().await?;
-        self.handle_response(resp).await
+        self.retry_write(|| async {
+            let resp = self.request(Method::PUT, path).await?.json(body).send().await?;
+            self.handle_response(resp).await
+        })
+        .await
     }
 
     async fn patch_json(&self, path: &str, body: &Value) -> Result<Value> {
-        let resp = self.http.patch(self.url(path)).json(body).send().await?;
-        self.handle_response(resp).await
+        self.retry_write(|| async {
+            let resp = self.request(Method::PATCH, path).await?.json(body).send().await?;
+            self.handle_response(resp).await
+        })
+        .await
     }
 
     async fn delete(&self, path: &str) -> Result<()> {
-        let resp = self.http.delete(self.url(path)).send().await?;
-        let status = resp.status();
-        if status == reqwest::StatusCode::UNAUTHORIZED
-            || status == reqwest::StatusCode::FORBIDDEN
-        {
-            return Err(GitxError::Auth);
-        }
-        if status == reqwest::StatusCode::NOT_FOUND {
-            return Err(GitxError::NotFound(self.url(path)));
-        }
-        if !status.is_success() {
-            let body = resp.text().await.unwrap_or_default();
-            return Err(GitxError::Api(format!("HTTP {status}: {body}")));
-        }
-        Ok(())
+        self.retry_write(|| async {
+            let resp = self.request(Method::DELETE, path).await?.send().await?;
+            self.handle_no_content(resp, path).await
+        })
+        .await
     }
 
     async fn delete_with_body(&self, path: &str, body: &Value) -> Result<()> {
-        let resp = self
-            .http
-            .delete(self.url(path))
-            .json(body)
-            .send()
-            .await?;
-        let status = resp.status();
-        if status == reqwest::StatusCode::UNAUTHORIZED
-            || status == reqwest::StatusCode::FORBIDDEN
-        {
-            return Err(GitxError::Auth);
-        }
-        if status == reqwest::StatusCode::NOT_FOUND {
-            return Err(GitxError::NotFound(self.url(path)));
-        }
-        if !status.is_success() {
-            let text = resp.text().await.unwrap_or_default();
-            return Err(GitxError::Api(format!("HTTP {status}: {text}")));
-        }
-        Ok(())
+        self.retry_write(|| async {
+            let resp = self
+                .request(Method::DELETE, path)
+                .await?
+                .json(body)
+                .send()
+                .await?;
+            self.handle_no_content(resp, path).await
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_app_key_is_a_config_error() {
+        let mut config = Config::new("https://github.com".to_string(), String::new(), Platform::GitHub);
+        config.github_app = Some(GitHubAppConfig {
+            app_id: "1".to_string(),
+            private_key: "not a pem".to_string(),
+            installation_id: "2".to_string(),
+        });
+        let err = GitHubClient::new(&config).unwrap_err();
+        assert!(err.to_string().contains("GITHUB_APP_PRIVATE_KEY"));
+    }
+
+    #[test]
+    fn forbidden_keeps_githubs_explanation() {
+        let body = r#"{"message":"Resource not accessible by personal access token","documentation_url":"https://docs.github.com"}"#;
+        assert_eq!(
+            forbidden_detail(body, Some("issues=write")),
+            "Resource not accessible by personal access token (token needs one of: issues=write)"
+        );
+        assert_eq!(forbidden_detail("plain text", None), "plain text");
+    }
+
+    #[test]
+    fn exhausted_budget_waits_until_reset() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(primary_limit_wait(&headers, 1_000), None);
+        headers.insert("x-ratelimit-remaining", HeaderValue::from_static("12"));
+        assert_eq!(primary_limit_wait(&headers, 1_000), None);
+
+        headers.insert("x-ratelimit-remaining", HeaderValue::from_static("0"));
+        assert_eq!(primary_limit_wait(&headers, 1_000), Some(60));
+        headers.insert("x-ratelimit-reset", HeaderValue::from_static("1900"));
+        assert_eq!(primary_limit_wait(&headers, 1_000), Some(900));
+        assert_eq!(primary_limit_wait(&headers, 2_000), Some(0));
+    }
+
+    #[test]
+    fn secondary_rate_limit_is_recognized() {
+        assert_eq!(secondary_limit_wait(Some("30"), ""), Some(30));
+        let body = r#"{"message":"You have exceeded a secondary rate limit. Please wait a few minutes before you try again."}"#;
+        assert_eq!(secondary_limit_wait(None, body), Some(60));
+        assert_eq!(secondary_limit_wait(None, r#"{"message":"Resource not accessible by integration"}"#), None);
+    }
+
+    #[tokio::test]
+    async fn writes_retry_once_after_secondary_limit() {
+        let config = Config::new("https://github.com".to_string(), "t".to_string(), Platform::GitHub);
+        let client = GitHubClient::new(&config).unwrap();
+
+        let attempts = std::sync::atomic::AtomicUsize::new(0);
+        let result = client
+            .retry_write(|| async {
+                match attempts.fetch_add(1, std::sync::atomic::Ordering::SeqCst) {
+                    0 => Err(GitxError::RateLimited { retry_after: 0 }),
+                    _ => Ok("done"),
+                }
+            })
+            .await;
+        assert_eq!(result.unwrap(), "done");
+        assert_eq!(attempts.load(std::sync::atomic::Ordering::SeqCst), 2);
+
+        let attempts = std::sync::atomic::AtomicUsize::new(0);
+        let result: Result<()> = client
+            .retry_write(|| async {
+                attempts.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                Err(GitxError::RateLimited { retry_after: MAX_SECONDARY_LIMIT_WAIT + 1 })
+            })
+            .await;
+        assert!(matches!(result, Err(GitxError::RateLimited { .. })));
+        assert_eq!(attempts.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn raw_requests_take_accept_from_the_caller() {
+        let config = Config::new("https://github.com".to_string(), "t".to_string(), Platform::GitHub);
+        let client = GitHubClient::new(&config).unwrap();
+        let request = client
+            .raw_request("/repos/o/r/contents/docs/guide.md", &[("ref", "feature/x#2 & y")], RawMedia::File)
+            .await
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(
+            request.url().as_str(),
+            "https://api.github.com/repos/o/r/contents/docs/guide.md?ref=feature%2Fx%232+%26+y"
+        );
+        assert_eq!(request.headers()[ACCEPT], "application/vnd.github.raw");
+
+        // The media type no longer depends on the path.
+        let request = client
+            .raw_request("/repos/o/r/actions/jobs/7/logs", &[], RawMedia::Text)
+            .await
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(request.headers()[ACCEPT], "application/vnd.github+json");
+    }
+
+    #[test]
+    fn graphql_endpoint_per_host() {
+        let config = Config::new("https://github.com".to_string(), "t".to_string(), Platform::GitHub);
+        let client = GitHubClient::new(&config).unwrap();
+        assert_eq!(client.url("/graphql"), "https://api.github.com/graphql");
+
+        let config = Config::new("https://ghe.example.com".to_string(), "t".to_string(), Platform::GitHub);
+        let client = GitHubClient::new(&config).unwrap();
+        assert_eq!(client.url("/graphql"), "https://ghe.example.com/api/graphql");
+        assert_eq!(client.url("/user"), "https://ghe.example.com/api/v3/user");
     }
 }
@@ -0,0 +1,362 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::header::{HeaderMap, HeaderValue};
+use serde_json::Value;
+
+use crate::config::{Config, ToolSettings};
+use crate::error::{GitxError, Result};
+use crate::platform::Platform;
+
+use super::cache::DefaultBranchCache;
+use super::{GitClient, RawMedia};
+
+/// HTTP client wrapper for the GitLab REST API (`/api/v4`).
+///
+/// The tools speak GitHub/Gitea-shaped paths (`/repos/{owner}/{repo}/...`).
+/// This client rewrites the supported ones onto GitLab's project API
+/// (URL-encoded `owner/repo` as the project id, merge requests for pulls)
+/// and reshapes the responses so the existing formatters can read them.
+/// Support is read-only for now: repository metadata, issue lists, and
+/// merge request lists. Everything else fails with a clear message.
+#[derive(Debug, Clone)]
+pub struct GitLabClient {
+    http: reqwest::Client,
+    base_api: String,
+    branches: Arc<DefaultBranchCache>,
+    settings: ToolSettings,
+}
+
+/// The GitLab resources the path shim knows how to translate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Resource {
+    Project,
+    Issues,
+    MergeRequests,
+}
+
+impl GitLabClient {
+    /// Create a new GitLab client from configuration.
+    ///
+    /// The base API is `{base_url}/api/v4` for both gitlab.com and self-managed instances.
+    pub fn new(config: &Config) -> Result<Self> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "PRIVATE-TOKEN",
+            HeaderValue::from_str(&config.token)
+                .map_err(|e| GitxError::Api(format!("Invalid token header: {e}")))?,
+        );
+
+        let http = super::http::client_builder(config)?
+            .default_headers(headers)
+            .build()
+            .map_err(|e| GitxError::Api(format!("Failed to build HTTP client: {e}")))?;
+
+        let base_api = format!("{}/api/v4", config.base_url);
+        Ok(Self { http, base_api, branches: Arc::default(), settings: config.tool_settings() })
+    }
+
+    /// Build the full API URL for a given (already translated) path.
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_api, path)
+    }
+
+    /// Handle a response: check status, deserialize JSON to Value.
+    async fn handle_response(&self, resp: reqwest::Response) -> Result<Value> {
+        let status = resp.status();
+        if status == reqwest::StatusCode::UNAUTHORIZED
+            || status == reqwest::StatusCode::FORBIDDEN
+        {
+            return Err(GitxError::Auth);
+        }
+        if status == reqwest::StatusCode::NOT_FOUND {
+            let url = resp.url().to_string();
+            return Err(GitxError::NotFound(url));
+        }
+        if !status.is_success() {
+            let body = resp.text().await.unwrap_or_default();
+            return Err(GitxError::Status { status: status.as_u16(), body });
+        }
+        let text = resp.text().await?;
+        if text.trim().is_empty() {
+            return Ok(Value::Null);
+        }
+        let body = serde_json::from_str::<Value>(&text)?;
+        Ok(body)
+    }
+
+    async fn get_translated(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+        timeout: Option<Duration>,
+    ) -> Result<Value> {
+        let (gitlab_path, resource) = translate_path(path).ok_or_else(|| unsupported(path))?;
+        let query = translate_query(query);
+        let mut builder = self.http.get(self.url(&gitlab_path)).query(&query);
+        if let Some(timeout) = timeout {
+            builder = builder.timeout(timeout);
+        }
+        let resp = builder.send().await?;
+        let val = self.handle_response(resp).await?;
+        Ok(normalize(resource, val))
+    }
+}
+
+fn unsupported(path: &str) -> GitxError {
+    gitlab_unsupported(path)
+}
+
+/// The error for a tool (or API path) the GitLab shim cannot serve. Tools
+/// whose only non-Gitea implementation calls GitHub-specific endpoints
+/// return it instead of sending GitHub paths to GitLab.
+pub fn gitlab_unsupported(what: &str) -> GitxError {
+    GitxError::Api(format!(
+        "{what} is not supported on GitLab yet. GitLab support currently covers \
+         repo_get, issue_list, and pr_list (merge requests)."
+    ))
+}
+
+/// Rewrite `/repos/{owner}/{repo}[/issues|/pulls]` to the matching
+/// `/projects/{id}` path. Returns `None` for anything the shim can't handle.
+/// `owner` is a single segment, so projects in nested groups can't be
+/// addressed; `resolve_owner_repo` refuses an owner containing `/`.
+fn translate_path(path: &str) -> Option<(String, Resource)> {
+    let rest = path.strip_prefix("/repos/")?;
+    let (owner, rest) = rest.split_once('/')?;
+    let (repo, tail) = rest.split_once('/').unwrap_or((rest, ""));
+    if owner.is_empty() || repo.is_empty() {
+        return None;
+    }
+
+    let resource = match tail {
+        "" => Resource::Project,
+        "issues" => Resource::Issues,
+        "pulls" => Resource::MergeRequests,
+        _ => return None,
+    };
+
+    let id: String = url::form_urlencoded::byte_serialize(format!("{owner}/{repo}").as_bytes()).collect();
+    let path = match resource {
+        Resource::Project => format!("/projects/{id}"),
+        Resource::Issues => format!("/projects/{id}/issues"),
+        Resource::MergeRequests => format!("/projects/{id}/merge_requests"),
+    };
+    Some((path, resource))
+}
+
+/// Map Gitea/GitHub query parameters onto GitLab's names and values.
+fn translate_query(query: &[(&str, &str)]) -> Vec<(String, String)> {
+    query
+        .iter()
+        .filter_map(|(key, value)| match (*key, *value) {
+            ("limit", v) => Some(("per_page".to_string(), v.to_string())),
+            ("state", "open") => Some(("state".to_string(), "opened".to_string())),
+            // GitLab returns every state when `state` is omitted.
+            ("state", "all") => None,
+            ("milestones", v) => Some(("milestone".to_string(), v.to_string())),
+            ("base", v) => Some(("target_branch".to_string(), v.to_string())),
+            ("head", v) => Some(("source_branch".to_string(), v.to_string())),
+            ("sort", "created") => Some(("order_by".to_string(), "created_at".to_string())),
+            ("sort", "updated") => Some(("order_by".to_string(), "updated_at".to_string())),
+            // GitLab has no comment-count or popularity order for merge requests.
+            ("sort", _) => None,
+            ("direction", v) => Some(("sort".to_string(), v.to_string())),
+            // Gitea's issue/PR discriminator; GitLab keeps them in separate endpoints.
+            ("type", _) => None,
+            (k, v) => Some((k.to_string(), v.to_string())),
+        })
+        .collect()
+}
+
+/// Add the GitHub-style fields the formatters read to a GitLab response.
+/// The original GitLab fields are kept so `format: "json"` output stays complete.
+fn normalize(resource: Resource, val: Value) -> Value {
+    match val {
+        Value::Array(items) => Value::Array(items.into_iter().map(|v| normalize(resource, v)).collect()),
+        Value::Object(mut obj) => {
+            let copy = |obj: &serde_json::Map<String, Value>, from: &str| obj.get(from).cloned().unwrap_or(Value::Null);
+            obj.insert("html_url".to_string(), copy(&obj, "web_url"));
+
+            match resource {
+                Resource::Project => {
+                    obj.insert("full_name".to_string(), copy(&obj, "path_with_namespace"));
+                    obj.insert("stars_count".to_string(), copy(&obj, "star_count"));
+                    let private = obj.get("visibility").and_then(|v| v.as_str()) != Some("public");
+                    obj.insert("private".to_string(), Value::Bool(private));
+                }
+                Resource::Issues | Resource::MergeRequests => {
+                    obj.insert("number".to_string(), copy(&obj, "iid"));
+                    obj.insert("body".to_string(), copy(&obj, "description"));
+                    if obj.get("state").and_then(|v| v.as_str()) == Some("opened") {
+                        obj.insert("state".to_string(), Value::String("open".to_string()));
+                    }
+                    if let Some(username) = obj.get("author").and_then(|a| a.get("username")).cloned() {
+                        obj.insert("user".to_string(), serde_json::json!({ "login": username }));
+                    }
+                    if let Some(labels) = obj.get("labels").and_then(|v| v.as_array()) {
+                        let labels: Vec<Value> = labels
+                            .iter()
+                            .filter_map(|l| l.as_str())
+                            .map(|name| serde_json::json!({ "name": name }))
+                            .collect();
+                        obj.insert("labels".to_string(), Value::Array(labels));
+                    }
+                    if resource == Resource::MergeRequests {
+                        let merged = obj.get("state").and_then(|v| v.as_str()) == Some("merged");
+                        obj.insert("merged".to_string(), Value::Bool(merged));
+                        obj.insert("head".to_string(), serde_json::json!({ "ref": copy(&obj, "source_branch") }));
+                        obj.insert("base".to_string(), serde_json::json!({ "ref": copy(&obj, "target_branch") }));
+                    }
+                }
+            }
+            Value::Object(obj)
+        }
+        other => other,
+    }
+}
+
+#[async_trait]
+impl GitClient for GitLabClient {
+    fn platform(&self) -> Platform {
+        Platform::GitLab
+    }
+
+    fn settings(&self) -> ToolSettings {
+        self.settings
+    }
+
+    async fn get_json(&self, path: &str) -> Result<Value> {
+        self.get_translated(path, &[], None).await
+    }
+
+    async fn get_json_with_query(&self, path: &str, query: &[(&str, &str)]) -> Result<Value> {
+        self.get_translated(path, query, None).await
+    }
+
+    async fn get_json_with_timeout(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+        timeout: Option<Duration>,
+    ) -> Result<Value> {
+        self.get_translated(path, query, timeout).await
+    }
+
+    async fn default_branch(&self, owner: &str, repo: &str) -> Result<String> {
+        self.branches
+            .get_or_fetch(owner, repo, || async { self.get_json(&format!("/repos/{owner}/{repo}")).await })
+            .await
+    }
+
+    fn forget_default_branch(&self, owner: &str, repo: &str) {
+        self.branches.invalidate(owner, repo);
+    }
+
+    async fn get_raw(&self, path: &str, _query: &[(&str, &str)], _media: RawMedia) -> Result<String> {
+        Err(unsupported(path))
+    }
+
+    async fn post_json(&self, path: &str, _body: &Value) -> Result<Value> {
+        Err(unsupported(path))
+    }
+
+    async fn post_no_content(&self, path: &str, _body: &Value) -> Result<()> {
+        Err(unsupported(path))
+    }
+
+    async fn put_json(&self, path: &str, _body: &Value) -> Result<Value> {
+        Err(unsupported(path))
+    }
+
+    async fn patch_json(&self, path: &str, _body: &Value) -> Result<Value> {
+        Err(unsupported(path))
+    }
+
+    async fn delete(&self, path: &str) -> Result<()> {
+        Err(unsupported(path))
+    }
+
+    async fn delete_with_body(&self, path: &str, _body: &Value) -> Result<()> {
+        Err(unsupported(path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repo_paths_map_to_projects() {
+        assert_eq!(
+            translate_path("/repos/acme/widgets"),
+            Some(("/projects/acme%2Fwidgets".to_string(), Resource::Project))
+        );
+        assert_eq!(
+            translate_path("/repos/acme/widgets/issues"),
+            Some(("/projects/acme%2Fwidgets/issues".to_string(), Resource::Issues))
+        );
+        assert_eq!(
+            translate_path("/repos/acme/widgets/pulls"),
+            Some(("/projects/acme%2Fwidgets/merge_requests".to_string(), Resource::MergeRequests))
+        );
+        assert_eq!(translate_path("/repos/acme/widgets/pulls/3/files"), None);
+        assert_eq!(translate_path("/user"), None);
+    }
+
+    #[test]
+    fn unsupported_error_names_the_tool() {
+        let err = gitlab_unsupported("commit_search").to_string();
+        assert!(err.contains("commit_search is not supported on GitLab yet"), "{err}");
+    }
+
+    #[test]
+    fn query_uses_gitlab_names() {
+        let query = translate_query(&[("state", "open"), ("type", "issues"), ("limit", "20"), ("page", "2")]);
+        assert_eq!(
+            query,
+            vec![
+                ("state".to_string(), "opened".to_string()),
+                ("per_page".to_string(), "20".to_string()),
+                ("page".to_string(), "2".to_string()),
+            ]
+        );
+        assert!(translate_query(&[("state", "all")]).is_empty());
+        assert_eq!(
+            translate_query(&[("base", "main"), ("head", "feature")]),
+            vec![
+                ("target_branch".to_string(), "main".to_string()),
+                ("source_branch".to_string(), "feature".to_string()),
+            ]
+        );
+        assert_eq!(
+            translate_query(&[("sort", "updated"), ("direction", "asc")]),
+            vec![
+                ("order_by".to_string(), "updated_at".to_string()),
+                ("sort".to_string(), "asc".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn merge_requests_get_github_fields() {
+        let mr = serde_json::json!({
+            "iid": 7,
+            "title": "Add feature",
+            "state": "opened",
+            "labels": ["bug"],
+            "author": { "username": "dev" },
+            "source_branch": "feature",
+            "target_branch": "main",
+        });
+        let mr = normalize(Resource::MergeRequests, mr);
+        assert_eq!(mr["number"], 7);
+        assert_eq!(mr["state"], "open");
+        assert_eq!(mr["labels"][0]["name"], "bug");
+        assert_eq!(mr["user"]["login"], "dev");
+        assert_eq!(mr["head"]["ref"], "feature");
+        assert_eq!(mr["base"]["ref"], "main");
+        assert_eq!(mr["merged"], false);
+    }
+}
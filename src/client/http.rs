@@ -0,0 +1,92 @@
+use crate::config::Config;
+use crate::error::{GitxError, Result};
+
+/// Start a `reqwest` client builder with the transport settings shared by
+/// every platform client (user agent, compression, custom CA, TLS verification,
+/// proxy).
+///
+/// Without `GITX_PROXY`, reqwest's own handling of `HTTPS_PROXY`/`HTTP_PROXY`/
+/// `NO_PROXY` applies.
+pub(crate) fn client_builder(config: &Config) -> Result<reqwest::ClientBuilder> {
+    // Advertise compressed encodings; large tree and list responses shrink a lot.
+    let mut builder = reqwest::Client::builder()
+        .user_agent(config.user_agent.as_str())
+        .gzip(true)
+        .brotli(true)
+        .deflate(true)
+        // Without a timeout a stalled server hangs the tool call forever;
+        // `timeout_secs` on individual tools overrides this per request.
+        .timeout(std::time::Duration::from_secs(config.request_timeout_secs));
+
+    if let Some(path) = &config.ca_cert {
+        let pem = std::fs::read(path).map_err(|e| {
+            GitxError::Config(format!("GITX_CA_CERT: cannot read {path}: {e}"))
+        })?;
+        let certs = reqwest::Certificate::from_pem_bundle(&pem).map_err(|e| {
+            GitxError::Config(format!("GITX_CA_CERT: {path} is not a valid PEM bundle: {e}"))
+        })?;
+        if certs.is_empty() {
+            return Err(GitxError::Config(format!(
+                "GITX_CA_CERT: no certificates found in {path}"
+            )));
+        }
+        for cert in certs {
+            builder = builder.add_root_certificate(cert);
+        }
+    }
+
+    if config.tls_insecure {
+        tracing::warn!("GITX_TLS_INSECURE is set: TLS certificate verification is disabled");
+        builder = builder.danger_accept_invalid_certs(true);
+    }
+
+    if let Some(proxy_url) = &config.proxy {
+        // Keep honouring NO_PROXY so internal hosts can still bypass the override.
+        let proxy = reqwest::Proxy::all(proxy_url)
+            .map_err(|e| GitxError::Config(format!("GITX_PROXY: invalid proxy URL {proxy_url}: {e}")))?
+            .no_proxy(reqwest::NoProxy::from_env());
+        builder = builder.proxy(proxy);
+    }
+
+    Ok(builder)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::platform::Platform;
+
+    fn config_with_ca(path: &str) -> Config {
+        let mut config = Config::new("https://git.example.com".to_string(), "t".to_string(), Platform::Gitea);
+        config.ca_cert = Some(path.to_string());
+        config
+    }
+
+    #[test]
+    fn invalid_proxy_is_a_config_error() {
+        let mut config = Config::new("https://git.example.com".to_string(), "t".to_string(), Platform::Gitea);
+        config.proxy = Some("not a url".to_string());
+        let err = client_builder(&config).unwrap_err();
+        assert!(matches!(err, GitxError::Config(_)), "{err}");
+        assert!(err.to_string().contains("GITX_PROXY"));
+    }
+
+    #[test]
+    fn missing_ca_file_is_a_config_error() {
+        let err = client_builder(&config_with_ca("/nonexistent/gitx-ca.pem")).unwrap_err();
+        assert!(matches!(err, GitxError::Config(_)), "{err}");
+        let msg = err.to_string();
+        assert!(msg.contains("GITX_CA_CERT"), "{msg}");
+        assert!(msg.contains("/nonexistent/gitx-ca.pem"), "{msg}");
+    }
+
+    #[test]
+    fn non_pem_ca_file_is_a_config_error() {
+        let path = std::env::temp_dir().join(format!("gitx-mcp-{}-bad-ca.pem", std::process::id()));
+        std::fs::write(&path, "not a certificate").unwrap();
+        let err = client_builder(&config_with_ca(path.to_str().unwrap())).unwrap_err();
+        std::fs::remove_file(&path).ok();
+        assert!(matches!(err, GitxError::Config(_)), "{err}");
+        assert!(err.to_string().contains("GITX_CA_CERT"));
+    }
+}
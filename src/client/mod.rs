@@ -1,7 +1,14 @@
 mod trait_def;
+mod cache;
+mod dry_run;
 mod gitea;
 mod github;
+mod gitlab;
+mod http;
 
-pub use trait_def::GitClient;
+pub use trait_def::{GitClient, RawMedia};
+pub use cache::DEFAULT_CACHE_ENTRIES;
+pub use dry_run::DryRunClient;
 pub use gitea::GiteaClient;
 pub use github::GitHubClient;
+pub use gitlab::{gitlab_unsupported, GitLabClient};
@@ -1,11 +1,25 @@
 use std::fmt::Debug;
+use std::time::Duration;
 
 use async_trait::async_trait;
 use serde_json::Value;
 
+use crate::config::ToolSettings;
 use crate::error::Result;
 use crate::platform::Platform;
 
+/// What a raw (non-JSON) GET asks for. Each client turns it into its own
+/// `Accept` header, so the response type never depends on the shape of the path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RawMedia {
+    /// A file's contents.
+    File,
+    /// A unified diff of a commit or pull request.
+    Diff,
+    /// Any other text, such as job logs.
+    Text,
+}
+
 /// Trait abstracting HTTP client operations for Git platform APIs.
 ///
 /// All JSON methods return `serde_json::Value` to maintain object safety
@@ -15,14 +29,40 @@ pub trait GitClient: Send + Sync + Debug {
     /// Which platform this client connects to.
     fn platform(&self) -> Platform;
 
+    /// The server settings tools apply to this client's requests and output.
+    fn settings(&self) -> ToolSettings;
+
     /// GET request, returning parsed JSON.
     async fn get_json(&self, path: &str) -> Result<Value>;
 
     /// GET request with query parameters, returning parsed JSON.
     async fn get_json_with_query(&self, path: &str, query: &[(&str, &str)]) -> Result<Value>;
 
-    /// GET request returning raw text (e.g. diffs).
-    async fn get_raw(&self, path: &str) -> Result<String>;
+    /// GET request with query parameters, returning raw text of the given kind (e.g. diffs).
+    async fn get_raw(&self, path: &str, query: &[(&str, &str)], media: RawMedia) -> Result<String>;
+
+    /// [`get_json_with_query`](Self::get_json_with_query) under a per-request
+    /// timeout; `None` keeps the client's default.
+    async fn get_json_with_timeout(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+        _timeout: Option<Duration>,
+    ) -> Result<Value> {
+        self.get_json_with_query(path, query).await
+    }
+
+    /// [`get_raw`](Self::get_raw) under a per-request timeout; `None` keeps the
+    /// client's default.
+    async fn get_raw_with_timeout(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+        media: RawMedia,
+        _timeout: Option<Duration>,
+    ) -> Result<String> {
+        self.get_raw(path, query, media).await
+    }
 
     /// POST request with JSON body, returning parsed JSON.
     async fn post_json(&self, path: &str, body: &Value) -> Result<Value>;
@@ -41,4 +81,15 @@ pub trait GitClient: Send + Sync + Debug {
 
     /// DELETE request with a JSON body (e.g. file_delete).
     async fn delete_with_body(&self, path: &str, body: &Value) -> Result<()>;
+
+    /// The repository's default branch. The platform clients cache it per
+    /// repository for a few minutes.
+    async fn default_branch(&self, owner: &str, repo: &str) -> Result<String> {
+        let repo = self.get_json(&format!("/repos/{owner}/{repo}")).await?;
+        Ok(super::cache::default_branch_of(&repo))
+    }
+
+    /// Forget the cached default branch of `owner/repo`, after a change that
+    /// may have moved or removed it.
+    fn forget_default_branch(&self, _owner: &str, _repo: &str) {}
 }
@@ -15,6 +15,31 @@ pub enum GitxError {
     #[error("Missing required parameter: {0}")]
     MissingParam(String),
 
+    /// A parameter was given but its value is out of range or not one of the accepted values.
+    #[error("Invalid parameter: {0}")]
+    InvalidParam(String),
+
+    /// A non-success HTTP response the clients have no more specific variant for.
+    #[error("{}", status_message(*status, body))]
+    Status { status: u16, body: String },
+
+    /// A GitHub rate limit (the hourly budget or the secondary abuse limit);
+    /// the request may be retried after `retry_after` seconds.
+    #[error("GitHub rate limit exceeded; retry after {retry_after}s")]
+    RateLimited { retry_after: u64 },
+
+    #[error("Merge blocked: {0}")]
+    MergeBlocked(String),
+
+    /// The write conflicts with the current state (e.g. a stale file SHA); the
+    /// message says how to recover.
+    #[error("{0}")]
+    Conflict(String),
+
+    /// A setting the operator supplied (e.g. `GITX_CA_CERT`) that cannot be used.
+    #[error("Invalid configuration: {0}")]
+    Config(String),
+
     #[error("Could not resolve repository from directory: {0}")]
     RepoResolution(String),
 
@@ -24,20 +49,66 @@ pub enum GitxError {
     #[error("JSON error: {0}")]
     Json(#[from] serde_json::Error),
 
+    /// A write intercepted by `GITX_DRY_RUN`; carries the preview text.
+    #[error("{0}")]
+    DryRun(String),
+
     #[error("{0}")]
     Other(#[from] anyhow::Error),
 }
 
+/// Describe an HTTP error status, calling out the ones an agent can act on.
+fn status_message(status: u16, body: &str) -> String {
+    match status {
+        403 => format!("Forbidden (HTTP 403): {body}"),
+        405 => format!("Method not allowed or not mergeable (HTTP 405): {body}"),
+        409 => format!("Conflict (HTTP 409): {body}"),
+        422 => format!("Validation failed (HTTP 422): {body}"),
+        _ => format!("HTTP {status}: {body}"),
+    }
+}
+
 impl From<GitxError> for ErrorData {
     fn from(err: GitxError) -> Self {
         let code = match &err {
             GitxError::MissingParam(_) => ErrorCode::INVALID_PARAMS,
+            GitxError::InvalidParam(_) => ErrorCode::INVALID_PARAMS,
             GitxError::NotFound(_) => ErrorCode::INVALID_PARAMS,
             GitxError::Auth => ErrorCode::INVALID_PARAMS,
+            GitxError::MergeBlocked(_) => ErrorCode::INVALID_REQUEST,
+            GitxError::Conflict(_) => ErrorCode::INVALID_REQUEST,
+            GitxError::RateLimited { .. } => ErrorCode::INVALID_REQUEST,
+            GitxError::Status { status: 422, .. } => ErrorCode::INVALID_PARAMS,
+            GitxError::Status { status: 400..=499, .. } => ErrorCode::INVALID_REQUEST,
             _ => ErrorCode::INTERNAL_ERROR,
         };
-        ErrorData::new(code, err.to_string(), None)
+        // Keep the status machine-readable alongside the message.
+        let data = match &err {
+            GitxError::Status { status, .. } => Some(serde_json::json!({ "status": status })),
+            GitxError::RateLimited { retry_after } => Some(serde_json::json!({ "retry_after": retry_after })),
+            _ => None,
+        };
+        ErrorData::new(code, err.to_string(), data)
     }
 }
 
 pub type Result<T> = std::result::Result<T, GitxError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_errors_keep_the_code() {
+        let err = GitxError::Status { status: 422, body: "{\"message\":\"Invalid\"}".to_string() };
+        assert_eq!(err.to_string(), "Validation failed (HTTP 422): {\"message\":\"Invalid\"}");
+        let data = ErrorData::from(err);
+        assert_eq!(data.code, ErrorCode::INVALID_PARAMS);
+        assert_eq!(data.data, Some(serde_json::json!({ "status": 422 })));
+
+        let data = ErrorData::from(GitxError::Status { status: 409, body: String::new() });
+        assert_eq!(data.code, ErrorCode::INVALID_REQUEST);
+        assert!(data.message.starts_with("Conflict"));
+        assert_eq!(ErrorData::from(GitxError::Status { status: 502, body: String::new() }).code, ErrorCode::INTERNAL_ERROR);
+    }
+}
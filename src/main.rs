@@ -1,8 +1,13 @@
+use std::sync::Arc;
+
 use anyhow::Result;
+use rmcp::transport::streamable_http_server::{
+    session::local::LocalSessionManager, StreamableHttpServerConfig, StreamableHttpService,
+};
 use rmcp::{transport::stdio, ServiceExt};
 use tracing_subscriber::EnvFilter;
 
-use gitx_mcp::config::Config;
+use gitx_mcp::config::{Config, Transport};
 use gitx_mcp::server::GitxMcp;
 
 #[tokio::main]
@@ -14,9 +19,33 @@ async fn main() -> Result<()> {
         .init();
 
     let config = Config::from_env()?;
-    let service = GitxMcp::new(config)?;
-    let server = service.serve(stdio()).await?;
-    server.waiting().await?;
+    let transport = config.transport;
+    let bind_addr = config.bind_addr.clone();
+    let mut service = GitxMcp::new(config)?;
+    service.fetch_repo_details().await;
+
+    match transport {
+        Transport::Stdio => {
+            let server = service.serve(stdio()).await?;
+            server.waiting().await?;
+        }
+        Transport::Http => {
+            // Every session shares the same client and detected repository.
+            let mcp = StreamableHttpService::new(
+                move || Ok(service.clone()),
+                Arc::new(LocalSessionManager::default()),
+                StreamableHttpServerConfig::default(),
+            );
+            let router = axum::Router::new().nest_service("/mcp", mcp);
+            let listener = tokio::net::TcpListener::bind(&bind_addr).await?;
+            tracing::info!("Serving MCP over HTTP at http://{bind_addr}/mcp");
+            axum::serve(listener, router)
+                .with_graceful_shutdown(async {
+                    let _ = tokio::signal::ctrl_c().await;
+                })
+                .await?;
+        }
+    }
 
     Ok(())
 }
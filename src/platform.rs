@@ -2,4 +2,5 @@
 pub enum Platform {
     Gitea,
     GitHub,
+    GitLab,
 }
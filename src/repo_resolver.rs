@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use crate::error::{GitxError, Result};
 
@@ -9,45 +9,165 @@ pub struct RepoInfo {
     pub repo: String,
 }
 
-/// Resolve the owner/repo from a `.git/config` file in the given directory.
+/// Resolve the owner/repo from the `.git/config` of the repository containing
+/// the given directory (searching parent directories like git does).
 ///
-/// Parses the `[remote "origin"]` section and extracts owner/repo from the URL.
+/// Uses the `origin` remote, or the first remote if there is no `origin`.
 /// Supports SSH (`git@host:owner/repo.git`), HTTPS (`https://host/owner/repo.git`),
 /// and path-style URLs.
 pub fn resolve_repo(directory: &str) -> Result<RepoInfo> {
-    let git_config_path = Path::new(directory).join(".git").join("config");
+    resolve_repo_with_remote(directory, None)
+}
 
-    if !git_config_path.exists() {
-        return Err(GitxError::RepoResolution(format!(
-            "No .git/config found in {directory}"
-        )));
-    }
+/// Like [`resolve_repo`], but reads the URL of the named `remote` when given.
+pub fn resolve_repo_with_remote(directory: &str, remote: Option<&str>) -> Result<RepoInfo> {
+    let root = find_repo_root(Path::new(directory))?;
+    let git_config_path = git_config_path(&root)?;
+
+    let content = std::fs::read_to_string(&git_config_path).map_err(|e| {
+        GitxError::RepoResolution(format!("Failed to read .git/config: {e}"))
+    })?;
+
+    let remotes = parse_remotes(&content);
+    let url = select_remote(&remotes, remote)?;
+    parse_remote_url(url)
+}
+
+/// Resolve every remote of the repository containing `directory`, in
+/// `.git/config` order. Remotes whose URL has no owner/repo are skipped.
+pub fn resolve_all_remotes(directory: &str) -> Result<Vec<(String, RepoInfo)>> {
+    let root = find_repo_root(Path::new(directory))?;
+    let git_config_path = git_config_path(&root)?;
 
     let content = std::fs::read_to_string(&git_config_path).map_err(|e| {
         GitxError::RepoResolution(format!("Failed to read .git/config: {e}"))
     })?;
 
-    // Simple parser: find [remote "origin"] section, then url = ...
-    let mut in_origin = false;
+    Ok(parse_remotes(&content)
+        .into_iter()
+        .filter_map(|(name, url)| Some((name, parse_remote_url(&url).ok()?)))
+        .collect())
+}
+
+/// Collect `[remote "name"]` URLs from a git config, in file order.
+fn parse_remotes(content: &str) -> Vec<(String, String)> {
+    let mut remotes = Vec::new();
+    let mut current: Option<String> = None;
+
     for line in content.lines() {
         let trimmed = line.trim();
         if trimmed.starts_with('[') {
-            in_origin = trimmed == "[remote \"origin\"]";
+            current = trimmed
+                .strip_prefix("[remote \"")
+                .and_then(|s| s.strip_suffix("\"]"))
+                .map(String::from);
             continue;
         }
-        if in_origin {
+        if let Some(name) = &current {
             if let Some(url) = trimmed.strip_prefix("url").and_then(|s| {
                 let s = s.trim_start();
                 s.strip_prefix('=').map(|s| s.trim())
             }) {
-                return parse_remote_url(url);
+                if !remotes.iter().any(|(n, _)| n == name) {
+                    remotes.push((name.clone(), url.to_string()));
+                }
             }
         }
     }
 
-    Err(GitxError::RepoResolution(
-        "No remote 'origin' URL found in .git/config".to_string(),
-    ))
+    remotes
+}
+
+/// Pick the remote URL to use: the requested one, else `origin`, else the first.
+fn select_remote<'a>(remotes: &'a [(String, String)], wanted: Option<&str>) -> Result<&'a str> {
+    let find = |name: &str| {
+        remotes
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, url)| url.as_str())
+    };
+
+    match wanted {
+        Some(name) => find(name).ok_or_else(|| {
+            let available: Vec<&str> = remotes.iter().map(|(n, _)| n.as_str()).collect();
+            GitxError::RepoResolution(format!(
+                "No remote '{name}' found in .git/config (available: {})",
+                if available.is_empty() { "none".to_string() } else { available.join(", ") }
+            ))
+        }),
+        None => find("origin")
+            .or_else(|| remotes.first().map(|(_, url)| url.as_str()))
+            .ok_or_else(|| {
+                GitxError::RepoResolution("No remote 'origin' URL found in .git/config".to_string())
+            }),
+    }
+}
+
+/// Walk up from `dir` to the nearest directory containing a `.git` entry,
+/// mirroring how git itself discovers the repository.
+fn find_repo_root(dir: &Path) -> Result<PathBuf> {
+    let start = std::fs::canonicalize(dir).unwrap_or_else(|_| dir.to_path_buf());
+
+    start
+        .ancestors()
+        .find(|d| d.join(".git").exists())
+        .map(Path::to_path_buf)
+        .ok_or_else(|| {
+            GitxError::RepoResolution(format!(
+                "No .git found in {} or any parent",
+                dir.display()
+            ))
+        })
+}
+
+/// Locate the git config for the repository in `dir`.
+///
+/// `.git` is normally a directory, but in worktrees and submodules it is a file
+/// containing `gitdir: <path>`. For worktrees that gitdir holds a `commondir`
+/// pointer to the main repository, which is where the config lives.
+fn git_config_path(dir: &Path) -> Result<PathBuf> {
+    let dot_git = dir.join(".git");
+
+    if dot_git.is_dir() {
+        let config = dot_git.join("config");
+        if config.exists() {
+            return Ok(config);
+        }
+    } else if dot_git.is_file() {
+        let content = std::fs::read_to_string(&dot_git).map_err(|e| {
+            GitxError::RepoResolution(format!("Failed to read {}: {e}", dot_git.display()))
+        })?;
+        let target = content
+            .lines()
+            .find_map(|l| l.trim().strip_prefix("gitdir:"))
+            .map(str::trim)
+            .ok_or_else(|| {
+                GitxError::RepoResolution(format!(
+                    "{} does not contain a gitdir: pointer",
+                    dot_git.display()
+                ))
+            })?;
+        let gitdir = dir.join(target);
+
+        let common_dir = match std::fs::read_to_string(gitdir.join("commondir")) {
+            Ok(common) => gitdir.join(common.trim()),
+            Err(_) => gitdir,
+        };
+        let config = common_dir.join("config");
+        if config.exists() {
+            return Ok(config);
+        }
+        return Err(GitxError::RepoResolution(format!(
+            "No git config found at {} (from {})",
+            config.display(),
+            dot_git.display()
+        )));
+    }
+
+    Err(GitxError::RepoResolution(format!(
+        "No .git/config found in {}",
+        dir.display()
+    )))
 }
 
 /// Parse a git remote URL into owner/repo.
@@ -82,9 +202,15 @@ fn parse_remote_url(url: &str) -> Result<RepoInfo> {
 }
 
 /// Extract owner/repo from a path like `owner/repo.git` or `owner/repo`.
+///
+/// Uses the last two segments so instances served under a subpath
+/// (`git/owner/repo.git`) resolve correctly.
 fn extract_owner_repo(path: &str) -> Result<RepoInfo> {
-    let path = path.trim_end_matches(".git").trim_matches('/');
-    let parts: Vec<&str> = path.splitn(3, '/').collect();
+    let path = path
+        .trim_matches('/')
+        .trim_end_matches(".git")
+        .trim_end_matches('/');
+    let parts: Vec<&str> = path.split('/').filter(|p| !p.is_empty()).collect();
 
     if parts.len() < 2 {
         return Err(GitxError::RepoResolution(format!(
@@ -93,7 +219,170 @@ fn extract_owner_repo(path: &str) -> Result<RepoInfo> {
     }
 
     Ok(RepoInfo {
-        owner: parts[0].to_string(),
-        repo: parts[1].to_string(),
+        owner: parts[parts.len() - 2].to_string(),
+        repo: parts[parts.len() - 1].to_string(),
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Create an empty scratch directory unique to this test.
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("gitx-mcp-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn write_config(gitdir: &Path, url: &str) {
+        std::fs::create_dir_all(gitdir).unwrap();
+        std::fs::write(
+            gitdir.join("config"),
+            format!("[core]\n\tbare = false\n[remote \"origin\"]\n\turl = {url}\n"),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn resolves_dot_git_directory() {
+        let dir = scratch_dir("plain");
+        write_config(&dir.join(".git"), "git@example.com:acme/widgets.git");
+
+        let info = resolve_repo(dir.to_str().unwrap()).unwrap();
+        assert_eq!((info.owner.as_str(), info.repo.as_str()), ("acme", "widgets"));
+    }
+
+    fn owner_repo(url: &str) -> (String, String) {
+        let info = parse_remote_url(url).unwrap();
+        (info.owner, info.repo)
+    }
+
+    #[test]
+    fn parses_https_url_with_subpath() {
+        assert_eq!(
+            owner_repo("https://example.com/git/owner/repo.git"),
+            ("owner".to_string(), "repo".to_string())
+        );
+        assert_eq!(
+            owner_repo("https://example.com:8443/gitea/owner/repo/"),
+            ("owner".to_string(), "repo".to_string())
+        );
+    }
+
+    #[test]
+    fn parses_ssh_url_with_port() {
+        assert_eq!(
+            owner_repo("ssh://git@host:2222/owner/repo.git"),
+            ("owner".to_string(), "repo".to_string())
+        );
+        assert_eq!(
+            owner_repo("git@host:owner/repo.git/"),
+            ("owner".to_string(), "repo".to_string())
+        );
+    }
+
+    #[test]
+    fn rejects_single_segment_path() {
+        assert!(parse_remote_url("https://example.com/repo.git").is_err());
+    }
+
+    const MULTI_REMOTE: &str = "[remote \"origin\"]\n\turl = git@example.com:me/fork.git\n\
+                                [remote \"upstream\"]\n\turl = https://example.com/acme/project.git\n";
+
+    #[test]
+    fn falls_back_to_first_remote_without_origin() {
+        let config = "[remote \"upstream\"]\n\turl = https://example.com/acme/project.git\n\
+                      [remote \"mirror\"]\n\turl = https://mirror.example.com/acme/copy.git\n";
+        let remotes = parse_remotes(config);
+        assert_eq!(remotes.len(), 2);
+        assert_eq!(
+            select_remote(&remotes, None).unwrap(),
+            "https://example.com/acme/project.git"
+        );
+    }
+
+    #[test]
+    fn selects_explicit_remote() {
+        let remotes = parse_remotes(MULTI_REMOTE);
+        assert_eq!(select_remote(&remotes, None).unwrap(), "git@example.com:me/fork.git");
+        assert_eq!(
+            select_remote(&remotes, Some("upstream")).unwrap(),
+            "https://example.com/acme/project.git"
+        );
+        let err = select_remote(&remotes, Some("nope")).unwrap_err().to_string();
+        assert!(err.contains("origin, upstream"));
+    }
+
+    #[test]
+    fn resolve_with_upstream_remote() {
+        let dir = scratch_dir("upstream");
+        std::fs::create_dir_all(dir.join(".git")).unwrap();
+        std::fs::write(dir.join(".git").join("config"), MULTI_REMOTE).unwrap();
+
+        let info = resolve_repo_with_remote(dir.to_str().unwrap(), Some("upstream")).unwrap();
+        assert_eq!((info.owner.as_str(), info.repo.as_str()), ("acme", "project"));
+        let info = resolve_repo(dir.to_str().unwrap()).unwrap();
+        assert_eq!((info.owner.as_str(), info.repo.as_str()), ("me", "fork"));
+    }
+
+    #[test]
+    fn resolves_all_remotes_in_order() {
+        let dir = scratch_dir("all-remotes");
+        std::fs::create_dir_all(dir.join(".git")).unwrap();
+        let config = format!("{MULTI_REMOTE}[remote \"local\"]\n\turl = bare.git\n");
+        std::fs::write(dir.join(".git").join("config"), config).unwrap();
+
+        let remotes = resolve_all_remotes(dir.to_str().unwrap()).unwrap();
+        let names: Vec<(&str, &str, &str)> = remotes
+            .iter()
+            .map(|(name, info)| (name.as_str(), info.owner.as_str(), info.repo.as_str()))
+            .collect();
+        // "local" has no owner in its URL, so it is left out.
+        assert_eq!(names, [("origin", "me", "fork"), ("upstream", "acme", "project")]);
+    }
+
+    #[test]
+    fn resolves_from_nested_subdirectory() {
+        let dir = scratch_dir("nested");
+        write_config(&dir.join(".git"), "https://example.com/acme/deep.git");
+        let nested = dir.join("src").join("bin");
+        std::fs::create_dir_all(&nested).unwrap();
+
+        let info = resolve_repo(nested.to_str().unwrap()).unwrap();
+        assert_eq!((info.owner.as_str(), info.repo.as_str()), ("acme", "deep"));
+    }
+
+    #[test]
+    fn resolves_dot_git_file_pointing_to_gitdir() {
+        let dir = scratch_dir("submodule");
+        let checkout = dir.join("checkout");
+        std::fs::create_dir_all(&checkout).unwrap();
+        write_config(&dir.join("modules").join("lib"), "https://example.com/acme/lib.git");
+        std::fs::write(checkout.join(".git"), "gitdir: ../modules/lib\n").unwrap();
+
+        let info = resolve_repo(checkout.to_str().unwrap()).unwrap();
+        assert_eq!((info.owner.as_str(), info.repo.as_str()), ("acme", "lib"));
+    }
+
+    #[test]
+    fn resolves_worktree_through_commondir() {
+        let dir = scratch_dir("worktree");
+        let main_git = dir.join("main").join(".git");
+        write_config(&main_git, "https://example.com/acme/app.git");
+        let wt_gitdir = main_git.join("worktrees").join("feature");
+        std::fs::create_dir_all(&wt_gitdir).unwrap();
+        std::fs::write(wt_gitdir.join("commondir"), "../..\n").unwrap();
+        let worktree = dir.join("feature");
+        std::fs::create_dir_all(&worktree).unwrap();
+        std::fs::write(
+            worktree.join(".git"),
+            format!("gitdir: {}\n", wt_gitdir.display()),
+        )
+        .unwrap();
+
+        let info = resolve_repo(worktree.to_str().unwrap()).unwrap();
+        assert_eq!((info.owner.as_str(), info.repo.as_str()), ("acme", "app"));
+    }
+}
@@ -1,46 +1,88 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
 use serde_json::Value;
 
+use crate::config::ToolSettings;
+
+/// Lay out a heading, labelled fields, and an optional body: one field per
+/// line normally, or heading and fields on a single ` · `-separated line in
+/// compact mode. The body always follows after a blank line.
+fn layout_record(heading: Option<String>, fields: Vec<(&str, String)>, body: Option<&str>, compact: bool) -> String {
+    let mut out = if compact {
+        heading
+            .into_iter()
+            .chain(fields.into_iter().map(|(label, value)| format!("{label}: {value}")))
+            .collect::<Vec<_>>()
+            .join(" · ")
+    } else {
+        heading
+            .map(|h| format!("## {h}"))
+            .into_iter()
+            .chain(fields.into_iter().map(|(label, value)| format!("**{label}:** {value}")))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    if let Some(body) = body.filter(|b| !b.is_empty()) {
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        out.push('\n');
+        out.push_str(body);
+    }
+    out
+}
+
 /// Format a JSON value into a readable markdown string for agent consumption.
-pub fn format_value(val: &Value) -> String {
+pub fn format_value(val: &Value, settings: ToolSettings) -> String {
     match val {
         Value::Null => "No data returned.".to_string(),
         Value::Array(arr) if arr.is_empty() => "No items found.".to_string(),
         Value::Array(arr) => arr
             .iter()
-            .map(|item| format_object(item))
+            .map(|v| format_object(v, settings.compact))
             .collect::<Vec<_>>()
-            .join("\n---\n"),
-        Value::Object(_) => format_object(val),
+            .join(if settings.compact { "\n" } else { "\n---\n" }),
+        Value::Object(_) => format_object(val, settings.compact),
         other => other.to_string(),
     }
 }
 
-/// Format a JSON object into readable key: value lines.
-fn format_object(val: &Value) -> String {
+/// Render `val` with the `markdown` formatter, or as pretty-printed JSON when
+/// `format` is `"json"`.
+pub fn render(val: &Value, format: Option<&str>, markdown: impl Fn(&Value) -> String) -> String {
+    match format {
+        Some(f) if f.eq_ignore_ascii_case("json") => format!(
+            "```json\n{}\n```",
+            serde_json::to_string_pretty(val).unwrap_or_else(|_| val.to_string())
+        ),
+        _ => markdown(val),
+    }
+}
+
+/// Format a JSON object into readable key: value lines (one line in compact mode).
+fn format_object(val: &Value, compact: bool) -> String {
     match val {
         Value::Object(map) => {
-            let mut lines = Vec::new();
-            for (key, value) in map {
-                let formatted = format_field(key, value);
-                if !formatted.is_empty() {
-                    lines.push(formatted);
-                }
-            }
-            lines.join("\n")
+            let fields = map
+                .iter()
+                .filter_map(|(key, value)| format_field(value).map(|v| (key.as_str(), v)))
+                .collect();
+            layout_record(None, fields, None, compact)
         }
         other => other.to_string(),
     }
 }
 
-/// Format a single field, handling nested objects and arrays.
-fn format_field(key: &str, value: &Value) -> String {
+/// Format a single field's value, handling nested objects and arrays.
+/// Returns `None` for empty values, which are left out.
+fn format_field(value: &Value) -> Option<String> {
     match value {
-        Value::Null => String::new(),
-        Value::String(s) if s.is_empty() => String::new(),
-        Value::String(s) => format!("**{key}:** {s}"),
-        Value::Number(n) => format!("**{key}:** {n}"),
-        Value::Bool(b) => format!("**{key}:** {b}"),
-        Value::Array(arr) if arr.is_empty() => String::new(),
+        Value::Null => None,
+        Value::String(s) if s.is_empty() => None,
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        Value::Array(arr) if arr.is_empty() => None,
         Value::Array(arr) => {
             // For arrays of simple values, join inline
             let items: Vec<String> = arr
@@ -58,34 +100,22 @@ fn format_field(key: &str, value: &Value) -> String {
                     other => Some(other.to_string()),
                 })
                 .collect();
-            if items.is_empty() {
-                String::new()
-            } else {
-                format!("**{key}:** {}", items.join(", "))
-            }
-        }
-        Value::Object(m) => {
-            // For nested objects, extract common identifiers
-            if let Some(name) = m
-                .get("login")
-                .or_else(|| m.get("name"))
-                .or_else(|| m.get("title"))
-                .or_else(|| m.get("full_name"))
-                .and_then(|v| v.as_str())
-            {
-                format!("**{key}:** {name}")
-            } else {
-                String::new()
-            }
+            (!items.is_empty()).then(|| items.join(", "))
         }
+        // For nested objects, extract common identifiers
+        Value::Object(m) => m
+            .get("login")
+            .or_else(|| m.get("name"))
+            .or_else(|| m.get("title"))
+            .or_else(|| m.get("full_name"))
+            .and_then(|v| v.as_str())
+            .map(String::from),
     }
 }
 
 /// Format an issue object into readable markdown.
-pub fn format_issue(issue: &Value) -> String {
-    let mut parts = Vec::new();
-
-    if let Some(number) = issue.get("number").and_then(|v| v.as_i64()) {
+pub fn format_issue(issue: &Value, settings: ToolSettings) -> String {
+    let heading = issue.get("number").and_then(|v| v.as_i64()).map(|number| {
         let title = issue
             .get("title")
             .and_then(|v| v.as_str())
@@ -94,15 +124,16 @@ pub fn format_issue(issue: &Value) -> String {
             .get("state")
             .and_then(|v| v.as_str())
             .unwrap_or("unknown");
-        parts.push(format!("## #{number} {title} [{state}]"));
-    }
+        format!("#{number} {title} [{state}]")
+    });
+    let mut fields = Vec::new();
 
     if let Some(user) = issue
         .get("user")
         .and_then(|v| v.get("login"))
         .and_then(|v| v.as_str())
     {
-        parts.push(format!("**Author:** {user}"));
+        fields.push(("Author", user.to_string()));
     }
 
     if let Some(labels) = issue.get("labels").and_then(|v| v.as_array()) {
@@ -111,7 +142,7 @@ pub fn format_issue(issue: &Value) -> String {
             .filter_map(|l| l.get("name").and_then(|v| v.as_str()))
             .collect();
         if !label_names.is_empty() {
-            parts.push(format!("**Labels:** {}", label_names.join(", ")));
+            fields.push(("Labels", label_names.join(", ")));
         }
     }
 
@@ -121,7 +152,7 @@ pub fn format_issue(issue: &Value) -> String {
             .filter_map(|a| a.get("login").and_then(|v| v.as_str()))
             .collect();
         if !names.is_empty() {
-            parts.push(format!("**Assignees:** {}", names.join(", ")));
+            fields.push(("Assignees", names.join(", ")));
         }
     }
 
@@ -130,24 +161,18 @@ pub fn format_issue(issue: &Value) -> String {
         .and_then(|v| v.get("title"))
         .and_then(|v| v.as_str())
     {
-        parts.push(format!("**Milestone:** {milestone}"));
+        fields.push(("Milestone", milestone.to_string()));
     }
 
     if let Some(created) = issue.get("created_at").and_then(|v| v.as_str()) {
-        parts.push(format!("**Created:** {created}"));
+        fields.push(("Created", format_timestamp(created, settings)));
     }
 
     if let Some(updated) = issue.get("updated_at").and_then(|v| v.as_str()) {
-        parts.push(format!("**Updated:** {updated}"));
+        fields.push(("Updated", format_timestamp(updated, settings)));
     }
 
-    if let Some(body) = issue.get("body").and_then(|v| v.as_str()) {
-        if !body.is_empty() {
-            parts.push(format!("\n{body}"));
-        }
-    }
-
-    parts.join("\n")
+    layout_record(heading, fields, issue.get("body").and_then(|v| v.as_str()), settings.compact)
 }
 
 /// Format a list of issues into readable markdown.
@@ -189,10 +214,8 @@ pub fn format_issue_list(issues: &[Value]) -> String {
 }
 
 /// Format a pull request object into readable markdown.
-pub fn format_pull_request(pr: &Value) -> String {
-    let mut parts = Vec::new();
-
-    if let Some(number) = pr.get("number").and_then(|v| v.as_i64()) {
+pub fn format_pull_request(pr: &Value, settings: ToolSettings) -> String {
+    let heading = pr.get("number").and_then(|v| v.as_i64()).map(|number| {
         let title = pr
             .get("title")
             .and_then(|v| v.as_str())
@@ -201,15 +224,16 @@ pub fn format_pull_request(pr: &Value) -> String {
             .get("state")
             .and_then(|v| v.as_str())
             .unwrap_or("unknown");
-        parts.push(format!("## PR #{number} {title} [{state}]"));
-    }
+        format!("PR #{number} {title} [{state}]")
+    });
+    let mut fields = Vec::new();
 
     if let Some(user) = pr
         .get("user")
         .and_then(|v| v.get("login"))
         .and_then(|v| v.as_str())
     {
-        parts.push(format!("**Author:** {user}"));
+        fields.push(("Author", user.to_string()));
     }
 
     if let Some(head) = pr
@@ -222,11 +246,15 @@ pub fn format_pull_request(pr: &Value) -> String {
             .and_then(|v| v.get("label"))
             .and_then(|v| v.as_str())
             .unwrap_or("?");
-        parts.push(format!("**Branch:** {head} -> {base}"));
+        fields.push(("Branch", format!("{head} -> {base}")));
     }
 
     if let Some(mergeable) = pr.get("mergeable").and_then(|v| v.as_bool()) {
-        parts.push(format!("**Mergeable:** {mergeable}"));
+        fields.push(("Mergeable", mergeable.to_string()));
+    }
+
+    if let Some(changes) = format_pr_changes(pr) {
+        fields.push(("Changes", changes));
     }
 
     if let Some(labels) = pr.get("labels").and_then(|v| v.as_array()) {
@@ -235,21 +263,32 @@ pub fn format_pull_request(pr: &Value) -> String {
             .filter_map(|l| l.get("name").and_then(|v| v.as_str()))
             .collect();
         if !label_names.is_empty() {
-            parts.push(format!("**Labels:** {}", label_names.join(", ")));
+            fields.push(("Labels", label_names.join(", ")));
         }
     }
 
     if let Some(created) = pr.get("created_at").and_then(|v| v.as_str()) {
-        parts.push(format!("**Created:** {created}"));
+        fields.push(("Created", format_timestamp(created, settings)));
     }
 
-    if let Some(body) = pr.get("body").and_then(|v| v.as_str()) {
-        if !body.is_empty() {
-            parts.push(format!("\n{body}"));
-        }
-    }
+    layout_record(heading, fields, pr.get("body").and_then(|v| v.as_str()), settings.compact)
+}
 
-    parts.join("\n")
+/// Summarize PR size as `+A -D across N files`, using whichever of the
+/// fields the API returned.
+fn format_pr_changes(pr: &Value) -> Option<String> {
+    let field = |key: &str| pr.get(key).and_then(|v| v.as_i64());
+    let lines = match (field("additions"), field("deletions")) {
+        (Some(a), Some(d)) => Some(format!("+{a} -{d}")),
+        _ => None,
+    };
+    let files = field("changed_files").map(|n| format!("{n} file{}", if n == 1 { "" } else { "s" }));
+    match (lines, files) {
+        (Some(lines), Some(files)) => Some(format!("{lines} across {files}")),
+        (Some(lines), None) => Some(lines),
+        (None, Some(files)) => Some(files),
+        (None, None) => None,
+    }
 }
 
 /// Format a list of pull requests.
@@ -275,7 +314,7 @@ pub fn format_pr_list(prs: &[Value]) -> String {
 }
 
 /// Format a comment object.
-pub fn format_comment(comment: &Value) -> String {
+pub fn format_comment(comment: &Value, settings: ToolSettings) -> String {
     let user = comment
         .get("user")
         .and_then(|v| v.get("login"))
@@ -291,17 +330,17 @@ pub fn format_comment(comment: &Value) -> String {
         .unwrap_or("");
     let id = comment.get("id").and_then(|v| v.as_i64()).unwrap_or(0);
 
-    format!("**Comment #{id}** by {user} ({created}):\n{body}")
+    format!("**Comment #{id}** by {user} ({}):\n{body}", format_timestamp(created, settings))
 }
 
 /// Format a list of comments.
-pub fn format_comment_list(comments: &[Value]) -> String {
+pub fn format_comment_list(comments: &[Value], settings: ToolSettings) -> String {
     if comments.is_empty() {
         return "No comments found.".to_string();
     }
     comments
         .iter()
-        .map(|c| format_comment(c))
+        .map(|comment| format_comment(comment, settings))
         .collect::<Vec<_>>()
         .join("\n\n---\n\n")
 }
@@ -339,10 +378,22 @@ pub fn format_commit(commit: &Value) -> String {
         parts.push(format!("**Author:** {author} ({date})"));
     }
 
+    if let Some(verification) = commit.get("commit").and_then(|v| v.get("verification")) {
+        let verified = verification.get("verified").and_then(|v| v.as_bool()).unwrap_or(false);
+        let reason = verification
+            .get("reason")
+            .and_then(|v| v.as_str())
+            .filter(|r| !r.is_empty())
+            .unwrap_or("unknown");
+        let status = if verified { "verified" } else { "unverified" };
+        parts.push(format!("**Signature:** {status} ({reason})"));
+    }
+
     parts.join("\n")
 }
 
-/// Format a list of commits.
+/// Format a list of commits. Diff stats are appended as `(+A -D)` when the
+/// API includes them (Gitea's list endpoint does; GitHub's list does not).
 pub fn format_commit_list(commits: &[Value]) -> String {
     if commits.is_empty() {
         return "No commits found.".to_string();
@@ -363,7 +414,13 @@ pub fn format_commit_list(commits: &[Value]) -> String {
                 .lines()
                 .next()
                 .unwrap_or("");
-            format!("- `{sha}` {msg}")
+            let stats = c.get("stats");
+            let additions = stats.and_then(|s| s.get("additions")).and_then(|v| v.as_i64());
+            let deletions = stats.and_then(|s| s.get("deletions")).and_then(|v| v.as_i64());
+            match (additions, deletions) {
+                (Some(a), Some(d)) => format!("- `{sha}` {msg} (+{a} -{d})"),
+                _ => format!("- `{sha}` {msg}"),
+            }
         })
         .collect::<Vec<_>>()
         .join("\n")
@@ -389,8 +446,11 @@ pub fn format_branch(branch: &Value) -> String {
     format!("- {name} (`{sha}`){prot_str}")
 }
 
-/// Format a file content response.
-pub fn format_file_content(file: &Value) -> String {
+/// Default cap on decoded file content returned by `format_file_content`.
+pub const DEFAULT_MAX_FILE_BYTES: usize = 100_000;
+
+/// Format a file content response, truncating decoded content beyond `max_bytes`.
+pub fn format_file_content(file: &Value, max_bytes: usize) -> String {
     let name = file
         .get("name")
         .and_then(|v| v.as_str())
@@ -421,6 +481,7 @@ pub fn format_file_content(file: &Value) -> String {
             .decode(&clean)
             .ok()
             .and_then(|bytes| String::from_utf8(bytes).ok())
+            .map(|text| truncate_content(text, max_bytes))
             .unwrap_or_else(|| "(binary content)".to_string())
     } else {
         "(empty file)".to_string()
@@ -432,7 +493,93 @@ pub fn format_file_content(file: &Value) -> String {
         .and_then(|v| v.as_str())
         .map(|s| format!("\n**SHA:** {s}"))
         .unwrap_or_default();
-    format!("**File:** {path} ({size} bytes){sha_line}\n\n```\n{decoded}\n```")
+    let lang = language_for_path(path).unwrap_or("");
+    format!("**File:** {path} ({size} bytes){sha_line}\n\n```{lang}\n{decoded}\n```")
+}
+
+/// Format raw file text (from `file_read_raw`) in a fenced block tagged with its language.
+pub fn format_raw_file(path: &str, text: String, max_bytes: usize) -> String {
+    let size = text.len();
+    let lang = language_for_path(path).unwrap_or("");
+    let text = truncate_content(text, max_bytes);
+    format!("**File:** {path} ({size} bytes)\n\n```{lang}\n{text}\n```")
+}
+
+/// Markdown code-fence language for a file path, based on its extension
+/// (or name, for files like `Dockerfile`). `None` for unknown types.
+pub fn language_for_path(path: &str) -> Option<&'static str> {
+    let name = path.rsplit('/').next().unwrap_or(path);
+    match name {
+        "Dockerfile" => return Some("dockerfile"),
+        "Makefile" | "makefile" | "GNUmakefile" => return Some("makefile"),
+        _ => {}
+    }
+    let ext = name.rsplit_once('.')?.1.to_ascii_lowercase();
+    let lang = match ext.as_str() {
+        "rs" => "rust",
+        "py" => "python",
+        "ts" => "typescript",
+        "tsx" => "tsx",
+        "js" | "mjs" | "cjs" => "javascript",
+        "jsx" => "jsx",
+        "go" => "go",
+        "java" => "java",
+        "kt" | "kts" => "kotlin",
+        "c" | "h" => "c",
+        "cpp" | "cc" | "cxx" | "hpp" => "cpp",
+        "cs" => "csharp",
+        "rb" => "ruby",
+        "php" => "php",
+        "swift" => "swift",
+        "sh" | "bash" => "bash",
+        "json" => "json",
+        "yml" | "yaml" => "yaml",
+        "toml" => "toml",
+        "md" => "markdown",
+        "html" | "htm" => "html",
+        "css" => "css",
+        "sql" => "sql",
+        "xml" => "xml",
+        _ => return None,
+    };
+    Some(lang)
+}
+
+/// Cut `text` to at most `max_bytes` (on a char boundary) and append a truncation marker.
+pub(crate) fn truncate_content(mut text: String, max_bytes: usize) -> String {
+    let total = text.len();
+    if total <= max_bytes {
+        return text;
+    }
+    let mut end = max_bytes;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    text.truncate(end);
+    text.push_str(&format!("\n... [truncated, {end} of {total} bytes shown]"));
+    text
+}
+
+/// Format a repository as a single list line: full name, stars, and description.
+pub fn format_repo_line(r: &Value) -> String {
+    let full_name = r
+        .get("full_name")
+        .and_then(|v| v.as_str())
+        .unwrap_or("?");
+    let desc = r
+        .get("description")
+        .and_then(|v| v.as_str())
+        .unwrap_or("");
+    let stars = r
+        .get("stars_count")
+        .or_else(|| r.get("stargazers_count"))
+        .and_then(|v| v.as_i64())
+        .unwrap_or(0);
+    if desc.is_empty() {
+        format!("- {full_name} ({stars} stars)")
+    } else {
+        format!("- {full_name} ({stars} stars) - {desc}")
+    }
 }
 
 /// Format a directory listing.
@@ -451,3 +598,281 @@ pub fn format_file_list(entries: &[Value]) -> String {
         .collect::<Vec<_>>()
         .join("\n")
 }
+
+/// Render a timestamp for display: the ISO value as-is, followed by a
+/// relative form like "(3 days ago)" when relative time is enabled.
+pub fn format_timestamp(iso: &str, settings: ToolSettings) -> String {
+    if !settings.relative_time {
+        return iso.to_string();
+    }
+    match relative_time_at(iso, now_unix()) {
+        Some(relative) => format!("{iso} ({relative})"),
+        None => iso.to_string(),
+    }
+}
+
+/// Render an ISO 8601 timestamp as "3 days ago" / "in 2 hours". Returns the
+/// input unchanged if it cannot be parsed.
+pub fn format_relative_time(iso: &str) -> String {
+    relative_time_at(iso, now_unix()).unwrap_or_else(|| iso.to_string())
+}
+
+fn now_unix() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+fn relative_time_at(iso: &str, now: i64) -> Option<String> {
+    let delta = now - parse_rfc3339(iso)?;
+    let secs = delta.unsigned_abs();
+    if secs < 10 {
+        return Some("just now".to_string());
+    }
+
+    let (amount, unit) = match secs {
+        0..=59 => (secs, "second"),
+        60..=3_599 => (secs / 60, "minute"),
+        3_600..=86_399 => (secs / 3_600, "hour"),
+        86_400..=604_799 => (secs / 86_400, "day"),
+        604_800..=2_591_999 => (secs / 604_800, "week"),
+        2_592_000..=31_535_999 => (secs / 2_592_000, "month"),
+        _ => (secs / 31_536_000, "year"),
+    };
+    let plural = if amount == 1 { "" } else { "s" };
+    Some(if delta >= 0 {
+        format!("{amount} {unit}{plural} ago")
+    } else {
+        format!("in {amount} {unit}{plural}")
+    })
+}
+
+/// Parse `YYYY-MM-DDTHH:MM:SS[.fff](Z|±HH:MM)` into seconds since the Unix epoch.
+fn parse_rfc3339(iso: &str) -> Option<i64> {
+    let num = |range: std::ops::Range<usize>| iso.get(range)?.parse::<i64>().ok();
+    let bytes = iso.as_bytes();
+    if bytes.len() < 20 || bytes[4] != b'-' || bytes[7] != b'-' || !matches!(bytes[10], b'T' | b' ') {
+        return None;
+    }
+    let (year, month, day) = (num(0..4)?, num(5..7)?, num(8..10)?);
+    let (hour, minute, second) = (num(11..13)?, num(14..16)?, num(17..19)?);
+
+    // Skip fractional seconds, then read the UTC offset.
+    let rest = iso[19..].trim_start_matches(|c: char| c == '.' || c.is_ascii_digit());
+    let offset = match rest {
+        "Z" | "z" => 0,
+        _ if rest.len() == 6 && rest.starts_with(['+', '-']) => {
+            let sign = if rest.starts_with('-') { -1 } else { 1 };
+            let hours: i64 = rest.get(1..3)?.parse().ok()?;
+            let minutes: i64 = rest.get(4..6)?.parse().ok()?;
+            sign * (hours * 3_600 + minutes * 60)
+        }
+        _ => return None,
+    };
+
+    Some(days_from_civil(year, month, day) * 86_400 + hour * 3_600 + minute * 60 + second - offset)
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date (Howard Hinnant's algorithm).
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y.rem_euclid(400);
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Format seconds since the Unix epoch as `YYYY-MM-DDTHH:MM:SSZ`.
+pub fn rfc3339_utc(unix_secs: u64) -> String {
+    let days = (unix_secs / 86_400) as i64;
+    let secs = unix_secs % 86_400;
+
+    // Civil-from-days (Howard Hinnant's algorithm).
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        secs / 3_600,
+        secs % 3_600 / 60,
+        secs % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::Engine;
+
+    const NOW: i64 = 1_714_557_600; // 2024-05-01T10:00:00Z
+
+    fn sample_issue() -> Value {
+        serde_json::json!({
+            "number": 7,
+            "title": "Crash on save",
+            "state": "open",
+            "user": { "login": "alice" },
+            "labels": [{ "name": "bug" }, { "name": "ui" }],
+            "created_at": "2024-04-30T10:00:00Z",
+            "body": "Steps to reproduce",
+        })
+    }
+
+    #[test]
+    fn issue_verbose_layout() {
+        assert_eq!(
+            format_issue(&sample_issue(), ToolSettings::default()),
+            "## #7 Crash on save [open]\n**Author:** alice\n**Labels:** bug, ui\n**Created:** 2024-04-30T10:00:00Z\n\nSteps to reproduce"
+        );
+    }
+
+    #[test]
+    fn issue_compact_layout() {
+        assert_eq!(
+            format_issue(&sample_issue(), ToolSettings { compact: true, ..ToolSettings::default() }),
+            "#7 Crash on save [open] · Author: alice · Labels: bug, ui · Created: 2024-04-30T10:00:00Z\n\nSteps to reproduce"
+        );
+    }
+
+    #[test]
+    fn object_compact_layout() {
+        let val = serde_json::json!({ "name": "v1.0", "draft": false, "author": { "login": "bob" }, "note": "" });
+        assert_eq!(format_object(&val, false), "**author:** bob\n**draft:** false\n**name:** v1.0");
+        assert_eq!(format_object(&val, true), "author: bob · draft: false · name: v1.0");
+    }
+
+    #[test]
+    fn relative_time_buckets() {
+        assert_eq!(relative_time_at("2024-05-01T09:59:15Z", NOW).as_deref(), Some("45 seconds ago"));
+        assert_eq!(relative_time_at("2024-05-01T07:00:00Z", NOW).as_deref(), Some("3 hours ago"));
+        assert_eq!(relative_time_at("2024-04-30T10:00:00Z", NOW).as_deref(), Some("1 day ago"));
+        assert_eq!(relative_time_at("2024-04-10T10:00:00Z", NOW).as_deref(), Some("3 weeks ago"));
+        assert_eq!(relative_time_at("2024-05-01T12:00:00+02:00", NOW).as_deref(), Some("just now"));
+        assert_eq!(relative_time_at("2024-05-03T10:00:00.123Z", NOW).as_deref(), Some("in 2 days"));
+        assert_eq!(relative_time_at("yesterday", NOW), None);
+        // A multi-byte character where the offset should be is rejected, not sliced.
+        assert_eq!(relative_time_at("2024-05-01T10:00:00é:000", NOW), None);
+    }
+
+    #[test]
+    fn timestamps_follow_the_settings_passed_in() {
+        let relative = ToolSettings { relative_time: true, ..ToolSettings::default() };
+        assert_eq!(format_timestamp("2024-05-01T10:00:00Z", ToolSettings::default()), "2024-05-01T10:00:00Z");
+        assert!(format_timestamp("2024-05-01T10:00:00Z", relative).starts_with("2024-05-01T10:00:00Z ("));
+    }
+
+    #[test]
+    fn rfc3339_round_trip() {
+        assert_eq!(rfc3339_utc(0), "1970-01-01T00:00:00Z");
+        assert_eq!(rfc3339_utc(951_825_599), "2000-02-29T11:59:59Z");
+        assert_eq!(parse_rfc3339(&rfc3339_utc(NOW as u64)), Some(NOW));
+    }
+
+    #[test]
+    fn pr_changes_line() {
+        let pr = serde_json::json!({ "additions": 120, "deletions": 30, "changed_files": 7 });
+        assert_eq!(format_pr_changes(&pr).as_deref(), Some("+120 -30 across 7 files"));
+        let pr = serde_json::json!({ "changed_files": 1 });
+        assert_eq!(format_pr_changes(&pr).as_deref(), Some("1 file"));
+        assert_eq!(format_pr_changes(&serde_json::json!({ "additions": 5 })), None);
+    }
+
+    #[test]
+    fn commit_list_shows_stats_when_present() {
+        let commits = vec![
+            serde_json::json!({
+                "sha": "abcdef1234567",
+                "commit": { "message": "Add parser\n\nDetails" },
+                "stats": { "total": 15, "additions": 12, "deletions": 3 },
+            }),
+            serde_json::json!({
+                "sha": "1234567abcdef",
+                "commit": { "message": "Fix typo" },
+            }),
+        ];
+        assert_eq!(
+            format_commit_list(&commits),
+            "- `abcdef1` Add parser (+12 -3)\n- `1234567` Fix typo"
+        );
+    }
+
+    fn file_with(content: &str) -> Value {
+        serde_json::json!({
+            "name": "big.txt",
+            "path": "big.txt",
+            "type": "file",
+            "size": content.len(),
+            "content": base64::engine::general_purpose::STANDARD.encode(content),
+        })
+    }
+
+    #[test]
+    fn render_json_returns_raw_object() {
+        let val = serde_json::json!({ "number": 7, "user": { "login": "alice" } });
+        let out = render(&val, Some("json"), |v| format_value(v, ToolSettings::default()));
+        assert!(out.starts_with("```json\n"));
+        assert!(out.contains("\"login\": \"alice\""));
+        assert_eq!(render(&val, None, |v| format_value(v, ToolSettings::default())), format_value(&val, ToolSettings::default()));
+    }
+
+    #[test]
+    fn file_content_truncated_past_limit() {
+        let out = format_file_content(&file_with(&"a".repeat(250)), 100);
+        assert!(out.contains("... [truncated, 100 of 250 bytes shown]"));
+        assert!(!out.contains(&"a".repeat(101)));
+    }
+
+    #[test]
+    fn file_content_under_limit_untouched() {
+        let out = format_file_content(&file_with("hello"), 100);
+        assert!(out.contains("hello"));
+        assert!(!out.contains("truncated"));
+    }
+
+    #[test]
+    fn commit_signature_rendered_when_present() {
+        let mut commit = serde_json::json!({
+            "sha": "abc",
+            "commit": { "message": "Fix", "verification": { "verified": true, "reason": "valid" } },
+        });
+        assert!(format_commit(&commit).contains("**Signature:** verified (valid)"));
+        commit["commit"]["verification"] = serde_json::json!({ "verified": false, "reason": "unsigned" });
+        assert!(format_commit(&commit).contains("**Signature:** unverified (unsigned)"));
+        commit["commit"].as_object_mut().unwrap().remove("verification");
+        assert!(!format_commit(&commit).contains("Signature"));
+    }
+
+    #[test]
+    fn language_hint_from_extension() {
+        assert_eq!(language_for_path("src/main.rs"), Some("rust"));
+        assert_eq!(language_for_path("web/App.TSX"), Some("tsx"));
+        assert_eq!(language_for_path("deploy/Dockerfile"), Some("dockerfile"));
+        assert_eq!(language_for_path("LICENSE"), None);
+        assert_eq!(language_for_path("data.bin"), None);
+    }
+
+    #[test]
+    fn file_content_fence_uses_language() {
+        let mut file = file_with("fn main() {}");
+        file["path"] = Value::from("src/main.rs");
+        assert!(format_file_content(&file, 100).contains("```rust\nfn main() {}\n```"));
+        assert!(format_file_content(&file_with("hello"), 100).contains("```\nhello"));
+    }
+
+    #[test]
+    fn truncation_respects_char_boundaries() {
+        let out = truncate_content("ééé".to_string(), 3);
+        assert!(out.starts_with("é\n"));
+        assert!(out.contains("[truncated, 2 of 6 bytes shown]"));
+    }
+}
@@ -14,59 +14,226 @@ use crate::repo_resolver;
 
 // Tool parameter types
 use crate::tools::actions::{
-    ActionsJobLogsParams, ActionsRunGetParams, ActionsRunListParams, ActionsWorkflowListParams,
+    ActionsArtifactDownloadUrlParams, ActionsJobLogsParams, ActionsRunArtifactsParams,
+    ActionsRunGetParams, ActionsRunJobsParams, ActionsRunListParams, ActionsWorkflowDispatchParams,
+    ActionsWorkflowListParams, ActionsWorkflowToggleParams,
 };
 use crate::tools::branches::{
-    BranchCreateParams, BranchDeleteParams, BranchListParams, BranchProtectionCreateParams,
-    BranchProtectionListParams,
+    BranchCreateParams, BranchDeleteParams, BranchListParams, BranchMergeParams, BranchProtectionCreateParams,
+    BranchProtectionDeleteParams, BranchProtectionEditParams, BranchProtectionListParams,
+};
+use crate::tools::commits::{
+    CommitCompareParams, CommitCreateParams, CommitDiffParams, CommitGetParams, CommitListParams, CommitSearchParams,
 };
-use crate::tools::commits::{CommitCompareParams, CommitDiffParams, CommitGetParams, CommitListParams};
 use crate::tools::files::{
-    FileCreateParams, FileDeleteParams, FileListParams, FileReadParams, FileUpdateParams,
-    TreeGetParams,
+    FileCreateParams, FileDeleteParams, FileDownloadParams, FileListParams, FileMoveParams,
+    FileReadParams, FileReadRawParams, FileUpdateParams, TreeGetParams,
+};
+use crate::tools::files_batch::FileCommitBatchParams;
+use crate::tools::graphql::GithubGraphqlParams;
+use crate::tools::issue_comments::{
+    IssueCommentCreateParams, IssueCommentDeleteParams, IssueCommentEditParams, IssueCommentListParams,
+};
+use crate::tools::issues::{
+    IssueAssigneesParams, IssueCreateParams, IssueDependenciesParams, IssueEditParams, IssueEventsParams,
+    IssueGetManyParams, IssueGetParams, IssueListParams, IssueSearchParams, IssueStateParams,
+    IssueTemplatesListParams, IssueTimeAddParams, IssueTimesListParams,
+};
+use crate::tools::labels::{
+    IssueAddLabelsParams, IssueRemoveLabelParams, LabelCreateBatchParams, LabelCreateParams, LabelEditParams, LabelListParams,
+    OrgLabelsParams,
 };
-use crate::tools::issue_comments::{IssueCommentCreateParams, IssueCommentListParams};
-use crate::tools::issues::{IssueCreateParams, IssueEditParams, IssueGetParams, IssueListParams};
-use crate::tools::labels::{LabelCreateParams, LabelEditParams, LabelListParams};
 use crate::tools::milestones::{MilestoneCreateParams, MilestoneGetParams, MilestoneListParams};
-use crate::tools::notifications::{NotificationListParams, NotificationMarkReadParams};
-use crate::tools::orgs::{OrgGetParams, OrgListParams, OrgTeamsParams};
-use crate::tools::pull_files::{PrDiffParams, PrFilesParams};
-use crate::tools::pull_reviews::{PrReviewCreateParams, PrReviewListParams};
-use crate::tools::pulls::{PrCreateParams, PrEditParams, PrGetParams, PrListParams, PrMergeParams};
-use crate::tools::releases::{ReleaseCreateParams, ReleaseGetParams, ReleaseListParams};
-use crate::tools::repo::{RepoGetParams, RepoSearchParams};
-use crate::tools::tags::{TagCreateParams, TagListParams};
-use crate::tools::users::{UserGetMeParams, UserGetParams};
+use crate::tools::notifications::{NotificationListParams, NotificationMarkReadParams, NotificationThreadGetParams};
+use crate::tools::orgs::{
+    OrgGetParams, OrgListParams, OrgTeamsParams, TeamMemberParams, TeamMembersParams,
+};
+use crate::tools::pull_files::{PrCommitsParams, PrDiffParams, PrDiffstatParams, PrFilesParams};
+use crate::tools::pull_reviews::{PrReviewCreateParams, PrReviewListParams, PrReviewersParams};
+use crate::tools::pulls::{
+    PrCreateParams, PrEditParams, PrGetParams, PrListParams, PrMarkReadyParams, PrMergeCheckParams, PrMergeParams,
+    PrStateParams,
+};
+use crate::tools::reactions::{ReactionAddParams, ReactionListParams};
+use crate::tools::releases::{
+    ReleaseCreateParams, ReleaseGetParams, ReleaseLatestParams, ReleaseListParams,
+};
+use crate::tools::repo::{
+    RepoArchiveParams, RepoEditParams, ForkSyncParams, RepoForkParams, RepoGetParams, RepoLanguagesParams, RepoListParams,
+    RepoSearchParams, RepoStarParams, RepoTopicsGetParams, RepoTopicsSetParams, RepoTransferParams,
+    RepoUnwatchParams, RepoUserListParams, RepoWatchParams,
+};
+use crate::tools::tags::{TagCreateParams, TagGetParams, TagListParams};
+use crate::tools::users::{StatusParams, UserGetMeParams, UserGetParams, UserReposParams, UserStarredParams};
 use crate::tools::wiki::{WikiCreateParams, WikiGetParams, WikiListParams};
 
 const RESOURCE_URI: &str = "repo://detected";
+/// Prefix of the per-remote resources, followed by the remote name.
+const REMOTE_RESOURCE_PREFIX: &str = "repo://remote/";
 
-/// The gitx-mcp server. Holds the HTTP client and routes all 43 tools.
+/// The gitx-mcp server. Holds the HTTP client and routes all 125 tools.
 #[derive(Debug, Clone)]
 pub struct GitxMcp {
     client: Arc<dyn GitClient>,
     tool_router: ToolRouter<Self>,
     detected_repo: Option<repo_resolver::RepoInfo>,
+    /// Default branch, clone URLs, and visibility of `detected_repo`, fetched
+    /// once at startup by `fetch_repo_details`.
+    repo_details: Option<serde_json::Map<String, serde_json::Value>>,
+    /// Every remote of the working directory's repository, by name, each
+    /// exposed as a `repo://remote/{name}` resource.
+    remotes: Vec<(String, repo_resolver::RepoInfo)>,
+    config: Config,
+}
+
+/// Per-call instance override accepted by read tools, for operators who
+/// work against more than one instance of the configured platform.
+#[derive(Debug, Default, serde::Deserialize, schemars::JsonSchema)]
+pub struct InstanceOverride {
+    /// Base URL of another instance to query for this call only (e.g. "https://git.other.com"). Requires `token` and explicit `owner`/`repo`; the host must be allowed by `GITX_ALLOWED_HOSTS`.
+    pub base_url: Option<String>,
+    /// API token to use for this call only.
+    pub token: Option<String>,
+}
+
+/// Tools that create, change, or delete anything on the instance. With
+/// `GITX_READ_ONLY` set they are left out of the tool list entirely.
+pub const MUTATING_TOOLS: &[&str] = &[
+    "issue_create",
+    "issue_edit",
+    "issue_close",
+    "issue_reopen",
+    "issue_add_assignees",
+    "issue_remove_assignees",
+    "issue_add_labels",
+    "issue_remove_label",
+    "issue_time_add",
+    "issue_comment_create",
+    "issue_comment_edit",
+    "issue_comment_delete",
+    "reaction_add",
+    "pr_create",
+    "pr_edit",
+    "pr_close",
+    "pr_reopen",
+    "pr_merge",
+    "pr_mark_ready",
+    "pr_review_create",
+    "pr_request_reviewers",
+    "pr_remove_reviewers",
+    "file_create",
+    "file_update",
+    "file_delete",
+    "file_move",
+    "file_commit_batch",
+    "branch_create",
+    "branch_delete",
+    "branch_merge",
+    "branch_protection_create",
+    "branch_protection_edit",
+    "branch_protection_delete",
+    "commit_create",
+    "label_create",
+    "label_create_batch",
+    "label_edit",
+    "milestone_create",
+    "notification_mark_read",
+    "release_create",
+    "repo_edit",
+    "repo_transfer",
+    "repo_archive",
+    "repo_unarchive",
+    "repo_watch",
+    "repo_unwatch",
+    "repo_star",
+    "repo_unstar",
+    "repo_topics_set",
+    "repo_fork",
+    "fork_sync",
+    "tag_create",
+    "wiki_create",
+    "team_add_member",
+    "team_remove_member",
+    "actions_workflow_dispatch",
+    "actions_workflow_enable",
+    "actions_workflow_disable",
+    "github_graphql",
+];
+
+/// Build the platform client for `config`, wrapped for dry runs when enabled.
+fn build_client(config: &Config) -> std::result::Result<Arc<dyn GitClient>, GitxError> {
+    let client: Arc<dyn GitClient> = match config.platform {
+        Platform::Gitea => Arc::new(GiteaClient::new(config)?),
+        Platform::GitHub => Arc::new(crate::client::GitHubClient::new(config)?),
+        Platform::GitLab => Arc::new(crate::client::GitLabClient::new(config)?),
+    };
+    if config.dry_run {
+        return Ok(Arc::new(crate::client::DryRunClient::new(client)));
+    }
+    Ok(client)
+}
+
+/// Refuse a per-call `base_url` unless it is an http(s) URL whose host is the
+/// configured instance's or one the operator listed in `GITX_ALLOWED_HOSTS`,
+/// so a prompt cannot point the server at internal addresses.
+fn check_allowed_host(base_url: &str, config: &Config) -> std::result::Result<(), GitxError> {
+    let url = url::Url::parse(base_url)
+        .map_err(|e| GitxError::InvalidParam(format!("base_url {base_url} is not a valid URL: {e}")))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(GitxError::InvalidParam(format!("base_url must be an http or https URL, got {base_url}")));
+    }
+    let host = url.host_str().unwrap_or_default().to_ascii_lowercase();
+    let own_host = url::Url::parse(&config.base_url)
+        .ok()
+        .and_then(|u| u.host_str().map(|h| h.to_ascii_lowercase()));
+    if own_host.as_deref() == Some(host.as_str()) || config.allowed_hosts.contains(&host) {
+        return Ok(());
+    }
+    Err(GitxError::InvalidParam(format!(
+        "base_url host {host} is not allowed; the operator must add it to GITX_ALLOWED_HOSTS"
+    )))
 }
 
 /// Resolve owner/repo from tool params — either explicit, from directory auto-detection,
 /// or from the server's startup-detected default.
+///
+/// `remote` selects which git remote to read during directory detection
+/// (defaults to `origin`, falling back to the first remote). With `strict`
+/// (`GITX_STRICT_REPO`) the server's working directory is never used and the
+/// cwd fallback is an error.
 pub fn resolve_owner_repo(
     owner: &Option<String>,
     repo: &Option<String>,
     directory: &Option<String>,
+    remote: &Option<String>,
     default_repo: Option<&repo_resolver::RepoInfo>,
+    strict: bool,
 ) -> std::result::Result<(String, String), GitxError> {
     // 1. Explicit owner+repo
     match (owner, repo) {
+        (Some(o), Some(r)) if o.contains('/') || r.contains('/') => {
+            return Err(GitxError::InvalidParam(format!(
+                "owner and repo must be single path segments (got \"{o}/{r}\"); nested GitLab groups are not supported"
+            )));
+        }
         (Some(o), Some(r)) if !o.is_empty() && !r.is_empty() => return Ok((o.clone(), r.clone())),
         _ => {}
     }
 
-    // 2. Explicit directory
-    if let Some(dir) = directory.as_deref().filter(|d| !d.is_empty()) {
-        let info = repo_resolver::resolve_repo(dir)?;
+    let remote = remote.as_deref().filter(|r| !r.is_empty());
+
+    // 2. Explicit directory (an explicit remote alone means the current
+    // directory, which strict mode never reads)
+    let directory = directory.as_deref().filter(|d| !d.is_empty());
+    if strict && directory.is_none() && remote.is_some() {
+        return Err(GitxError::MissingParam(
+            "directory (or owner/repo) required with remote; strict mode never reads the server's working directory"
+                .to_string(),
+        ));
+    }
+    let dir = directory.or(remote.map(|_| "."));
+    if let Some(dir) = dir {
+        let info = repo_resolver::resolve_repo_with_remote(dir, remote)?;
         return Ok((info.owner, info.repo));
     }
 
@@ -75,64 +242,249 @@ pub fn resolve_owner_repo(
         return Ok((info.owner.clone(), info.repo.clone()));
     }
 
-    // 4. Last resort — cwd detection
+    // 4. Last resort — cwd detection, which may be the wrong repo entirely
+    if strict {
+        return Err(GitxError::MissingParam(
+            "owner/repo required; no repo specified and strict mode is on".to_string(),
+        ));
+    }
     let info = repo_resolver::resolve_repo(".")?;
     Ok((info.owner, info.repo))
 }
 
+/// The `repo://detected` fields taken from a repository object. GitLab's
+/// field names are used as a fallback for the clone URLs and visibility.
+fn repo_details(repo: &serde_json::Value) -> serde_json::Map<String, serde_json::Value> {
+    let field = |keys: &[&str]| {
+        keys.iter()
+            .find_map(|k| repo.get(*k).and_then(|v| v.as_str()))
+            .map(|v| serde_json::Value::String(v.to_string()))
+    };
+    let visibility = field(&["visibility"]).or_else(|| {
+        repo.get("private")
+            .and_then(|v| v.as_bool())
+            .map(|private| serde_json::Value::String(if private { "private" } else { "public" }.to_string()))
+    });
+
+    [
+        ("default_branch", field(&["default_branch"])),
+        ("clone_url", field(&["clone_url", "http_url_to_repo"])),
+        ("ssh_url", field(&["ssh_url", "ssh_url_to_repo"])),
+        ("visibility", visibility),
+    ]
+    .into_iter()
+    .filter_map(|(key, value)| Some((key.to_string(), value?)))
+    .collect()
+}
+
 /// Helper to convert our Result<CallToolResult> to the ErrorData variant.
 fn map_err(r: crate::error::Result<CallToolResult>) -> Result<CallToolResult, ErrorData> {
-    r.map_err(ErrorData::from)
+    match r {
+        // A write stopped by GITX_DRY_RUN is the expected outcome, not a failure.
+        Err(GitxError::DryRun(preview)) => Ok(CallToolResult::success(vec![Content::text(preview)])),
+        r => r.map_err(ErrorData::from),
+    }
 }
 
 #[tool_router]
 impl GitxMcp {
     pub fn new(config: Config) -> std::result::Result<Self, GitxError> {
-        let client: Arc<dyn GitClient> = match config.platform {
-            Platform::Gitea => Arc::new(GiteaClient::new(&config)?),
-            Platform::GitHub => Arc::new(crate::client::GitHubClient::new(&config)?),
-        };
+        let client = build_client(&config)?;
 
-        let detected_repo = match repo_resolver::resolve_repo(".") {
+        // Strict mode only takes a default from GITX_DEFAULT_OWNER/GITX_DEFAULT_REPO.
+        let cwd_repo = if config.strict_repo {
+            Err(GitxError::RepoResolution("cwd detection disabled by GITX_STRICT_REPO".to_string()))
+        } else {
+            repo_resolver::resolve_repo(".")
+        };
+        let detected_repo = match cwd_repo {
             Ok(info) => {
                 tracing::info!("Auto-detected repository: {}/{}", info.owner, info.repo);
                 Some(info)
             }
             Err(e) => {
                 tracing::debug!("No repository detected in cwd: {e}");
-                None
+                match (&config.default_owner, &config.default_repo) {
+                    (Some(owner), Some(repo)) => {
+                        tracing::info!("Using default repository from environment: {owner}/{repo}");
+                        Some(repo_resolver::RepoInfo {
+                            owner: owner.clone(),
+                            repo: repo.clone(),
+                        })
+                    }
+                    _ => None,
+                }
             }
         };
 
+        let remotes = repo_resolver::resolve_all_remotes(".").unwrap_or_default();
+
+        let mut tool_router = Self::tool_router();
+        if config.read_only {
+            for name in MUTATING_TOOLS {
+                tool_router.remove_route(name);
+            }
+            tracing::info!("Read-only mode: {} mutating tools disabled", MUTATING_TOOLS.len());
+        }
+
         Ok(Self {
             client,
-            tool_router: Self::tool_router(),
+            tool_router,
             detected_repo,
+            repo_details: None,
+            remotes,
+            config,
         })
     }
 
+    /// Look up the detected repository once so `repo://detected` can include
+    /// its default branch, clone URLs, and visibility. Failures are logged and
+    /// leave the resource with just owner/repo.
+    pub async fn fetch_repo_details(&mut self) {
+        let Some(info) = &self.detected_repo else {
+            return;
+        };
+        match self
+            .client
+            .get_json(&format!("/repos/{}/{}", info.owner, info.repo))
+            .await
+        {
+            Ok(repo) => self.repo_details = Some(repo_details(&repo)),
+            Err(e) => tracing::warn!("Could not fetch details of {}/{}: {e}", info.owner, info.repo),
+        }
+    }
+
+    /// The client and default repository to use for a call: the shared ones,
+    /// or a throwaway client when the call overrides the instance or token.
+    /// Another instance has no default repository, so it needs an explicit
+    /// `owner`/`repo`, and its host must be listed in `GITX_ALLOWED_HOSTS`.
+    fn client_for(
+        &self,
+        instance: &InstanceOverride,
+        owner: &Option<String>,
+        repo: &Option<String>,
+    ) -> Result<(Arc<dyn GitClient>, Option<&repo_resolver::RepoInfo>), ErrorData> {
+        let base_url = instance.base_url.as_deref().filter(|u| !u.is_empty());
+        let token = instance.token.as_deref().filter(|t| !t.is_empty());
+        if base_url.is_none() && token.is_none() {
+            return Ok((self.client.clone(), self.detected_repo.as_ref()));
+        }
+        // Never send the configured token to a different host.
+        let Some(token) = token else {
+            return Err(GitxError::MissingParam(
+                "token (required when base_url is overridden)".to_string(),
+            )
+            .into());
+        };
+
+        let mut config = self.config.clone();
+        let mut detected = self.detected_repo.as_ref();
+        if let Some(base_url) = base_url {
+            check_allowed_host(base_url, &self.config)?;
+            let explicit = |v: &Option<String>| v.as_deref().is_some_and(|v| !v.is_empty());
+            if !explicit(owner) || !explicit(repo) {
+                return Err(GitxError::MissingParam(
+                    "owner and repo (required when base_url is overridden)".to_string(),
+                )
+                .into());
+            }
+            config.base_url = base_url.trim_end_matches('/').to_string();
+            detected = None;
+        }
+        config.token = token.to_string();
+        config.github_app = None;
+        Ok((build_client(&config)?, detected))
+    }
+
     // ── Issues ──────────────────────────────────────────────────────
 
-    #[tool(description = "Use this when you need to list issues in a repository. Returns issue numbers, titles, states, and labels. Supports filtering by state (open/closed) and labels. Only returns issues (not pull requests). Use issue_get for full details of a specific issue.")]
+    #[tool(description = "Use this when you need to list issues in a repository. Returns issue numbers, titles, states, and labels. Supports filtering by state (open/closed) and labels, and sorting by created, updated, or comments (on Gitea only the returned page is sorted). Only returns issues (not pull requests). Use issue_get for full details of a specific issue.")]
     async fn issue_list(&self, Parameters(p): Parameters<IssueListParams>) -> Result<CallToolResult, ErrorData> {
-        map_err(crate::tools::issues::issue_list(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+        let (client, detected) = self.client_for(&p.instance, &p.owner, &p.repo)?;
+        map_err(crate::tools::issues::issue_list(client.as_ref(), p, detected).await)
+    }
+
+    #[tool(description = "Use this when you need to find issues or pull requests by text, author, or assignee (e.g. 'open bugs reported by alice mentioning crash'). Filters: keyword (full-text), author, assignee, state (open/closed/all, default open), labels (comma-separated), and kind ('issues' or 'pulls'; both by default). Returns number, title, state, and labels for each match. Use issue_list for simple state/label listing, and issue_get or pr_get for full details.")]
+    async fn issue_search(&self, Parameters(p): Parameters<IssueSearchParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::issues::issue_search(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
     }
 
-    #[tool(description = "Use this when you need to get the full details of a specific issue including its body, labels, assignees, and milestone. Requires the issue number. Returns number, title, state, body, labels, assignees, milestone, and timestamps. Use issue_comment_list to see comments on the issue.")]
+    #[tool(description = "Use this when you need to understand how an issue or pull request relates to other tickets. On Gitea, lists the issues it is blocked by and the issues it blocks, as tracked by the API. On every platform, also resolves the issues its body closes via keywords like 'Closes #12' or 'Fixes owner/repo#7'; those are parsed from the text, so edits or other link styles are not seen. Returns number, title, and state for each linked item, with each section marked as API-tracked or body-parsed.")]
+    async fn issue_dependencies(&self, Parameters(p): Parameters<IssueDependenciesParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::issues::issue_dependencies(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to log time spent on an issue or pull request with Gitea's built-in time tracking. Provide the time in seconds (e.g. 5400 for 1h 30m). Gitea only; on GitHub, which has no time-tracking API, this returns an explanatory message.")]
+    async fn issue_time_add(&self, Parameters(p): Parameters<IssueTimeAddParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::issues::issue_time_add(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to see the time logged on an issue or pull request with Gitea's built-in time tracking, optionally only one user's entries. Returns user, duration, and date for each entry, plus the total for the page. Gitea only; on GitHub this returns an explanatory message.")]
+    async fn issue_times_list(&self, Parameters(p): Parameters<IssueTimesListParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::issues::issue_times_list(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to get the full details of a specific issue including its body, labels, assignees, and milestone. Requires the issue number. Returns number, title, state, body, labels, assignees, milestone, and timestamps. Use issue_comment_list to see comments on the issue. Set format='json' to get the raw API object instead of markdown.")]
     async fn issue_get(&self, Parameters(p): Parameters<IssueGetParams>) -> Result<CallToolResult, ErrorData> {
-        map_err(crate::tools::issues::issue_get(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+        let (client, detected) = self.client_for(&p.instance, &p.owner, &p.repo)?;
+        map_err(crate::tools::issues::issue_get(client.as_ref(), p, detected).await)
+    }
+
+    #[tool(description = "Use this when you need the full details of several issues at once, e.g. after issue_list. Provide up to 50 issue numbers; they are fetched in parallel and returned in the order given. An issue that can't be fetched shows its error without failing the others.")]
+    async fn issue_get_many(&self, Parameters(p): Parameters<IssueGetManyParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::issues::issue_get_many(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
     }
 
-    #[tool(description = "Use this when you need to create a new issue in a repository. Provide a title and optionally a body, labels, milestone, and assignees. On Gitea, labels and milestone require numeric IDs — use label_list and milestone_list to look them up first. On GitHub, labels are names (strings). Returns the created issue details. Fails with 404 if the repository is not found, or 403 if you lack permission.")]
+    #[tool(description = "Use this when you need the history of an issue or pull request — who labeled, assigned, referenced, renamed, closed, or reopened it, and when. Returns the timeline events in order with type, actor, and timestamp. Paginated with page/limit. Use issue_comment_list for the comment text itself.")]
+    async fn issue_events(&self, Parameters(p): Parameters<IssueEventsParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::issues::issue_events(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to create a new issue in a repository. Provide a title and optionally a body, labels, milestone, and assignees. Set template to a markdown template file from issue_templates_list to start the body from it. On Gitea, labels and milestone require numeric IDs — use label_list and milestone_list to look them up first. On GitHub, labels are names (strings). Returns the created issue details. Fails with 404 if the repository is not found, or 403 if you lack permission.")]
     async fn issue_create(&self, Parameters(p): Parameters<IssueCreateParams>) -> Result<CallToolResult, ErrorData> {
         map_err(crate::tools::issues::issue_create(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
     }
 
+    #[tool(description = "Use this when you need to file an issue that follows the repository's conventions. Lists the issue templates under .gitea/ISSUE_TEMPLATE or .github/ISSUE_TEMPLATE with their names and default titles. Pass a markdown template's file name as issue_create's template parameter to start the issue body from it. Returns 'No issue templates found.' when the repository has none.")]
+    async fn issue_templates_list(&self, Parameters(p): Parameters<IssueTemplatesListParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::issues::issue_templates_list(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
     #[tool(description = "Use this when you need to update an existing issue — change its title, body, state (open/closed), labels, assignees, or milestone. On Gitea, labels and milestone require numeric IDs — use label_list and milestone_list to look them up first. On GitHub, labels are names (strings). Labels and assignees replace existing values (not additive). Returns the updated issue details.")]
     async fn issue_edit(&self, Parameters(p): Parameters<IssueEditParams>) -> Result<CallToolResult, ErrorData> {
         map_err(crate::tools::issues::issue_edit(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
     }
 
+    #[tool(description = "Use this when you need to close an issue. Takes just the issue number; simpler than issue_edit with a state. Returns the updated issue details.")]
+    async fn issue_close(&self, Parameters(p): Parameters<IssueStateParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::issues::issue_close(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to reopen a closed issue. Takes just the issue number. Returns the updated issue details.")]
+    async fn issue_reopen(&self, Parameters(p): Parameters<IssueStateParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::issues::issue_reopen(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to assign people to an issue or pull request without replacing its existing assignees. Provide the issue number and the usernames to add. Safer than issue_edit when others may be changing assignees concurrently. Returns the resulting assignee list.")]
+    async fn issue_add_assignees(&self, Parameters(p): Parameters<IssueAssigneesParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::issues::issue_add_assignees(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to unassign specific people from an issue or pull request while keeping the other assignees. Provide the issue number and the usernames to remove. Returns the resulting assignee list.")]
+    async fn issue_remove_assignees(&self, Parameters(p): Parameters<IssueAssigneesParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::issues::issue_remove_assignees(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to add labels to an issue or pull request without removing the ones it already has, e.g. to add a triage tag. Provide label names; on Gitea, names are resolved to IDs for you and numeric IDs from label_list also work. Returns the resulting label list.")]
+    async fn issue_add_labels(&self, Parameters(p): Parameters<IssueAddLabelsParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::labels::issue_add_labels(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to remove a single label from an issue or pull request while keeping its other labels. Provide the label name (or, on Gitea, its ID). Returns the remaining label list.")]
+    async fn issue_remove_label(&self, Parameters(p): Parameters<IssueRemoveLabelParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::labels::issue_remove_label(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
     // ── Issue Comments ──────────────────────────────────────────────
 
     #[tool(description = "Use this when you need to list all comments on a specific issue or pull request. Returns comment authors, dates, and bodies for each comment, or a message if no comments exist.")]
@@ -145,19 +497,43 @@ impl GitxMcp {
         map_err(crate::tools::issue_comments::issue_comment_create(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
     }
 
+    #[tool(description = "Use this when you need to correct or update a comment you posted on an issue or pull request, instead of adding a follow-up comment. Requires the comment ID (shown as 'Comment #ID' by issue_comment_list) and the new body in markdown. Returns the updated comment. Fails with 403 if you are not the author or lack permission, or 404 if the comment does not exist.")]
+    async fn issue_comment_edit(&self, Parameters(p): Parameters<IssueCommentEditParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::issue_comments::issue_comment_edit(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to delete a comment from an issue or pull request. Requires the comment ID (shown as 'Comment #ID' by issue_comment_list). This cannot be undone. Fails with 403 if you lack permission, or 404 if the comment does not exist.")]
+    async fn issue_comment_delete(&self, Parameters(p): Parameters<IssueCommentDeleteParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::issue_comments::issue_comment_delete(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    // ── Reactions ───────────────────────────────────────────────────
+
+    #[tool(description = "Use this when you need to acknowledge an issue, pull request, or comment with an emoji reaction (e.g. a thumbs-up) instead of posting a comment. Set target_type to 'issue' with the issue/PR number, or 'comment' with the comment ID from issue_comment_list. content must be one of: +1, -1, laugh, confused, heart, hooray, rocket, eyes. Returns a confirmation.")]
+    async fn reaction_add(&self, Parameters(p): Parameters<ReactionAddParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::reactions::reaction_add(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to see the emoji reactions on an issue, pull request, or comment. Set target_type to 'issue' with the issue/PR number, or 'comment' with the comment ID. Returns each reaction with its count and the users who reacted.")]
+    async fn reaction_list(&self, Parameters(p): Parameters<ReactionListParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::reactions::reaction_list(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
     // ── Pull Requests ───────────────────────────────────────────────
 
-    #[tool(description = "Use this when you need to list pull requests in a repository. Returns PR numbers, titles, states, and branch info. Supports filtering by state (open/closed/all, defaults to open). Use pr_get for full details of a specific PR.")]
+    #[tool(description = "Use this when you need to list pull requests in a repository. Returns PR numbers, titles, states, and branch info. Supports filtering by state (open/closed/all, defaults to open), base branch, head branch, and author, and sorting by created, updated, or popularity. Filters the server cannot apply (all three on Gitea, author on GitHub) are applied to the fetched page, and a note says how many PRs were scanned and matched. Use pr_get for full details of a specific PR.")]
     async fn pr_list(&self, Parameters(p): Parameters<PrListParams>) -> Result<CallToolResult, ErrorData> {
-        map_err(crate::tools::pulls::pr_list(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+        let (client, detected) = self.client_for(&p.instance, &p.owner, &p.repo)?;
+        map_err(crate::tools::pulls::pr_list(client.as_ref(), p, detected).await)
     }
 
-    #[tool(description = "Use this when you need to get full details of a specific pull request including branches, mergeable status, body, labels, and assignees. Returns number, title, state, head/base branches, mergeable status, body, labels, assignees, and timestamps. Check mergeable status here before calling pr_merge. Use pr_files for changed files or pr_diff for the full diff.")]
+    #[tool(description = "Use this when you need to get full details of a specific pull request including branches, mergeable status, body, labels, and assignees. Returns number, title, state, head/base branches, mergeable status, body, labels, assignees, and timestamps. Check mergeable status here before calling pr_merge. Use pr_files for changed files or pr_diff for the full diff. Set wait_for_mergeable=true right after creating or updating a PR: the tool re-checks up to 5 times, 2 seconds apart (about 8 seconds max), until the server has computed mergeability, and reports the final status first (with format='json', in the object's mergeable_status and mergeable_checks fields). Set format='json' to get the raw API object instead of markdown.")]
     async fn pr_get(&self, Parameters(p): Parameters<PrGetParams>) -> Result<CallToolResult, ErrorData> {
-        map_err(crate::tools::pulls::pr_get(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+        let (client, detected) = self.client_for(&p.instance, &p.owner, &p.repo)?;
+        map_err(crate::tools::pulls::pr_get(client.as_ref(), p, detected).await)
     }
 
-    #[tool(description = "Use this when you need to create a new pull request. Provide head branch (source), base branch (target), title, and optionally a body, labels, milestone, and assignees. On Gitea, labels require numeric IDs — use label_list to look them up first. On GitHub, labels are names (strings). The head branch must exist and have commits ahead of base. Returns the created PR details. Fails with 404 if branches don't exist, or 409 if a PR already exists for these branches.")]
+    #[tool(description = "Use this when you need to create a new pull request. Provide head branch (source), base branch (target), title, and optionally a body, labels, milestone, and assignees. On Gitea, labels require numeric IDs — use label_list to look them up first. On GitHub, labels are names (strings). Set draft=true to open it as a draft: GitHub uses its native draft flag, Gitea prefixes the title with 'WIP:' (Gitea's draft convention). The head branch must exist and have commits ahead of base. For a PR from a fork, set owner/repo to the upstream repository and head to 'user:branch'; on GitHub, maintainer_can_modify=true lets upstream maintainers push to it. Returns the created PR details. Fails with 404 if branches don't exist, or 409 if a PR already exists for these branches.")]
     async fn pr_create(&self, Parameters(p): Parameters<PrCreateParams>) -> Result<CallToolResult, ErrorData> {
         map_err(crate::tools::pulls::pr_create(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
     }
@@ -167,45 +543,97 @@ impl GitxMcp {
         map_err(crate::tools::pulls::pr_edit(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
     }
 
-    #[tool(description = "Use this when you need to merge a pull request. Supports merge, rebase, and squash strategies. Use pr_get first to verify the PR is mergeable. Fails with 405 if the PR is not mergeable (conflicts, missing reviews, etc.) or 404 if the PR does not exist.")]
+    #[tool(description = "Use this when you need to close a pull request without merging it. Takes just the PR number; simpler than pr_edit with a state. Returns the updated PR details.")]
+    async fn pr_close(&self, Parameters(p): Parameters<PrStateParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::pulls::pr_close(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to reopen a closed (unmerged) pull request. Takes just the PR number. Returns the updated PR details.")]
+    async fn pr_reopen(&self, Parameters(p): Parameters<PrStateParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::pulls::pr_reopen(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to merge a pull request. Supports merge, rebase, and squash strategies. Use pr_get first to verify the PR is mergeable. If the server refuses the merge, the error explains why: merge conflicts, missing approvals, failing required status checks, or a head branch that is out of date. Fails with 404 if the PR does not exist.")]
     async fn pr_merge(&self, Parameters(p): Parameters<PrMergeParams>) -> Result<CallToolResult, ErrorData> {
         map_err(crate::tools::pulls::pr_merge(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
     }
 
+    #[tool(description = "Use this when you need to know whether a pull request can be merged right now, without merging it. Gathers conflict status, approvals against the base branch's required count, changes requested, commit statuses and check runs on the head commit (including required checks that have not reported), and whether the head branch is behind base, then reports a yes/no/unknown verdict with the blockers (e.g. 'Blockers: 1 required approval missing; CI pending'). If any part cannot be read (e.g. branch protection without admin access) the verdict is unknown and says which. Use pr_merge to actually merge. Fails with 404 if the PR does not exist.")]
+    async fn pr_merge_check(&self, Parameters(p): Parameters<PrMergeCheckParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::pulls::pr_merge_check(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to mark a draft pull request as ready for review. On Gitea, strips the 'WIP:' / '[WIP]' title prefix that marks a draft. On GitHub this is NOT supported — the REST API has no endpoint for it (GraphQL only), so the tool returns an explanatory message without changing anything. Returns the updated PR details on Gitea.")]
+    async fn pr_mark_ready(&self, Parameters(p): Parameters<PrMarkReadyParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::pulls::pr_mark_ready(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
     // ── Pull Request Reviews ────────────────────────────────────────
 
-    #[tool(description = "Use this when you need to list reviews on a pull request. Returns review ID, reviewer username, state (APPROVED/CHANGES_REQUESTED/COMMENT), and body for each review.")]
+    #[tool(description = "Use this when you need to list reviews on a pull request. Paginated with page/limit; filter by state (APPROVED, CHANGES_REQUESTED, COMMENTED) to answer questions like who approved most recently. Returns review ID, reviewer username, state, submission time, and body for each review.")]
     async fn pr_review_list(&self, Parameters(p): Parameters<PrReviewListParams>) -> Result<CallToolResult, ErrorData> {
         map_err(crate::tools::pull_reviews::pr_review_list(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
     }
 
-    #[tool(description = "Use this when you need to submit a review on a pull request. Event must be one of: APPROVED, REQUEST_CHANGES, or COMMENT (uppercase). Returns the submitted review state. Fails with 404 if the PR does not exist, or 422 if the event type is invalid.")]
+    #[tool(description = "Use this when you need to submit a review on a pull request. Event must be one of: APPROVED, REQUEST_CHANGES, or COMMENT (uppercase). Optionally attach inline comments, each with a file path, a line number in the new version of the file, and a body — use pr_files to find the changed paths. Returns the submitted review state. Fails with 404 if the PR does not exist, or 422 if the event type is invalid.")]
     async fn pr_review_create(&self, Parameters(p): Parameters<PrReviewCreateParams>) -> Result<CallToolResult, ErrorData> {
         map_err(crate::tools::pull_reviews::pr_review_create(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
     }
 
+    #[tool(description = "Use this when you need to request a review on a pull request from specific users or teams. Provide reviewer usernames and optionally team names. Returns the list of reviewers currently requested after the operation. Fails with 422 if a reviewer is not a collaborator on the repository, or 404 if the PR does not exist.")]
+    async fn pr_request_reviewers(&self, Parameters(p): Parameters<PrReviewersParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::pull_reviews::pr_request_reviewers(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to withdraw a pending review request from users or teams on a pull request. Provide the same usernames/team names that were requested. Returns the list of reviewers still requested after the operation. Fails with 404 if the PR does not exist.")]
+    async fn pr_remove_reviewers(&self, Parameters(p): Parameters<PrReviewersParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::pull_reviews::pr_remove_reviewers(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
     // ── Pull Request Files ──────────────────────────────────────────
 
-    #[tool(description = "Use this when you need to see which files were changed in a pull request. Returns filename, status (added/modified/deleted), and diff stats (+additions/-deletions) for each file. For the full unified diff content, use pr_diff instead.")]
+    #[tool(description = "Use this when you need to see which files were changed in a pull request. Returns filename, status (added/modified/deleted), and diff stats (+additions/-deletions) for each file. Set include_patch=true to also show each file's diff hunks (large patches are truncated per file). For the full unified diff of the whole PR, use pr_diff instead.")]
     async fn pr_files(&self, Parameters(p): Parameters<PrFilesParams>) -> Result<CallToolResult, ErrorData> {
         map_err(crate::tools::pull_files::pr_files(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
     }
 
-    #[tool(description = "Use this when you need to see the raw unified diff of all changes in a pull request. Returns the full diff in unified format. For a summary of changed files with stats, use pr_files instead.")]
+    #[tool(description = "Use this when you need a cheap overview of how big a pull request is before deciding whether to fetch its full diff. Returns a git diff --stat style summary: one compact line per file with its change count and a +/- bar, then total files changed, insertions, and deletions. No hunk content. Use pr_files for file statuses or per-file patches, and pr_diff for the full diff.")]
+    async fn pr_diffstat(&self, Parameters(p): Parameters<PrDiffstatParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::pull_files::pr_diffstat(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to see the raw unified diff of all changes in a pull request. Returns the full diff in unified format. For a summary of changed files with stats, use pr_files instead. Requests give up after 30 seconds by default; pass timeout_secs (up to 600) for very large diffs.")]
     async fn pr_diff(&self, Parameters(p): Parameters<PrDiffParams>) -> Result<CallToolResult, ErrorData> {
         map_err(crate::tools::pull_files::pr_diff(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
     }
 
+    #[tool(description = "Use this when you need to list the commits contained in a pull request. Returns short SHA and first line of the message for each commit. Use pr_diff for the actual changes, or commit_get with a SHA for full details of an individual commit. Fails with 404 if the PR does not exist.")]
+    async fn pr_commits(&self, Parameters(p): Parameters<PrCommitsParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::pull_files::pr_commits(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
     // ── Files ───────────────────────────────────────────────────────
 
-    #[tool(description = "Use this when you need to read the content of a file from the repository at a specific ref (branch, tag, or commit SHA). Returns the file path, size, SHA, and decoded content. IMPORTANT: The returned SHA is required by file_update and file_delete — always call file_read first before updating or deleting a file. Fails with 404 if the file or ref does not exist.")]
+    #[tool(description = "Use this when you need to read the content of a file from the repository at a specific ref (branch, tag, or commit SHA). Returns the file path, size, SHA, and decoded content. Content longer than max_bytes (default 100000) is truncated with a marker — raise max_bytes if you need the rest. IMPORTANT: The returned SHA is required by file_update and file_delete — always call file_read first before updating or deleting a file. Fails with 404 if the file or ref does not exist.")]
     async fn file_read(&self, Parameters(p): Parameters<FileReadParams>) -> Result<CallToolResult, ErrorData> {
-        map_err(crate::tools::files::file_read(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+        let (client, detected) = self.client_for(&p.instance, &p.owner, &p.repo)?;
+        map_err(crate::tools::files::file_read(client.as_ref(), p, detected).await)
+    }
+
+    #[tool(description = "Use this when you need the text of a large source file efficiently. Fetches the raw file at an optional ref without the base64 round-trip of file_read, so large text files come back intact. Returns the content in a code block tagged with its language. Use file_read instead when you need the file's SHA for a later update.")]
+    async fn file_read_raw(&self, Parameters(p): Parameters<FileReadRawParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::files::file_read_raw(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need the raw bytes of a binary file (image, archive, PDF, font) from the repository. Unlike file_read, the content is NOT decoded — it is returned as a base64 string together with the file size, SHA, and a content type guessed from the extension, so it can be saved to disk or passed to an image-capable model. Use file_read for text files. Fails with 404 if the file or ref does not exist.")]
+    async fn file_download(&self, Parameters(p): Parameters<FileDownloadParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::files::file_download(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
     }
 
     #[tool(description = "Use this when you need to list files and directories at a path in the repository. Returns names and types (file/dir) for each entry in the directory. This lists a single directory level — use tree_get for a full recursive listing of all files.")]
     async fn file_list(&self, Parameters(p): Parameters<FileListParams>) -> Result<CallToolResult, ErrorData> {
-        map_err(crate::tools::files::file_list(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+        let (client, detected) = self.client_for(&p.instance, &p.owner, &p.repo)?;
+        map_err(crate::tools::files::file_list(client.as_ref(), p, detected).await)
     }
 
     #[tool(description = "Use this when you need to create a new file in the repository. Provide the file path, content, and a commit message. Content is plain text (base64-encoding is handled automatically). Creates a commit. Returns the created file path. Fails with 422 if the file already exists (use file_update instead).")]
@@ -223,16 +651,28 @@ impl GitxMcp {
         map_err(crate::tools::files::file_delete(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
     }
 
-    #[tool(description = "Use this when you need to get the full file tree of a repository recursively. Returns all file and directory paths in the repository at a given ref. For listing a single directory level, use file_list instead.")]
+    #[tool(description = "Use this when you need to move or rename a file. Deletes from_path and adds to_path in a single commit on branch (defaults to the default branch), so there is no need to chain file_read, file_create, and file_delete. Works for binary files and keeps the file mode on GitHub. Returns both paths and the commit SHA. Fails with 404 if from_path does not exist on the branch. Refuses to overwrite an existing to_path, and on Gitea refuses files whose content the server does not return (very large or LFS files).")]
+    async fn file_move(&self, Parameters(p): Parameters<FileMoveParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::files::file_move(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to create, update, and/or delete several files in ONE commit (e.g. scaffolding a project or a multi-file refactor). Each entry has a path, operation ('create', 'update', or 'delete'), content (plain text, not needed for delete), and optionally the current sha (looked up automatically for update/delete when omitted; if given and the file has changed since, the whole batch fails with a conflict). Existing files keep their mode (e.g. executable). Commits to `branch` (default branch if omitted) with a single message. Prefer this over repeated file_create/file_update calls. Returns the new commit SHA and the list of changes. Fails with 404 if the branch or a file to update/delete does not exist, and with a conflict if a file to create already exists.")]
+    async fn file_commit_batch(&self, Parameters(p): Parameters<FileCommitBatchParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::files_batch::file_commit_batch(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to get the full file tree of a repository recursively. Returns all file and directory paths in the repository at a given ref. In large repositories, pass prefix to only list paths under a directory, depth to explore top-down a few levels at a time (e.g. depth=1 lists one level; a walk stops after 100 directories or 5000 entries and says so), and max_entries to cap the output. Notes when the server truncated the tree. Requests give up after 30 seconds by default; pass timeout_secs (up to 600) if a huge tree needs longer to fetch. For listing a single directory level, use file_list instead.")]
     async fn tree_get(&self, Parameters(p): Parameters<TreeGetParams>) -> Result<CallToolResult, ErrorData> {
-        map_err(crate::tools::files::tree_get(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+        let (client, detected) = self.client_for(&p.instance, &p.owner, &p.repo)?;
+        map_err(crate::tools::files::tree_get(client.as_ref(), p, detected).await)
     }
 
     // ── Branches ────────────────────────────────────────────────────
 
     #[tool(description = "Use this when you need to list all branches in a repository. Returns branch names, latest commit SHA, and protection status for each branch.")]
     async fn branch_list(&self, Parameters(p): Parameters<BranchListParams>) -> Result<CallToolResult, ErrorData> {
-        map_err(crate::tools::branches::branch_list(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+        let (client, detected) = self.client_for(&p.instance, &p.owner, &p.repo)?;
+        map_err(crate::tools::branches::branch_list(client.as_ref(), p, detected).await)
     }
 
     #[tool(description = "Use this when you need to create a new branch from an existing branch or commit SHA. Returns the created branch name. Fails with 409 if the branch name already exists, or 404 if the source branch does not exist.")]
@@ -245,38 +685,65 @@ impl GitxMcp {
         map_err(crate::tools::branches::branch_delete(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
     }
 
+    #[tool(description = "Use this when you need to merge one branch directly into another without opening a pull request (GitHub only). Provide base (the branch to merge into), head (branch or SHA to merge), and an optional commit_message. Returns the merge commit SHA, or a 'nothing to merge' message if base already contains head. Fails with a clear conflict message if the branches cannot be merged automatically. On Gitea this is not supported — use pr_create followed by pr_merge.")]
+    async fn branch_merge(&self, Parameters(p): Parameters<BranchMergeParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::branches::branch_merge(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
     #[tool(description = "Use this when you need to list branch protection rules for a repository. Returns branch name patterns and their push/review settings for each rule.")]
     async fn branch_protection_list(&self, Parameters(p): Parameters<BranchProtectionListParams>) -> Result<CallToolResult, ErrorData> {
         map_err(crate::tools::branches::branch_protection_list(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
     }
 
-    #[tool(description = "Use this when you need to create a branch protection rule. Configure whether direct pushes are allowed and whether rejected reviews block merging. Supports glob patterns for branch names (e.g. 'main', 'release/*'). Fails with 422 if a rule for this pattern already exists.")]
+    #[tool(description = "Use this when you need to create a branch protection rule. Configure whether direct pushes are allowed, whether rejected reviews block merging, how many approvals are required (required_approvals), and on GitHub whether admins are bound by the rule (enforce_admins, default true). Supports glob patterns for branch names (e.g. 'main', 'release/*'). Fails with 422 if a rule for this pattern already exists.")]
     async fn branch_protection_create(&self, Parameters(p): Parameters<BranchProtectionCreateParams>) -> Result<CallToolResult, ErrorData> {
         map_err(crate::tools::branches::branch_protection_create(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
     }
 
+    #[tool(description = "Use this when you need to change an existing branch protection rule, e.g. require more approvals or allow direct pushes. Provide the rule's branch_name and the fields to change: enable_push, block_on_rejected_reviews, required_approvals, enforce_admins (GitHub only). On Gitea only the provided fields change. On GitHub the current rule is read first and written back with only those fields changed. Requires admin rights. Fails with 404 if no rule exists for the branch.")]
+    async fn branch_protection_edit(&self, Parameters(p): Parameters<BranchProtectionEditParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::branches::branch_protection_edit(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to remove a branch protection rule entirely. Provide the rule's branch_name. Requires admin rights. Fails with 404 if no rule exists for the branch.")]
+    async fn branch_protection_delete(&self, Parameters(p): Parameters<BranchProtectionDeleteParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::branches::branch_protection_delete(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
     // ── Commits ─────────────────────────────────────────────────────
 
-    #[tool(description = "Use this when you need to list commits in a repository, optionally filtered by branch/tag or file path. Returns commit SHA, author, date, and message for each commit. Use commit_get for full details including diff stats.")]
+    #[tool(description = "Use this when you need to list commits in a repository, optionally filtered by branch/tag, file path, a since/until date range (ISO 8601), or author. Set no_merges=true to leave out merge commits. The no_merges filter (and, on Gitea, the author filter) is applied to the fetched page only, so a page may hold fewer commits than the limit; page further to see more. Returns commit SHA, author, date, and message for each commit. Use commit_get for full details including diff stats.")]
     async fn commit_list(&self, Parameters(p): Parameters<CommitListParams>) -> Result<CallToolResult, ErrorData> {
-        map_err(crate::tools::commits::commit_list(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+        let (client, detected) = self.client_for(&p.instance, &p.owner, &p.repo)?;
+        map_err(crate::tools::commits::commit_list(client.as_ref(), p, detected).await)
     }
 
-    #[tool(description = "Use this when you need to get the full details of a specific commit by its SHA, including message, author, diff stats, and parent commits. Use commit_diff for the full unified diff of the commit.")]
+    #[tool(description = "Use this when you need to find commits by keyword, e.g. the commit that introduced or fixed something. On GitHub this uses commit search across the repository's default branch history, and query accepts search qualifiers such as author:alice. On Gitea there is no commit search, so the query is matched as a case-insensitive substring of messages within one page of commit_list; page further to search older history. Returns SHA and first message line for each match. Use commit_get for full details.")]
+    async fn commit_search(&self, Parameters(p): Parameters<CommitSearchParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::commits::commit_search(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to get the full details of a specific commit by its SHA, including message, author, diff stats, and parent commits. Use commit_diff for the full unified diff of the commit. Set format='json' to get the raw API object instead of markdown.")]
     async fn commit_get(&self, Parameters(p): Parameters<CommitGetParams>) -> Result<CallToolResult, ErrorData> {
-        map_err(crate::tools::commits::commit_get(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+        let (client, detected) = self.client_for(&p.instance, &p.owner, &p.repo)?;
+        map_err(crate::tools::commits::commit_get(client.as_ref(), p, detected).await)
     }
 
-    #[tool(description = "Use this when you need to get the raw unified diff of a specific commit. Returns the diff in unified format. For comparing two different refs, use commit_compare instead.")]
+    #[tool(description = "Use this when you need to get the raw unified diff of a specific commit. Returns the diff in unified format. For comparing two different refs, use commit_compare instead. Requests give up after 30 seconds by default; pass timeout_secs (up to 600) for very large diffs.")]
     async fn commit_diff(&self, Parameters(p): Parameters<CommitDiffParams>) -> Result<CallToolResult, ErrorData> {
         map_err(crate::tools::commits::commit_diff(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
     }
 
-    #[tool(description = "Use this when you need to compare two refs (branches, tags, or commit SHAs). Returns the list of commits between them and the changed files with their status.")]
+    #[tool(description = "Use this when you need to compare two refs (branches, tags, or commit SHAs). Returns how far head is ahead of and behind base, total additions/deletions, the list of commits between them, and the changed files with their status. Answers 'is this branch behind main?'.")]
     async fn commit_compare(&self, Parameters(p): Parameters<CommitCompareParams>) -> Result<CallToolResult, ErrorData> {
         map_err(crate::tools::commits::commit_compare(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
     }
 
+    #[tool(description = "Use this when you need to create a commit directly on a branch from a set of file changes (create, update, delete) without a pull request. Provide branch, message, and files. On GitHub the commit is built through the git data API (blobs, tree on top of the current tree, commit with the current head as parent, then the branch ref is moved); on Gitea it uses the multi-file contents API. SHAs for updates/deletes are looked up automatically when omitted. Returns the full SHA of the new commit.")]
+    async fn commit_create(&self, Parameters(p): Parameters<CommitCreateParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::commits::commit_create(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
     // ── Labels ──────────────────────────────────────────────────────
 
     #[tool(description = "Use this when you need to list all labels available in a repository. Returns label ID, name, color, and description for each label. Use the returned IDs (Gitea) or names (GitHub) when creating or editing issues and pull requests (issue_create, issue_edit, pr_create, pr_edit).")]
@@ -284,11 +751,21 @@ impl GitxMcp {
         map_err(crate::tools::labels::label_list(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
     }
 
+    #[tool(description = "Use this when you need the labels defined at the organization level (Gitea only), which apply to every repository in the org but are not returned by label_list. Returns label ID, name, color, and description in the same format as label_list; the IDs work with issue_create, issue_edit, pr_create, and pr_edit. On GitHub, labels only exist per repository, so this returns an explanatory message.")]
+    async fn org_labels(&self, Parameters(p): Parameters<OrgLabelsParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::labels::org_labels(self.client.as_ref(), p).await)
+    }
+
     #[tool(description = "Use this when you need to create a new label in a repository. Provide a name and hex color. Returns the created label name. Fails with 422 if a label with the same name already exists.")]
     async fn label_create(&self, Parameters(p): Parameters<LabelCreateParams>) -> Result<CallToolResult, ErrorData> {
         map_err(crate::tools::labels::label_create(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
     }
 
+    #[tool(description = "Use this when you need to set up several labels at once, e.g. when bootstrapping a new repository. Pass labels (each with name, six-digit hex color such as '#d73a4a', and optional description; an invalid color fails the whole call before anything is created) and/or preset='default' for the standard set (bug, documentation, duplicate, enhancement, good first issue, help wanted, invalid, question, wontfix). Labels that already exist are skipped rather than failing the batch. Returns which labels were created, skipped, or failed.")]
+    async fn label_create_batch(&self, Parameters(p): Parameters<LabelCreateBatchParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::labels::label_create_batch(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
     #[tool(description = "Use this when you need to update an existing label's name, color, or description. Requires the label ID from label_list. Returns the updated label name.")]
     async fn label_edit(&self, Parameters(p): Parameters<LabelEditParams>) -> Result<CallToolResult, ErrorData> {
         map_err(crate::tools::labels::label_edit(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
@@ -313,11 +790,16 @@ impl GitxMcp {
 
     // ── Notifications ───────────────────────────────────────────────
 
-    #[tool(description = "Use this when you need to list your notifications (not repository-scoped). Returns notification ID, status (read/unread), subject type, title, and repository for each notification. Use the returned IDs with notification_mark_read to mark specific notifications as read.")]
+    #[tool(description = "Use this when you need to list your notifications, across all repositories by default or for one repository when owner/repo (or directory) is given. Returns notification ID, status (read/unread), subject type, title, and repository for each notification. Use the returned IDs with notification_mark_read to mark specific notifications as read.")]
     async fn notification_list(&self, Parameters(p): Parameters<NotificationListParams>) -> Result<CallToolResult, ErrorData> {
         map_err(crate::tools::notifications::notification_list(self.client.as_ref(), p).await)
     }
 
+    #[tool(description = "Use this when you need the details of a single notification before deciding whether it is actionable. Takes the notification ID from notification_list and returns the subject (title, type, state), repository, reason (GitHub only), read status, last update, and last-read timestamp (GitHub only). Does not mark the notification as read.")]
+    async fn notification_thread_get(&self, Parameters(p): Parameters<NotificationThreadGetParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::notifications::notification_thread_get(self.client.as_ref(), p).await)
+    }
+
     #[tool(description = "Use this when you need to mark notifications as read, either all at once or a specific notification by ID from notification_list.")]
     async fn notification_mark_read(&self, Parameters(p): Parameters<NotificationMarkReadParams>) -> Result<CallToolResult, ErrorData> {
         map_err(crate::tools::notifications::notification_mark_read(self.client.as_ref(), p).await)
@@ -327,14 +809,20 @@ impl GitxMcp {
 
     #[tool(description = "Use this when you need to list releases in a repository. Returns release ID, tag name, title, and draft/prerelease flags for each release. Use release_get with the returned ID for full details.")]
     async fn release_list(&self, Parameters(p): Parameters<ReleaseListParams>) -> Result<CallToolResult, ErrorData> {
-        map_err(crate::tools::releases::release_list(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+        let (client, detected) = self.client_for(&p.instance, &p.owner, &p.repo)?;
+        map_err(crate::tools::releases::release_list(client.as_ref(), p, detected).await)
     }
 
-    #[tool(description = "Use this when you need to get full details of a specific release. Requires the release ID from release_list. Returns the full release object including tag, title, body, draft/prerelease status, and assets.")]
+    #[tool(description = "Use this when you need to get full details of a specific release. Requires the release ID from release_list. Returns the full release object including tag, title, body, draft/prerelease status, and assets. Set format='json' to get the raw API object instead of markdown.")]
     async fn release_get(&self, Parameters(p): Parameters<ReleaseGetParams>) -> Result<CallToolResult, ErrorData> {
         map_err(crate::tools::releases::release_get(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
     }
 
+    #[tool(description = "Use this when you need the newest published release of a repository without paging through release_list. Returns the full release object including tag, title, body, and assets. Draft and prerelease entries are excluded by the upstream endpoint, matching GitHub/Gitea semantics. Fails with 404 if the repository has no published releases.")]
+    async fn release_latest(&self, Parameters(p): Parameters<ReleaseLatestParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::releases::release_latest(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
     #[tool(description = "Use this when you need to create a new release with a tag, title, and release notes. If the tag doesn't exist, it will be created pointing to target_commitish. For creating just a tag without a release, use tag_create instead. Returns the created release tag name.")]
     async fn release_create(&self, Parameters(p): Parameters<ReleaseCreateParams>) -> Result<CallToolResult, ErrorData> {
         map_err(crate::tools::releases::release_create(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
@@ -342,9 +830,10 @@ impl GitxMcp {
 
     // ── Repository ──────────────────────────────────────────────────
 
-    #[tool(description = "Use this when you need to get metadata about a repository. Returns full name, description, default branch, stars, forks, visibility, and primary language.")]
+    #[tool(description = "Use this when you need to get metadata about a repository. Returns full name, description, default branch, stars, forks, visibility, and primary language. Set format='json' to get the raw API object instead of markdown.")]
     async fn repo_get(&self, Parameters(p): Parameters<RepoGetParams>) -> Result<CallToolResult, ErrorData> {
-        map_err(crate::tools::repo::repo_get(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+        let (client, detected) = self.client_for(&p.instance, &p.owner, &p.repo)?;
+        map_err(crate::tools::repo::repo_get(client.as_ref(), p, detected).await)
     }
 
     #[tool(description = "Use this when you need to search for repositories by keyword. Returns full name, description, and star count for each matching repository.")]
@@ -352,6 +841,86 @@ impl GitxMcp {
         map_err(crate::tools::repo::repo_search(self.client.as_ref(), p).await)
     }
 
+    #[tool(description = "Use this when you need to list all repositories of a user or organization (e.g. 'all repos in my org') without a search keyword. Pass owner as a user or org name; omit it to list the authenticated user's own repositories. Returns full name, star count, and description for each repository. Supports page/limit pagination. Use repo_search instead to find repositories by keyword.")]
+    async fn repo_list(&self, Parameters(p): Parameters<RepoListParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::repo::repo_list(self.client.as_ref(), p).await)
+    }
+
+    #[tool(description = "Use this when you need to change repository settings: description, default branch, visibility (private), issue tracker (has_issues), wiki (has_wiki), or archived state. Only the fields you provide are changed. The new default branch must already exist. Platform caveats: GitHub cannot unarchive a repository through the API (archived=false only works on Gitea); Gitea's extra toggles such as has_projects and has_pull_requests are not exposed here. Requires admin rights on the repository. Returns the updated repository metadata.")]
+    async fn repo_edit(&self, Parameters(p): Parameters<RepoEditParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::repo::repo_edit(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to move a repository to another user or organization (transfer ownership). Provide new_owner; on Gitea, team_ids grants teams of the receiving organization access. Returns the new full name, or says the transfer is pending when the recipient must accept it first (transfers to another user usually need acceptance). Requires admin rights on the repository and permission to create repositories in the target organization.")]
+    async fn repo_transfer(&self, Parameters(p): Parameters<RepoTransferParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::repo::repo_transfer(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to archive a repository, e.g. an end-of-life project. Archiving makes the repository read-only (no pushes, issues, or PRs) but keeps everything and is reversible with repo_unarchive, so prefer it over deletion. Requires admin rights on the repository. Returns the resulting archived state.")]
+    async fn repo_archive(&self, Parameters(p): Parameters<RepoArchiveParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::repo::repo_archive(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to unarchive a previously archived repository so it accepts pushes, issues, and PRs again. Requires admin rights on the repository. Returns the resulting archived state, and says so if the server left the repository archived (older GitHub API versions cannot unarchive).")]
+    async fn repo_unarchive(&self, Parameters(p): Parameters<RepoArchiveParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::repo::repo_unarchive(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to watch (subscribe to) a repository so its activity generates notifications. On GitHub, set ignored=true to ignore the repository instead, muting all its notifications including mentions; Gitea has no ignore state. Returns the resulting subscription state. Use repo_unwatch to stop watching, and notification_list to read what arrives.")]
+    async fn repo_watch(&self, Parameters(p): Parameters<RepoWatchParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::repo::repo_watch(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to stop watching a repository (or stop ignoring it, on GitHub), e.g. to cut notification noise. Afterwards you only get notifications for threads you participate in or are mentioned in. Returns the resulting subscription state.")]
+    async fn repo_unwatch(&self, Parameters(p): Parameters<RepoUnwatchParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::repo::repo_unwatch(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to star a repository as the authenticated user, e.g. to bookmark it or show appreciation. Starring an already-starred repository is a no-op. Returns a confirmation.")]
+    async fn repo_star(&self, Parameters(p): Parameters<RepoStarParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::repo::repo_star(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to remove the authenticated user's star from a repository. Returns a confirmation. Use user_starred to see which repositories are starred.")]
+    async fn repo_unstar(&self, Parameters(p): Parameters<RepoStarParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::repo::repo_unstar(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to see the topics (tags used to categorize and discover repositories) of a repository. Returns the topic list.")]
+    async fn repo_topics_get(&self, Parameters(p): Parameters<RepoTopicsGetParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::repo::repo_topics_get(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to set the topics of a repository, e.g. to categorize it. The given list replaces all existing topics; pass an empty list to clear them. Topics must be lowercase letters, digits, and hyphens (max 35 characters each). Requires admin rights on the repository. Returns the resulting topic list.")]
+    async fn repo_topics_set(&self, Parameters(p): Parameters<RepoTopicsSetParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::repo::repo_topics_set(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to know what a repository is written in. Returns every detected language with its share of the code as a percentage (and byte count), largest first. repo_get only shows the primary language.")]
+    async fn repo_languages(&self, Parameters(p): Parameters<RepoLanguagesParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::repo::repo_languages(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to know who has starred a repository. Returns one username per line. Paginated with page/limit.")]
+    async fn repo_stargazers(&self, Parameters(p): Parameters<RepoUserListParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::repo::repo_stargazers(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to know who is watching (subscribed to) a repository and gets its notifications. Returns one username per line. Paginated with page/limit.")]
+    async fn repo_subscribers(&self, Parameters(p): Parameters<RepoUserListParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::repo::repo_subscribers(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to fork a repository, e.g. before proposing changes to a repo you cannot push to (fork, branch, then open a PR). Forks into the authenticated user's account, or into `organization` if given. Returns the full name of the new fork. On GitHub forking is asynchronous — the fork may take a few seconds before its contents are available. Fails with 409 if a fork already exists in the target account.")]
+    async fn repo_fork(&self, Parameters(p): Parameters<RepoForkParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::repo::repo_fork(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to bring a fork's branch up to date with its parent repository, e.g. before branching off for a new PR. Defaults to the fork's default branch. Returns whether the branch was fast-forwarded, merged, or already up to date; if the fork has diverged with conflicting changes, says so instead of changing anything. On Gitea this needs an instance with the sync_fork API (recent Gitea/Forgejo); older versions get a message explaining how to sync from a local clone.")]
+    async fn fork_sync(&self, Parameters(p): Parameters<ForkSyncParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::repo::fork_sync(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
     // ── Users ───────────────────────────────────────────────────────
 
     #[tool(description = "Use this when you need to get information about the currently authenticated user (yourself). Returns username, full name, email, and admin status.")]
@@ -359,11 +928,26 @@ impl GitxMcp {
         map_err(crate::tools::users::user_get_me(self.client.as_ref()).await)
     }
 
-    #[tool(description = "Use this when you need to get public profile information about a specific user by their username. Returns username, full name, and account creation date.")]
+    #[tool(description = "Use this when you need to check that the server is configured correctly or how much API budget remains. Returns the authenticated user, the platform and base URL, and the current rate-limit budget with its reset time (GitHub). A good first call to smoke-test credentials.")]
+    async fn status(&self, Parameters(_p): Parameters<StatusParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::users::status(self.client.as_ref(), &self.config.base_url).await)
+    }
+
+    #[tool(description = "Use this when you need to get public profile information about a specific user by their username. Returns username, full name, account creation date, and public repo and follower counts when the platform reports them.")]
     async fn user_get(&self, Parameters(p): Parameters<UserGetParams>) -> Result<CallToolResult, ErrorData> {
         map_err(crate::tools::users::user_get(self.client.as_ref(), p).await)
     }
 
+    #[tool(description = "Use this when you need to see what repositories a specific user owns, e.g. 'what has this person been working on'. Requires a username. Returns full name, star count, and description of each public repository, with page/limit pagination. Use repo_list for organizations or your own repositories.")]
+    async fn user_repos(&self, Parameters(p): Parameters<UserReposParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::users::user_repos(self.client.as_ref(), p).await)
+    }
+
+    #[tool(description = "Use this when you need to list the repositories the authenticated user has starred, e.g. to audit starred projects. Returns full name, star count, and description of each repository, with page/limit pagination. Use repo_star and repo_unstar to change stars.")]
+    async fn user_starred(&self, Parameters(p): Parameters<UserStarredParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::users::user_starred(self.client.as_ref(), p).await)
+    }
+
     // ── Tags ────────────────────────────────────────────────────────
 
     #[tool(description = "Use this when you need to list all tags in a repository. Returns tag name and short commit SHA for each tag.")]
@@ -371,6 +955,11 @@ impl GitxMcp {
         map_err(crate::tools::tags::tag_list(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
     }
 
+    #[tool(description = "Use this when you need the details of a single tag — its target commit and, for annotated tags, the tagger, date, signature status, and annotation message. tag_list only shows names and SHAs. Fails with 404 if the tag does not exist.")]
+    async fn tag_get(&self, Parameters(p): Parameters<TagGetParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::tags::tag_get(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
     #[tool(description = "Use this when you need to create a new tag pointing to a specific commit SHA or branch. For creating a release with release notes, use release_create instead. Returns the created tag name.")]
     async fn tag_create(&self, Parameters(p): Parameters<TagCreateParams>) -> Result<CallToolResult, ErrorData> {
         map_err(crate::tools::tags::tag_create(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
@@ -410,6 +999,21 @@ impl GitxMcp {
         map_err(crate::tools::orgs::org_teams(self.client.as_ref(), p).await)
     }
 
+    #[tool(description = "Use this when you need to see who belongs to a team. Requires the team ID from org_teams. Returns the username of each member.")]
+    async fn team_members(&self, Parameters(p): Parameters<TeamMembersParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::orgs::team_members(self.client.as_ref(), p).await)
+    }
+
+    #[tool(description = "Use this when you need to add a user to a team, e.g. to grant them the team's repository access. Requires the team ID from org_teams and the username. On GitHub this sends a membership invitation if the user is not yet an organization member. Requires organization owner or team maintainer rights. Fails with 404 if the team or user does not exist.")]
+    async fn team_add_member(&self, Parameters(p): Parameters<TeamMemberParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::orgs::team_add_member(self.client.as_ref(), p).await)
+    }
+
+    #[tool(description = "Use this when you need to remove a user from a team, revoking the access the team grants. Requires the team ID from org_teams and the username. Requires organization owner or team maintainer rights. Fails with 404 if the team does not exist or the user is not a member.")]
+    async fn team_remove_member(&self, Parameters(p): Parameters<TeamMemberParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::orgs::team_remove_member(self.client.as_ref(), p).await)
+    }
+
     // ── Actions / CI ────────────────────────────────────────────────
 
     #[tool(description = "Use this when you need to list CI/CD workflows (Actions) configured in a repository. On Gitea, tries the Actions API first, then falls back to listing workflow files in .gitea/workflows or .github/workflows. On GitHub, uses the native workflows API. Returns workflow file names.")]
@@ -417,33 +1021,71 @@ impl GitxMcp {
         map_err(crate::tools::actions::actions_workflow_list(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
     }
 
+    #[tool(description = "Use this when you need to trigger a CI workflow run manually (workflow_dispatch). Provide workflow_id as a numeric ID or the workflow file name (e.g. 'ci.yml'), the ref (branch or tag) to run on, and optional inputs matching the workflow's declared workflow_dispatch inputs. The workflow must have a workflow_dispatch trigger. Returns a confirmation; the run starts asynchronously — use actions_run_list to find it. On Gitea, dispatch requires a recent version; older versions return a clear 'not supported' message.")]
+    async fn actions_workflow_dispatch(&self, Parameters(p): Parameters<ActionsWorkflowDispatchParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::actions::actions_workflow_dispatch(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to turn a disabled CI workflow back on. Provide workflow_id as a numeric ID or the workflow file name (e.g. 'ci.yml'). Returns the workflow's state as re-fetched afterwards (e.g. 'active'). On Gitea this needs a version with the workflow API; older versions return a clear 'not supported' message.")]
+    async fn actions_workflow_enable(&self, Parameters(p): Parameters<ActionsWorkflowToggleParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::actions::actions_workflow_enable(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to pause a noisy or misbehaving CI workflow without editing its file. Provide workflow_id as a numeric ID or the workflow file name (e.g. 'ci.yml'). The workflow stops running on its triggers until re-enabled with actions_workflow_enable. Returns the workflow's state as re-fetched afterwards (e.g. 'disabled_manually'). On Gitea this needs a version with the workflow API; older versions return a clear 'not supported' message.")]
+    async fn actions_workflow_disable(&self, Parameters(p): Parameters<ActionsWorkflowToggleParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::actions::actions_workflow_disable(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
     #[tool(description = "Use this when you need to list workflow runs for a repository. Returns run number, workflow path, title, and status/conclusion for each run. Use actions_run_get with a run ID for full details.")]
     async fn actions_run_list(&self, Parameters(p): Parameters<ActionsRunListParams>) -> Result<CallToolResult, ErrorData> {
         map_err(crate::tools::actions::actions_run_list(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
     }
 
-    #[tool(description = "Use this when you need to get details of a specific workflow run by its ID. Returns run number, title, status, conclusion, workflow path, event, branch, actor, and timestamps. Use actions_job_logs with a job ID to see logs for debugging.")]
+    #[tool(description = "Use this when you need to get details of a specific workflow run by its ID. Returns run number, title, status, conclusion, workflow path, event, branch, actor, and timestamps. Use actions_run_jobs to list the run's jobs, then actions_job_logs with a job ID to see logs for debugging.")]
     async fn actions_run_get(&self, Parameters(p): Parameters<ActionsRunGetParams>) -> Result<CallToolResult, ErrorData> {
         map_err(crate::tools::actions::actions_run_get(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
     }
 
-    #[tool(description = "Use this when you need to get the logs of a specific CI/CD job to debug failures. Requires a job ID from the workflow run. Returns the raw log output in a code block.")]
+    #[tool(description = "Use this when you need to list the jobs of a workflow run, e.g. to find which job failed. Requires the run ID from actions_run_list. Returns each job's name, job_id, status, and conclusion — pass the job_id to actions_job_logs to read its logs.")]
+    async fn actions_run_jobs(&self, Parameters(p): Parameters<ActionsRunJobsParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::actions::actions_run_jobs(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to get the logs of a specific CI/CD job to debug failures. Requires a job ID from the workflow run. Returns the raw log output in a code block. Requests give up after 30 seconds by default; pass timeout_secs (up to 600) for very long logs.")]
     async fn actions_job_logs(&self, Parameters(p): Parameters<ActionsJobLogsParams>) -> Result<CallToolResult, ErrorData> {
         map_err(crate::tools::actions::actions_job_logs(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
     }
+
+    #[tool(description = "Use this when you need to see what build outputs (test reports, binaries, coverage) a workflow run uploaded. Requires a run ID from actions_run_list. Returns each artifact's name, artifact_id, size, and expiry. Use actions_artifact_download_url to get a download link. On Gitea this needs a version with the artifact API; older versions return a clear 'not supported' message.")]
+    async fn actions_run_artifacts(&self, Parameters(p): Parameters<ActionsRunArtifactsParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::actions::actions_run_artifacts(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    #[tool(description = "Use this when you need to download a workflow artifact. Requires an artifact_id from actions_run_artifacts. Returns the artifact's download URL (the zip is not fetched into the response); the URL needs the API token and redirects to a short-lived link, so fetch it with e.g. curl -L. Reports expired artifacts instead of a URL.")]
+    async fn actions_artifact_download_url(&self, Parameters(p): Parameters<ActionsArtifactDownloadUrlParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::actions::actions_artifact_download_url(self.client.as_ref(), p, self.detected_repo.as_ref()).await)
+    }
+
+    // ── GraphQL ─────────────────────────────────────────────────────
+
+    #[tool(description = "Use this when you need a GitHub capability that only the GraphQL API offers, such as blame, Projects (v2), or Discussions, and no dedicated tool covers it. Provide the GraphQL query (or mutation) and optional variables as a JSON object. Returns the raw JSON response, including any GraphQL errors next to partial data. GitHub only — fails on Gitea. Prefer the dedicated tools when one exists.")]
+    async fn github_graphql(&self, Parameters(p): Parameters<GithubGraphqlParams>) -> Result<CallToolResult, ErrorData> {
+        map_err(crate::tools::graphql::github_graphql(self.client.as_ref(), p).await)
+    }
 }
 
 // Extracted resource logic — testable without RequestContext.
 impl GitxMcp {
     fn build_resource_list(&self) -> std::result::Result<ListResourcesResult, ErrorData> {
-        let resources = if let Some(ref info) = self.detected_repo {
+        let mut resources = if let Some(ref info) = self.detected_repo {
             vec![RawResource {
                 uri: RESOURCE_URI.to_string(),
                 name: "detected-repo".to_string(),
                 title: Some(format!("{}/{}", info.owner, info.repo)),
                 description: Some(
-                    "Auto-detected repository from the server's working directory. \
-                     When present, owner and repo params can be omitted from tool calls."
+                    "Auto-detected repository from the server's working directory: owner, repo, \
+                     and (when the server could look it up) default_branch, clone_url, ssh_url, \
+                     and visibility. When present, owner and repo params can be omitted from tool calls."
                         .to_string(),
                 ),
                 mime_type: Some("application/json".to_string()),
@@ -456,6 +1098,23 @@ impl GitxMcp {
             vec![]
         };
 
+        resources.extend(self.remotes.iter().map(|(name, info)| {
+            RawResource {
+                uri: format!("{REMOTE_RESOURCE_PREFIX}{name}"),
+                name: format!("remote-{name}"),
+                title: Some(format!("{name}: {}/{}", info.owner, info.repo)),
+                description: Some(format!(
+                    "Repository behind the '{name}' git remote. Pass remote=\"{name}\" to a tool \
+                     to target it, e.g. the upstream of a fork."
+                )),
+                mime_type: Some("application/json".to_string()),
+                size: None,
+                icons: None,
+                meta: None,
+            }
+            .no_annotation()
+        }));
+
         Ok(ListResourcesResult {
             resources,
             next_cursor: None,
@@ -464,6 +1123,20 @@ impl GitxMcp {
     }
 
     fn build_resource_read(&self, uri: &str) -> std::result::Result<ReadResourceResult, ErrorData> {
+        if let Some(name) = uri.strip_prefix(REMOTE_RESOURCE_PREFIX) {
+            let (_, info) = self.remotes.iter().find(|(n, _)| n == name).ok_or_else(|| {
+                ErrorData::resource_not_found(format!("No git remote named '{name}'"), None)
+            })?;
+            let json = serde_json::json!({
+                "remote": name,
+                "owner": info.owner,
+                "repo": info.repo,
+            });
+            return Ok(ReadResourceResult {
+                contents: vec![ResourceContents::text(json.to_string(), uri)],
+            });
+        }
+
         if uri != RESOURCE_URI {
             return Err(ErrorData::resource_not_found(
                 format!("Unknown resource URI: {uri}"),
@@ -478,10 +1151,13 @@ impl GitxMcp {
             )
         })?;
 
-        let json = serde_json::json!({
+        let mut json = serde_json::json!({
             "owner": info.owner,
             "repo": info.repo,
         });
+        if let (Some(obj), Some(details)) = (json.as_object_mut(), &self.repo_details) {
+            obj.extend(details.clone());
+        }
 
         Ok(ReadResourceResult {
             contents: vec![ResourceContents::text(json.to_string(), RESOURCE_URI)],
@@ -495,12 +1171,13 @@ impl ServerHandler for GitxMcp {
         let platform_name = match self.client.platform() {
             Platform::Gitea => "Gitea/Forgejo",
             Platform::GitHub => "GitHub",
+            Platform::GitLab => "GitLab",
         };
 
         let instructions = match self.client.platform() {
             Platform::Gitea => {
                 format!(
-                    "{platform_name} MCP server with 43 tools covering issues, PRs, files, branches, \
+                    "{platform_name} MCP server with 125 tools covering issues, PRs, files, branches, \
                      commits, labels, milestones, releases, notifications, wiki, organizations, and \
                      CI/CD actions. Read the repo://detected resource to get the auto-detected \
                      owner/repo — when set, owner and repo params can be omitted from all tool calls. \
@@ -511,7 +1188,7 @@ impl ServerHandler for GitxMcp {
             }
             Platform::GitHub => {
                 format!(
-                    "{platform_name} MCP server with 43 tools covering issues, PRs, files, branches, \
+                    "{platform_name} MCP server with 125 tools covering issues, PRs, files, branches, \
                      commits, labels, milestones, releases, notifications, organizations, and \
                      CI/CD actions. Read the repo://detected resource to get the auto-detected \
                      owner/repo — when set, owner and repo params can be omitted from all tool calls. \
@@ -520,6 +1197,16 @@ impl ServerHandler for GitxMcp {
                      Labels use names (strings), not numeric IDs. Wiki CRUD is not available on GitHub."
                 )
             }
+            Platform::GitLab => {
+                format!(
+                    "{platform_name} MCP server. GitLab support is read-only and currently covers \
+                     repo_get, issue_list, and pr_list (merge requests); other tools return an \
+                     error explaining they are not supported on GitLab yet. Read the repo://detected \
+                     resource to get the auto-detected owner/repo — when set, owner and repo params \
+                     can be omitted from all tool calls. Nested groups are not supported: owner must \
+                     be a single top-level group or user."
+                )
+            }
         };
 
         ServerInfo {
@@ -562,6 +1249,16 @@ mod tests {
     use super::*;
     use crate::repo_resolver::RepoInfo;
 
+    // ── read-only mode ──────────────────────────────────────────────
+
+    #[test]
+    fn mutating_tools_are_registered_tools() {
+        let router = GitxMcp::tool_router();
+        for name in MUTATING_TOOLS {
+            assert!(router.has_route(name), "{name} is not a registered tool");
+        }
+    }
+
     // ── resolve_owner_repo tests ───────────────────────────────────
 
     #[test]
@@ -573,11 +1270,19 @@ mod tests {
             repo: "default-repo".to_string(),
         };
 
-        let (o, r) = resolve_owner_repo(&owner, &repo, &None, Some(&default)).unwrap();
+        let (o, r) = resolve_owner_repo(&owner, &repo, &None, &None, Some(&default), false).unwrap();
         assert_eq!(o, "alice");
         assert_eq!(r, "my-repo");
     }
 
+    #[test]
+    fn nested_namespaces_are_rejected() {
+        let owner = Some("group/subgroup".to_string());
+        let repo = Some("project".to_string());
+        let err = resolve_owner_repo(&owner, &repo, &None, &None, None, false).unwrap_err();
+        assert!(matches!(err, GitxError::InvalidParam(_)), "{err}");
+    }
+
     #[test]
     fn empty_strings_skip_to_default() {
         let owner = Some(String::new());
@@ -587,7 +1292,7 @@ mod tests {
             repo: "fallback-repo".to_string(),
         };
 
-        let (o, r) = resolve_owner_repo(&owner, &repo, &None, Some(&default)).unwrap();
+        let (o, r) = resolve_owner_repo(&owner, &repo, &None, &None, Some(&default), false).unwrap();
         assert_eq!(o, "fallback-owner");
         assert_eq!(r, "fallback-repo");
     }
@@ -602,7 +1307,7 @@ mod tests {
             repo: "fallback-repo".to_string(),
         };
 
-        let (o, r) = resolve_owner_repo(&owner, &repo, &None, Some(&default)).unwrap();
+        let (o, r) = resolve_owner_repo(&owner, &repo, &None, &None, Some(&default), false).unwrap();
         assert_eq!(o, "fallback-owner");
         assert_eq!(r, "fallback-repo");
     }
@@ -628,7 +1333,9 @@ mod tests {
             &None,
             &None,
             &Some(tmp.to_string_lossy().to_string()),
+            &None,
             Some(&default),
+            false,
         )
         .unwrap();
         assert_eq!(o, "dir-owner");
@@ -645,7 +1352,7 @@ mod tests {
             repo: "default-repo".to_string(),
         };
 
-        let (o, r) = resolve_owner_repo(&None, &None, &Some(String::new()), Some(&default)).unwrap();
+        let (o, r) = resolve_owner_repo(&None, &None, &Some(String::new()), &None, Some(&default), false).unwrap();
         assert_eq!(o, "default-owner");
         assert_eq!(r, "default-repo");
     }
@@ -655,7 +1362,7 @@ mod tests {
         // No explicit params, no default — falls through to cwd "." detection.
         // The test repo may or may not have an origin remote, so just verify
         // the function doesn't panic and returns Ok or a clean error.
-        let result = resolve_owner_repo(&None, &None, &None, None);
+        let result = resolve_owner_repo(&None, &None, &None, &None, None, false);
         // Either it succeeds (origin exists) or fails cleanly
         match result {
             Ok((o, r)) => {
@@ -670,13 +1377,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn strict_mode_refuses_cwd_fallback() {
+        let err = resolve_owner_repo(&None, &None, &None, &None, None, true).unwrap_err();
+        assert!(matches!(err, GitxError::MissingParam(_)));
+        assert_eq!(
+            err.to_string(),
+            "Missing required parameter: owner/repo required; no repo specified and strict mode is on"
+        );
+    }
+
+    #[test]
+    fn strict_mode_refuses_remote_without_directory() {
+        let default = RepoInfo { owner: "envowner".to_string(), repo: "envrepo".to_string() };
+        let remote = Some("upstream".to_string());
+        let err = resolve_owner_repo(&None, &None, &None, &remote, Some(&default), true).unwrap_err();
+        assert!(matches!(err, GitxError::MissingParam(_)));
+        assert!(err.to_string().contains("strict mode never reads the server's working directory"));
+    }
+
+    #[test]
+    fn strict_mode_still_uses_explicit_and_default_repos() {
+        let default = RepoInfo { owner: "envowner".to_string(), repo: "envrepo".to_string() };
+        let (o, r) = resolve_owner_repo(&None, &None, &None, &None, Some(&default), true).unwrap();
+        assert_eq!((o.as_str(), r.as_str()), ("envowner", "envrepo"));
+
+        let owner = Some("acme".to_string());
+        let repo = Some("widgets".to_string());
+        let (o, r) = resolve_owner_repo(&owner, &repo, &None, &None, None, true).unwrap();
+        assert_eq!((o.as_str(), r.as_str()), ("acme", "widgets"));
+    }
+
     #[test]
     fn bad_directory_no_default_errors() {
         let result = resolve_owner_repo(
             &None,
             &None,
             &Some("/nonexistent/path/that/does/not/exist".to_string()),
+            &None,
             None,
+            false,
         );
         assert!(result.is_err());
     }
@@ -684,19 +1424,99 @@ mod tests {
     // ── Helper to build GitxMcp for tests ─────────────────────────
 
     fn test_server(detected_repo: Option<RepoInfo>) -> GitxMcp {
-        let config = crate::config::Config {
-            base_url: "http://localhost:3000".to_string(),
-            token: "test-token".to_string(),
-            platform: Platform::Gitea,
-        };
+        let config = crate::config::Config::new(
+            "http://localhost:3000".to_string(),
+            "test-token".to_string(),
+            Platform::Gitea,
+        );
         let client: Arc<dyn GitClient> = Arc::new(crate::client::GiteaClient::new(&config).unwrap());
         GitxMcp {
             client,
             tool_router: GitxMcp::tool_router(),
             detected_repo,
+            repo_details: None,
+            remotes: Vec::new(),
+            config,
         }
     }
 
+    // ── Instance override tests ────────────────────────────────────
+
+    fn explicit(v: &str) -> Option<String> {
+        Some(v.to_string())
+    }
+
+    #[test]
+    fn no_override_reuses_shared_client() {
+        let server = test_server(Some(RepoInfo { owner: "o".to_string(), repo: "r".to_string() }));
+        let (client, detected) = server.client_for(&InstanceOverride::default(), &None, &None).unwrap();
+        assert!(Arc::ptr_eq(&client, &server.client));
+        assert_eq!(detected.map(|d| d.repo.as_str()), Some("r"));
+    }
+
+    #[test]
+    fn base_url_override_requires_token() {
+        let mut server = test_server(None);
+        server.config.allowed_hosts = vec!["git.other.com".to_string()];
+        let instance = InstanceOverride {
+            base_url: Some("https://git.other.com".to_string()),
+            token: None,
+        };
+        assert!(server.client_for(&instance, &explicit("o"), &explicit("r")).is_err());
+
+        let instance = InstanceOverride {
+            base_url: Some("https://git.other.com".to_string()),
+            token: Some("other-token".to_string()),
+        };
+        let (client, _) = server.client_for(&instance, &explicit("o"), &explicit("r")).unwrap();
+        assert!(!Arc::ptr_eq(&client, &server.client));
+    }
+
+    #[test]
+    fn base_url_override_needs_allowed_host() {
+        let server = test_server(None);
+        let instance = |url: &str| InstanceOverride {
+            base_url: Some(url.to_string()),
+            token: Some("other-token".to_string()),
+        };
+        let err = server.client_for(&instance("http://169.254.169.254"), &explicit("o"), &explicit("r")).unwrap_err();
+        assert!(err.message.contains("GITX_ALLOWED_HOSTS"), "{}", err.message);
+        assert!(server.client_for(&instance("file:///etc/passwd"), &explicit("o"), &explicit("r")).is_err());
+        // The configured instance itself is always allowed.
+        assert!(server.client_for(&instance("http://LOCALHOST:3000"), &explicit("o"), &explicit("r")).is_ok());
+    }
+
+    #[test]
+    fn base_url_override_drops_detected_repo() {
+        let mut server = test_server(Some(RepoInfo { owner: "o".to_string(), repo: "r".to_string() }));
+        server.config.allowed_hosts = vec!["git.other.com".to_string()];
+        let instance = InstanceOverride {
+            base_url: Some("https://git.other.com".to_string()),
+            token: Some("other-token".to_string()),
+        };
+        assert!(server.client_for(&instance, &None, &None).is_err());
+        let (_, detected) = server.client_for(&instance, &explicit("o"), &explicit("r")).unwrap();
+        assert!(detected.is_none());
+
+        // A token-only override stays on this instance and keeps its default repo.
+        let instance = InstanceOverride { base_url: None, token: Some("other-token".to_string()) };
+        let (_, detected) = server.client_for(&instance, &None, &None).unwrap();
+        assert!(detected.is_some());
+    }
+
+    #[test]
+    fn override_params_are_flattened() {
+        let params: crate::tools::repo::RepoGetParams = serde_json::from_value(serde_json::json!({
+            "owner": "o",
+            "repo": "r",
+            "base_url": "https://git.other.com",
+            "token": "t",
+        }))
+        .unwrap();
+        assert_eq!(params.instance.base_url.as_deref(), Some("https://git.other.com"));
+        assert_eq!(params.instance.token.as_deref(), Some("t"));
+    }
+
     // ── Resource logic tests ───────────────────────────────────────
 
     #[test]
@@ -750,6 +1570,85 @@ mod tests {
         assert_eq!(parsed["repo"], "testrepo");
     }
 
+    #[test]
+    fn read_resource_includes_repo_details() {
+        let mut server = test_server(Some(RepoInfo {
+            owner: "testowner".to_string(),
+            repo: "testrepo".to_string(),
+        }));
+        server.repo_details = Some(repo_details(&serde_json::json!({
+            "default_branch": "main",
+            "clone_url": "https://git.example.com/testowner/testrepo.git",
+            "ssh_url": "git@git.example.com:testowner/testrepo.git",
+            "private": true,
+        })));
+
+        let result = server.build_resource_read("repo://detected").unwrap();
+        let text = match &result.contents[0] {
+            ResourceContents::TextResourceContents { text, .. } => text,
+            _ => panic!("Expected text resource content"),
+        };
+        let parsed: serde_json::Value = serde_json::from_str(text).unwrap();
+        assert_eq!(parsed["owner"], "testowner");
+        assert_eq!(parsed["default_branch"], "main");
+        assert_eq!(parsed["clone_url"], "https://git.example.com/testowner/testrepo.git");
+        assert_eq!(parsed["ssh_url"], "git@git.example.com:testowner/testrepo.git");
+        assert_eq!(parsed["visibility"], "private");
+    }
+
+    #[test]
+    fn repo_details_reads_gitlab_fields() {
+        let details = repo_details(&serde_json::json!({
+            "default_branch": "develop",
+            "http_url_to_repo": "https://gitlab.com/g/p.git",
+            "ssh_url_to_repo": "git@gitlab.com:g/p.git",
+            "visibility": "internal",
+            "private": true,
+        }));
+        assert_eq!(details["clone_url"], "https://gitlab.com/g/p.git");
+        assert_eq!(details["ssh_url"], "git@gitlab.com:g/p.git");
+        assert_eq!(details["visibility"], "internal");
+        assert!(repo_details(&serde_json::json!({})).is_empty());
+    }
+
+    fn upstream_fork_remotes() -> Vec<(String, RepoInfo)> {
+        vec![
+            ("origin".to_string(), RepoInfo { owner: "me".to_string(), repo: "fork".to_string() }),
+            ("upstream".to_string(), RepoInfo { owner: "acme".to_string(), repo: "project".to_string() }),
+        ]
+    }
+
+    #[test]
+    fn list_resources_includes_each_remote() {
+        let mut server = test_server(Some(RepoInfo {
+            owner: "me".to_string(),
+            repo: "fork".to_string(),
+        }));
+        server.remotes = upstream_fork_remotes();
+
+        let result = server.build_resource_list().unwrap();
+        let uris: Vec<&str> = result.resources.iter().map(|r| r.raw.uri.as_str()).collect();
+        assert_eq!(uris, ["repo://detected", "repo://remote/origin", "repo://remote/upstream"]);
+        assert_eq!(result.resources[2].raw.title.as_deref(), Some("upstream: acme/project"));
+    }
+
+    #[test]
+    fn read_remote_resource_by_name() {
+        let mut server = test_server(None);
+        server.remotes = upstream_fork_remotes();
+
+        let result = server.build_resource_read("repo://remote/upstream").unwrap();
+        let text = match &result.contents[0] {
+            ResourceContents::TextResourceContents { text, .. } => text,
+            _ => panic!("Expected text resource content"),
+        };
+        let parsed: serde_json::Value = serde_json::from_str(text).unwrap();
+        assert_eq!(parsed, serde_json::json!({ "remote": "upstream", "owner": "acme", "repo": "project" }));
+
+        let err = server.build_resource_read("repo://remote/mirror").unwrap_err();
+        assert_eq!(err.code, ErrorCode::RESOURCE_NOT_FOUND);
+    }
+
     #[test]
     fn read_resource_unknown_uri_errors() {
         let server = test_server(Some(RepoInfo {
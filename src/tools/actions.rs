@@ -2,10 +2,13 @@ use rmcp::model::{CallToolResult, Content};
 use schemars::JsonSchema;
 use serde::Deserialize;
 
-use crate::client::GitClient;
+use crate::client::{GitClient, RawMedia};
+use crate::config::ToolSettings;
 use crate::error::Result;
 use crate::repo_resolver::RepoInfo;
+use crate::response;
 use crate::server::resolve_owner_repo;
+use crate::types::common::{query_refs, request_timeout, QueryBuilder};
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct ActionsWorkflowListParams {
@@ -15,6 +18,8 @@ pub struct ActionsWorkflowListParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -25,6 +30,8 @@ pub struct ActionsRunListParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
     /// Page number (1-based). Defaults to 1.
     pub page: Option<i64>,
     /// Items per page (max 50). Defaults to 20.
@@ -39,6 +46,22 @@ pub struct ActionsRunGetParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Workflow run ID.
+    pub run_id: i64,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ActionsRunJobsParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
     /// Workflow run ID.
     pub run_id: i64,
 }
@@ -51,8 +74,60 @@ pub struct ActionsJobLogsParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
-    /// Job ID (from the workflow run details in actions_run_get).
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Job ID (from actions_run_jobs).
     pub job_id: i64,
+    /// Timeout in seconds for this call's API requests, for very large results
+    /// (1-600). Defaults to the server's request timeout (`GITX_REQUEST_TIMEOUT`, 30s).
+    pub timeout_secs: Option<u64>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ActionsRunArtifactsParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Workflow run ID.
+    pub run_id: i64,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ActionsArtifactDownloadUrlParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Artifact ID (from actions_run_artifacts).
+    pub artifact_id: i64,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ActionsWorkflowDispatchParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Workflow ID or workflow file name (e.g. "ci.yml").
+    pub workflow_id: String,
+    /// Branch or tag to run the workflow on.
+    #[serde(rename = "ref")]
+    pub git_ref: String,
+    /// Inputs declared under `workflow_dispatch.inputs` in the workflow file.
+    pub inputs: Option<serde_json::Map<String, serde_json::Value>>,
 }
 
 pub async fn actions_workflow_list(
@@ -62,9 +137,10 @@ pub async fn actions_workflow_list(
 ) -> Result<CallToolResult> {
     use crate::platform::Platform;
 
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
 
     match client.platform() {
+        Platform::GitLab => Err(crate::client::gitlab_unsupported("actions_workflow_list")),
         Platform::GitHub => {
             // GitHub has a native workflows API
             let result = client
@@ -118,25 +194,22 @@ pub async fn actions_workflow_list(
                     ))
                     .await;
 
-                match files_val {
-                    Ok(val) => {
-                        let entries = val.as_array().cloned().unwrap_or_default();
-                        if !entries.is_empty() {
-                            let formatted: Vec<String> = entries
-                                .iter()
-                                .map(|e| {
-                                    let name =
-                                        e.get("name").and_then(|v| v.as_str()).unwrap_or("?");
-                                    format!("- {name}")
-                                })
-                                .collect();
-                            return Ok(CallToolResult::success(vec![Content::text(format!(
-                                "Workflow files:\n{}",
-                                formatted.join("\n")
-                            ))]));
-                        }
+                if let Ok(val) = files_val {
+                    let entries = val.as_array().cloned().unwrap_or_default();
+                    if !entries.is_empty() {
+                        let formatted: Vec<String> = entries
+                            .iter()
+                            .map(|e| {
+                                let name =
+                                    e.get("name").and_then(|v| v.as_str()).unwrap_or("?");
+                                format!("- {name}")
+                            })
+                            .collect();
+                        return Ok(CallToolResult::success(vec![Content::text(format!(
+                            "Workflow files:\n{}",
+                            formatted.join("\n")
+                        ))]));
                     }
-                    Err(_) => {}
                 }
 
                 // Try .github/workflows
@@ -146,29 +219,26 @@ pub async fn actions_workflow_list(
                     ))
                     .await;
 
-                match files2_val {
-                    Ok(val) => {
-                        let entries = val.as_array().cloned().unwrap_or_default();
-                        if !entries.is_empty() {
-                            let formatted: Vec<String> = entries
-                                .iter()
-                                .map(|e| {
-                                    let name = e
-                                        .get("name")
-                                        .and_then(|v| v.as_str())
-                                        .unwrap_or("?");
-                                    format!("- {name}")
-                                })
-                                .collect();
-                            return Ok(CallToolResult::success(vec![Content::text(
-                                format!(
-                                    "Workflow files:\n{}",
-                                    formatted.join("\n")
-                                ),
-                            )]));
-                        }
+                if let Ok(val) = files2_val {
+                    let entries = val.as_array().cloned().unwrap_or_default();
+                    if !entries.is_empty() {
+                        let formatted: Vec<String> = entries
+                            .iter()
+                            .map(|e| {
+                                let name = e
+                                    .get("name")
+                                    .and_then(|v| v.as_str())
+                                    .unwrap_or("?");
+                                format!("- {name}")
+                            })
+                            .collect();
+                        return Ok(CallToolResult::success(vec![Content::text(
+                            format!(
+                                "Workflow files:\n{}",
+                                formatted.join("\n")
+                            ),
+                        )]));
                     }
-                    Err(_) => {}
                 }
 
                 return Ok(CallToolResult::success(vec![Content::text(
@@ -200,16 +270,12 @@ pub async fn actions_run_list(
     params: ActionsRunListParams,
     default_repo: Option<&RepoInfo>,
 ) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
-    let mut query: Vec<(&str, String)> = Vec::new();
-    query.push(("page", params.page.unwrap_or(1).to_string()));
-    query.push(("limit", params.limit.unwrap_or(20).min(50).to_string()));
-
-    let query_refs: Vec<(&str, &str)> = query.iter().map(|(k, v)| (*k, v.as_str())).collect();
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let query = QueryBuilder::for_client(client).page(params.page).limit(params.limit).build();
     let result = client
         .get_json_with_query(
             &format!("/repos/{owner}/{repo}/actions/runs"),
-            &query_refs,
+            &query_refs(&query),
         )
         .await?;
 
@@ -266,7 +332,7 @@ pub async fn actions_run_get(
     params: ActionsRunGetParams,
     default_repo: Option<&RepoInfo>,
 ) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
     let run = client
         .get_json(&format!(
             "/repos/{owner}/{repo}/actions/runs/{}",
@@ -316,11 +382,11 @@ pub async fn actions_run_get(
     }
 
     if let Some(started) = run.get("started_at").and_then(|v| v.as_str()) {
-        parts.push(format!("**Started:** {started}"));
+        parts.push(format!("**Started:** {}", response::format_timestamp(started, client.settings())));
     }
 
     if let Some(completed) = run.get("completed_at").and_then(|v| v.as_str()) {
-        parts.push(format!("**Completed:** {completed}"));
+        parts.push(format!("**Completed:** {}", response::format_timestamp(completed, client.settings())));
     }
 
     Ok(CallToolResult::success(vec![Content::text(
@@ -328,17 +394,63 @@ pub async fn actions_run_get(
     )]))
 }
 
+pub async fn actions_run_jobs(
+    client: &dyn GitClient,
+    params: ActionsRunJobsParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let result = client
+        .get_json(&format!(
+            "/repos/{owner}/{repo}/actions/runs/{}/jobs",
+            params.run_id
+        ))
+        .await?;
+
+    let jobs = result
+        .get("jobs")
+        .and_then(|v| v.as_array())
+        .cloned()
+        .unwrap_or_default();
+
+    if jobs.is_empty() {
+        return Ok(CallToolResult::success(vec![Content::text(
+            "No jobs found for this run.",
+        )]));
+    }
+
+    let formatted: Vec<String> = jobs
+        .iter()
+        .map(|j| {
+            let id = j.get("id").and_then(|v| v.as_i64()).unwrap_or(0);
+            let name = j.get("name").and_then(|v| v.as_str()).unwrap_or("?");
+            let status = j
+                .get("status")
+                .and_then(|v| v.as_str())
+                .unwrap_or("unknown");
+            match j.get("conclusion").and_then(|v| v.as_str()) {
+                Some(conclusion) if !conclusion.is_empty() => {
+                    format!("- {name} [job_id: {id}] ({status}, {conclusion})")
+                }
+                _ => format!("- {name} [job_id: {id}] ({status})"),
+            }
+        })
+        .collect();
+
+    Ok(CallToolResult::success(vec![Content::text(
+        formatted.join("\n"),
+    )]))
+}
+
 pub async fn actions_job_logs(
     client: &dyn GitClient,
     params: ActionsJobLogsParams,
     default_repo: Option<&RepoInfo>,
 ) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let timeout = request_timeout(params.timeout_secs)?;
     let logs = client
-        .get_raw(&format!(
-            "/repos/{owner}/{repo}/actions/jobs/{}/logs",
-            params.job_id
-        ))
+        .get_raw_with_timeout(&format!("/repos/{owner}/{repo}/actions/jobs/{}/logs", params.job_id), &[], RawMedia::Text, timeout)
         .await?;
 
     if logs.is_empty() {
@@ -351,3 +463,285 @@ pub async fn actions_job_logs(
         "```\n{logs}\n```"
     ))]))
 }
+
+pub async fn actions_run_artifacts(
+    client: &dyn GitClient,
+    params: ActionsRunArtifactsParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    use crate::platform::Platform;
+
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let result = match client
+        .get_json(&format!("/repos/{owner}/{repo}/actions/runs/{}/artifacts", params.run_id))
+        .await
+    {
+        Ok(result) => result,
+        Err(crate::error::GitxError::NotFound(_)) if client.platform() == Platform::Gitea => {
+            return Ok(CallToolResult::success(vec![Content::text(format!(
+                "Listing artifacts is not supported on this Gitea version, or run {} was not found. \
+                 The artifact API requires a recent Gitea or Forgejo.",
+                params.run_id
+            ))]));
+        }
+        Err(e) => return Err(e),
+    };
+
+    let artifacts = result
+        .get("artifacts")
+        .and_then(|v| v.as_array())
+        .cloned()
+        .unwrap_or_default();
+
+    if artifacts.is_empty() {
+        return Ok(CallToolResult::success(vec![Content::text(
+            "No artifacts found for this run.",
+        )]));
+    }
+
+    let formatted: Vec<String> = artifacts.iter().map(|a| format_artifact_line(a, client.settings())).collect();
+
+    Ok(CallToolResult::success(vec![Content::text(
+        formatted.join("\n"),
+    )]))
+}
+
+pub async fn actions_artifact_download_url(
+    client: &dyn GitClient,
+    params: ActionsArtifactDownloadUrlParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    use crate::platform::Platform;
+
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let artifact = match client
+        .get_json(&format!("/repos/{owner}/{repo}/actions/artifacts/{}", params.artifact_id))
+        .await
+    {
+        Ok(artifact) => artifact,
+        Err(crate::error::GitxError::NotFound(_)) if client.platform() == Platform::Gitea => {
+            return Ok(CallToolResult::success(vec![Content::text(format!(
+                "Artifact downloads are not supported on this Gitea version, or artifact {} was not found. \
+                 The artifact API requires a recent Gitea or Forgejo.",
+                params.artifact_id
+            ))]));
+        }
+        Err(e) => return Err(e),
+    };
+
+    Ok(CallToolResult::success(vec![Content::text(format_artifact_download(
+        &artifact,
+        params.artifact_id,
+    ))]))
+}
+
+/// "- name [artifact_id: 7] (1.5 MB, expires 2024-06-01T00:00:00Z)" for one artifact.
+fn format_artifact_line(artifact: &serde_json::Value, settings: ToolSettings) -> String {
+    let id = artifact.get("id").and_then(|v| v.as_i64()).unwrap_or(0);
+    let name = artifact.get("name").and_then(|v| v.as_str()).unwrap_or("?");
+    let size = format_size(artifact.get("size_in_bytes").and_then(|v| v.as_u64()).unwrap_or(0));
+    let expiry = if artifact.get("expired").and_then(|v| v.as_bool()) == Some(true) {
+        "expired".to_string()
+    } else {
+        match artifact.get("expires_at").and_then(|v| v.as_str()) {
+            Some(at) => format!("expires {}", response::format_timestamp(at, settings)),
+            None => "no expiry".to_string(),
+        }
+    };
+    format!("- {name} [artifact_id: {id}] ({size}, {expiry})")
+}
+
+/// The artifact's download URL, with how to fetch it. The API URL answers with
+/// a redirect to a short-lived signed URL, so it is returned rather than the zip.
+fn format_artifact_download(artifact: &serde_json::Value, artifact_id: i64) -> String {
+    let name = artifact.get("name").and_then(|v| v.as_str()).unwrap_or("artifact");
+    if artifact.get("expired").and_then(|v| v.as_bool()) == Some(true) {
+        return format!("Artifact {name} ({artifact_id}) has expired and can no longer be downloaded.");
+    }
+    match artifact.get("archive_download_url").and_then(|v| v.as_str()) {
+        Some(url) => format!(
+            "**Artifact:** {name} ({})\n**Download URL:** {url}\n\n\
+             The URL needs the API token (`Authorization` header) and redirects to a short-lived \
+             link to the zip archive, e.g. `curl -L -H \"Authorization: Bearer $TOKEN\" -o {name}.zip {url}`.",
+            format_size(artifact.get("size_in_bytes").and_then(|v| v.as_u64()).unwrap_or(0))
+        ),
+        None => format!("Artifact {name} ({artifact_id}) has no download URL."),
+    }
+}
+
+/// Human-readable byte count, e.g. "512 B" or "1.5 MB".
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ActionsWorkflowToggleParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Workflow ID or workflow file name (e.g. "ci.yml").
+    pub workflow_id: String,
+}
+
+pub async fn actions_workflow_dispatch(
+    client: &dyn GitClient,
+    params: ActionsWorkflowDispatchParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    use crate::platform::Platform;
+
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let mut body = serde_json::json!({ "ref": params.git_ref });
+
+    if let Some(inputs) = &params.inputs {
+        body["inputs"] = serde_json::Value::Object(inputs.clone());
+    }
+
+    let result = client
+        .post_json(
+            &format!(
+                "/repos/{owner}/{repo}/actions/workflows/{}/dispatches",
+                params.workflow_id
+            ),
+            &body,
+        )
+        .await;
+
+    match result {
+        Ok(_) => Ok(CallToolResult::success(vec![Content::text(format!(
+            "Workflow {} dispatched on {}. Use actions_run_list to follow the new run.",
+            params.workflow_id, params.git_ref
+        ))])),
+        Err(crate::error::GitxError::NotFound(_)) if client.platform() == Platform::Gitea => {
+            Ok(CallToolResult::success(vec![Content::text(format!(
+                "Workflow dispatch is not supported on this Gitea version, or workflow {} was not found. \
+                 Manual dispatch requires Gitea 1.23+ (or a recent Forgejo) and a `workflow_dispatch` trigger.",
+                params.workflow_id
+            ))]))
+        }
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn actions_workflow_enable(
+    client: &dyn GitClient,
+    params: ActionsWorkflowToggleParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    set_workflow_enabled(client, params, true, default_repo).await
+}
+
+pub async fn actions_workflow_disable(
+    client: &dyn GitClient,
+    params: ActionsWorkflowToggleParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    set_workflow_enabled(client, params, false, default_repo).await
+}
+
+/// Enable or disable a workflow, then re-fetch it to report the state the server ended up with.
+async fn set_workflow_enabled(
+    client: &dyn GitClient,
+    params: ActionsWorkflowToggleParams,
+    enable: bool,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    use crate::platform::Platform;
+
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let path = format!("/repos/{owner}/{repo}/actions/workflows/{}", params.workflow_id);
+    let action = if enable { "enable" } else { "disable" };
+
+    match client.put_json(&format!("{path}/{action}"), &serde_json::json!({})).await {
+        Ok(_) => {}
+        Err(crate::error::GitxError::NotFound(_)) if client.platform() == Platform::Gitea => {
+            return Ok(CallToolResult::success(vec![Content::text(format!(
+                "Enabling and disabling workflows is not supported on this Gitea version, or workflow {} \
+                 was not found. The workflow API requires a recent Gitea or Forgejo.",
+                params.workflow_id
+            ))]));
+        }
+        Err(e) => return Err(e),
+    }
+
+    let workflow = client.get_json(&path).await?;
+    Ok(CallToolResult::success(vec![Content::text(format_workflow_state(
+        &workflow,
+        &params.workflow_id,
+    ))]))
+}
+
+/// "Workflow CI (.github/workflows/ci.yml) is now active." from a workflow object.
+fn format_workflow_state(workflow: &serde_json::Value, workflow_id: &str) -> String {
+    let field = |key: &str| workflow.get(key).and_then(|v| v.as_str());
+    let name = field("name").unwrap_or(workflow_id);
+    let state = field("state").unwrap_or("unknown");
+    match field("path") {
+        Some(path) => format!("Workflow {name} ({path}) is now {state}."),
+        None => format!("Workflow {name} is now {state}."),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn workflow_state_reports_server_state() {
+        let workflow = serde_json::json!({
+            "name": "CI",
+            "path": ".github/workflows/ci.yml",
+            "state": "disabled_manually",
+        });
+        assert_eq!(
+            format_workflow_state(&workflow, "ci.yml"),
+            "Workflow CI (.github/workflows/ci.yml) is now disabled_manually."
+        );
+        assert_eq!(format_workflow_state(&serde_json::json!({}), "ci.yml"), "Workflow ci.yml is now unknown.");
+    }
+
+    #[test]
+    fn artifact_lines_show_size_and_expiry() {
+        let artifact = serde_json::json!({
+            "id": 7,
+            "name": "coverage",
+            "size_in_bytes": 1_572_864,
+            "expired": false,
+            "expires_at": "2024-06-01T00:00:00Z",
+        });
+        assert_eq!(
+            format_artifact_line(&artifact, ToolSettings::default()),
+            "- coverage [artifact_id: 7] (1.5 MB, expires 2024-06-01T00:00:00Z)"
+        );
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(2048), "2.0 KB");
+    }
+
+    #[test]
+    fn expired_artifacts_have_no_download() {
+        let artifact = serde_json::json!({
+            "name": "coverage",
+            "expired": true,
+            "archive_download_url": "https://api.github.com/repos/acme/widgets/actions/artifacts/7/zip",
+        });
+        assert_eq!(
+            format_artifact_download(&artifact, 7),
+            "Artifact coverage (7) has expired and can no longer be downloaded."
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use futures::stream::{self, StreamExt};
+use serde_json::Value;
+
+use crate::client::GitClient;
+use crate::error::Result;
+
+/// How many requests a batch keeps in flight at once.
+pub(crate) const BATCH_CONCURRENCY: usize = 8;
+
+/// GET every path concurrently (at most [`BATCH_CONCURRENCY`] at a time).
+///
+/// Results come back in the order of `paths`; one failed request doesn't
+/// abort the others.
+pub(crate) async fn get_many(client: &dyn GitClient, paths: &[String]) -> Vec<Result<Value>> {
+    // Create the (lazy) request futures up front: keeping the closure out of
+    // the stream keeps the tool future `Send` for the rmcp router.
+    let requests: Vec<_> = paths.iter().map(|path| client.get_json(path)).collect();
+    stream::iter(requests)
+        .buffered(BATCH_CONCURRENCY)
+        .collect()
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use axum::extract::Path;
+    use axum::http::StatusCode;
+    use axum::response::IntoResponse;
+
+    use crate::client::GiteaClient;
+    use crate::config::Config;
+    use crate::platform::Platform;
+
+    /// Mock Gitea serving `/issues/{n}`: later numbers answer sooner, issue 3
+    /// does not exist, and the peak number of requests in flight is recorded.
+    async fn mock_issues(peak: Arc<AtomicUsize>) -> GiteaClient {
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let app = axum::Router::new().route(
+            "/api/v1/repos/o/r/issues/{n}",
+            axum::routing::get(move |Path(n): Path<u64>| {
+                let (in_flight, peak) = (in_flight.clone(), peak.clone());
+                async move {
+                    let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    peak.fetch_max(now, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(60u64.saturating_sub(n * 3))).await;
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+                    if n == 3 {
+                        StatusCode::NOT_FOUND.into_response()
+                    } else {
+                        axum::Json(serde_json::json!({ "number": n })).into_response()
+                    }
+                }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        GiteaClient::new(&Config::new(base_url, "t".to_string(), Platform::Gitea)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn results_keep_request_order_and_isolate_failures() {
+        let peak = Arc::new(AtomicUsize::new(0));
+        let client = mock_issues(peak.clone()).await;
+        let paths: Vec<String> = (1..=12).map(|n| format!("/repos/o/r/issues/{n}")).collect();
+
+        let results = get_many(&client, &paths).await;
+
+        assert_eq!(results.len(), 12);
+        for (n, result) in (1..=12).zip(&results) {
+            match result {
+                Ok(issue) => assert_eq!(issue["number"], n),
+                Err(e) => {
+                    assert_eq!(n, 3, "only issue 3 fails");
+                    assert!(matches!(e, crate::error::GitxError::NotFound(_)), "{e}");
+                }
+            }
+        }
+        assert!(results[2].is_err());
+
+        let peak = peak.load(Ordering::SeqCst);
+        assert!(peak > 1, "requests ran one at a time");
+        assert!(peak <= BATCH_CONCURRENCY, "{peak} requests in flight");
+    }
+}
@@ -6,7 +6,8 @@ use crate::client::GitClient;
 use crate::error::Result;
 use crate::response;
 use crate::repo_resolver::RepoInfo;
-use crate::server::resolve_owner_repo;
+use crate::server::{resolve_owner_repo, InstanceOverride};
+use crate::types::common::{query_refs, QueryBuilder};
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct BranchListParams {
@@ -16,6 +17,11 @@ pub struct BranchListParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Query a different instance (or with a different token) for this call only.
+    #[serde(flatten)]
+    pub instance: InstanceOverride,
     /// Page number (1-based). Defaults to 1.
     pub page: Option<i64>,
     /// Items per page (max 50). Defaults to 20.
@@ -30,6 +36,8 @@ pub struct BranchCreateParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
     /// Name for the new branch.
     pub new_branch_name: String,
     /// Source branch name or commit SHA to create the new branch from. Defaults to the default branch.
@@ -44,6 +52,8 @@ pub struct BranchDeleteParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
     /// Branch name to delete.
     pub branch: String,
 }
@@ -56,6 +66,8 @@ pub struct BranchProtectionListParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -66,12 +78,72 @@ pub struct BranchProtectionCreateParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
     /// Branch name pattern to protect (e.g. "main", "release/*").
     pub branch_name: String,
     /// Allow direct pushes to this branch (bypassing pull requests).
     pub enable_push: Option<bool>,
     /// Block merging when reviews have been rejected.
     pub block_on_rejected_reviews: Option<bool>,
+    /// Number of approving reviews required before merging.
+    pub required_approvals: Option<i64>,
+    /// Apply the rule to repository admins too (GitHub only). Defaults to true.
+    pub enforce_admins: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct BranchProtectionEditParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Branch name pattern of the existing rule (e.g. "main").
+    pub branch_name: String,
+    /// Allow direct pushes to this branch (bypassing pull requests).
+    pub enable_push: Option<bool>,
+    /// Block merging when reviews have been rejected.
+    pub block_on_rejected_reviews: Option<bool>,
+    /// Number of approving reviews required before merging.
+    pub required_approvals: Option<i64>,
+    /// Apply the rule to repository admins too (GitHub only). Left unchanged when omitted.
+    pub enforce_admins: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct BranchProtectionDeleteParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Branch name pattern of the rule to remove.
+    pub branch_name: String,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct BranchMergeParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Branch to merge into.
+    pub base: String,
+    /// Branch or commit SHA to merge.
+    pub head: String,
+    /// Merge commit message. Defaults to the platform's standard message.
+    pub commit_message: Option<String>,
 }
 
 pub async fn branch_list(
@@ -79,14 +151,10 @@ pub async fn branch_list(
     params: BranchListParams,
     default_repo: Option<&RepoInfo>,
 ) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
-    let mut query: Vec<(&str, String)> = Vec::new();
-    query.push(("page", params.page.unwrap_or(1).to_string()));
-    query.push(("limit", params.limit.unwrap_or(20).min(50).to_string()));
-
-    let query_refs: Vec<(&str, &str)> = query.iter().map(|(k, v)| (*k, v.as_str())).collect();
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let query = QueryBuilder::for_client(client).page(params.page).limit(params.limit).build();
     let val = client
-        .get_json_with_query(&format!("/repos/{owner}/{repo}/branches"), &query_refs)
+        .get_json_with_query(&format!("/repos/{owner}/{repo}/branches"), &query_refs(&query))
         .await?;
     let branches = val.as_array().cloned().unwrap_or_default();
 
@@ -96,7 +164,7 @@ pub async fn branch_list(
         )]));
     }
 
-    let formatted: Vec<String> = branches.iter().map(|b| response::format_branch(b)).collect();
+    let formatted: Vec<String> = branches.iter().map(response::format_branch).collect();
 
     Ok(CallToolResult::success(vec![Content::text(
         formatted.join("\n"),
@@ -108,7 +176,7 @@ pub async fn branch_create(
     params: BranchCreateParams,
     default_repo: Option<&RepoInfo>,
 ) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
     let mut body = serde_json::json!({
         "new_branch_name": params.new_branch_name,
     });
@@ -120,6 +188,8 @@ pub async fn branch_create(
     let branch = client
         .post_json(&format!("/repos/{owner}/{repo}/branches"), &body)
         .await?;
+    // Creating the first branch of an empty repository makes it the default.
+    client.forget_default_branch(&owner, &repo);
 
     let name = branch
         .get("name")
@@ -136,10 +206,11 @@ pub async fn branch_delete(
     params: BranchDeleteParams,
     default_repo: Option<&RepoInfo>,
 ) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
     client
         .delete(&format!("/repos/{owner}/{repo}/branches/{}", params.branch))
         .await?;
+    client.forget_default_branch(&owner, &repo);
 
     Ok(CallToolResult::success(vec![Content::text(format!(
         "Branch deleted: {}",
@@ -147,6 +218,58 @@ pub async fn branch_delete(
     ))]))
 }
 
+pub async fn branch_merge(
+    client: &dyn GitClient,
+    params: BranchMergeParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    use crate::platform::Platform;
+
+    if client.platform() == Platform::Gitea {
+        return Ok(CallToolResult::success(vec![Content::text(
+            "Direct branch merges are not available on Gitea. \
+             Use pr_create (head -> base) followed by pr_merge instead.",
+        )]));
+    }
+
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let mut body = serde_json::json!({
+        "base": params.base,
+        "head": params.head,
+    });
+
+    if let Some(msg) = &params.commit_message {
+        body["commit_message"] = serde_json::Value::String(msg.clone());
+    }
+
+    let result = client
+        .post_json(&format!("/repos/{owner}/{repo}/merges"), &body)
+        .await;
+
+    match result {
+        // 204 No Content: base already contains head.
+        Ok(serde_json::Value::Null) => Ok(CallToolResult::success(vec![Content::text(format!(
+            "Nothing to merge: {} already contains {}.",
+            params.base, params.head
+        ))])),
+        Ok(commit) => {
+            let sha = commit.get("sha").and_then(|v| v.as_str()).unwrap_or("unknown");
+            Ok(CallToolResult::success(vec![Content::text(format!(
+                "Merged {} into {}. Merge commit: {sha}",
+                params.head, params.base
+            ))]))
+        }
+        Err(crate::error::GitxError::Status { status: 409, .. }) => {
+            Err(crate::error::GitxError::Conflict(format!(
+                "Merge conflict: {} cannot be merged into {} automatically. \
+                 Resolve the conflicts locally or through a pull request.",
+                params.head, params.base
+            )))
+        }
+        Err(e) => Err(e),
+    }
+}
+
 pub async fn branch_protection_list(
     client: &dyn GitClient,
     params: BranchProtectionListParams,
@@ -154,7 +277,7 @@ pub async fn branch_protection_list(
 ) -> Result<CallToolResult> {
     use crate::platform::Platform;
 
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
 
     match client.platform() {
         Platform::Gitea => {
@@ -188,6 +311,7 @@ pub async fn branch_protection_list(
                 formatted.join("\n"),
             )]))
         }
+        Platform::GitLab => Err(crate::client::gitlab_unsupported("branch_protection_list")),
         Platform::GitHub => {
             // GitHub: list branches and filter protected ones
             let val = client
@@ -231,20 +355,11 @@ pub async fn branch_protection_create(
 ) -> Result<CallToolResult> {
     use crate::platform::Platform;
 
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
 
     match client.platform() {
         Platform::Gitea => {
-            let mut body = serde_json::json!({
-                "branch_name": params.branch_name,
-            });
-
-            if let Some(push) = params.enable_push {
-                body["enable_push"] = serde_json::Value::Bool(push);
-            }
-            if let Some(block) = params.block_on_rejected_reviews {
-                body["block_on_rejected_reviews"] = serde_json::Value::Bool(block);
-            }
+            let body = gitea_protection_create_body(&params);
 
             let _rule = client
                 .post_json(
@@ -258,26 +373,14 @@ pub async fn branch_protection_create(
                 params.branch_name
             ))]))
         }
+        Platform::GitLab => Err(crate::client::gitlab_unsupported("branch_protection_create")),
         Platform::GitHub => {
-            let mut body = serde_json::json!({
-                "required_status_checks": null,
-                "enforce_admins": true,
-                "required_pull_request_reviews": null,
-                "restrictions": null,
-            });
-
-            if params.enable_push == Some(false) {
-                // Restricting push: require PR reviews
-                body["required_pull_request_reviews"] = serde_json::json!({
-                    "required_approving_review_count": 1,
-                });
-            }
-            if params.block_on_rejected_reviews == Some(true) {
-                body["required_pull_request_reviews"] = serde_json::json!({
-                    "dismiss_stale_reviews": true,
-                    "required_approving_review_count": 1,
-                });
-            }
+            let body = github_protection_body(
+                params.enable_push,
+                params.block_on_rejected_reviews,
+                params.required_approvals,
+                params.enforce_admins,
+            );
 
             let _rule = client
                 .put_json(
@@ -296,3 +399,370 @@ pub async fn branch_protection_create(
         }
     }
 }
+
+pub async fn branch_protection_edit(
+    client: &dyn GitClient,
+    params: BranchProtectionEditParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    use crate::platform::Platform;
+
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+
+    match client.platform() {
+        Platform::Gitea => {
+            let mut body = serde_json::json!({});
+
+            if let Some(push) = params.enable_push {
+                body["enable_push"] = serde_json::Value::Bool(push);
+            }
+            if let Some(block) = params.block_on_rejected_reviews {
+                body["block_on_rejected_reviews"] = serde_json::Value::Bool(block);
+            }
+            if let Some(approvals) = params.required_approvals {
+                body["required_approvals"] = serde_json::json!(approvals);
+            }
+
+            let _rule = client
+                .patch_json(
+                    &format!(
+                        "/repos/{owner}/{repo}/branch_protections/{}",
+                        params.branch_name
+                    ),
+                    &body,
+                )
+                .await?;
+        }
+        Platform::GitLab => return Err(crate::client::gitlab_unsupported("branch_protection_edit")),
+        Platform::GitHub => {
+            // GitHub has no partial update: PUT replaces the whole rule, so
+            // start from the current one and change only the given fields.
+            let path = format!(
+                "/repos/{owner}/{repo}/branches/{}/protection",
+                params.branch_name
+            );
+            let current = client.get_json(&path).await?;
+            let body = github_protection_edit_body(&current, &params);
+
+            let _rule = client.put_json(&path, &body).await?;
+        }
+    }
+
+    Ok(CallToolResult::success(vec![Content::text(format!(
+        "Branch protection updated for: {}",
+        params.branch_name
+    ))]))
+}
+
+pub async fn branch_protection_delete(
+    client: &dyn GitClient,
+    params: BranchProtectionDeleteParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    use crate::platform::Platform;
+
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let path = match client.platform() {
+        Platform::Gitea => format!(
+            "/repos/{owner}/{repo}/branch_protections/{}",
+            params.branch_name
+        ),
+        Platform::GitHub | Platform::GitLab => format!(
+            "/repos/{owner}/{repo}/branches/{}/protection",
+            params.branch_name
+        ),
+    };
+
+    client.delete(&path).await?;
+
+    Ok(CallToolResult::success(vec![Content::text(format!(
+        "Branch protection deleted for: {}",
+        params.branch_name
+    ))]))
+}
+
+/// Build the Gitea `POST /branch_protections` body from create params.
+fn gitea_protection_create_body(params: &BranchProtectionCreateParams) -> serde_json::Value {
+    let mut body = serde_json::json!({
+        "branch_name": params.branch_name,
+    });
+
+    if let Some(push) = params.enable_push {
+        body["enable_push"] = serde_json::Value::Bool(push);
+    }
+    if let Some(block) = params.block_on_rejected_reviews {
+        body["block_on_rejected_reviews"] = serde_json::Value::Bool(block);
+    }
+    if let Some(approvals) = params.required_approvals {
+        body["required_approvals"] = serde_json::json!(approvals);
+        // Count approvals from anyone with write access rather than a whitelist.
+        body["enable_approvals_whitelist"] = serde_json::Value::Bool(false);
+    }
+
+    body
+}
+
+/// Build the full GitHub branch protection body (`PUT .../protection`).
+/// Any restriction on pushing or reviews turns on required PR reviews.
+fn github_protection_body(
+    enable_push: Option<bool>,
+    block_on_rejected_reviews: Option<bool>,
+    required_approvals: Option<i64>,
+    enforce_admins: Option<bool>,
+) -> serde_json::Value {
+    let mut body = serde_json::json!({
+        "required_status_checks": null,
+        "enforce_admins": enforce_admins.unwrap_or(true),
+        "required_pull_request_reviews": null,
+        "restrictions": null,
+    });
+
+    let block = block_on_rejected_reviews == Some(true);
+    if enable_push == Some(false) || block || required_approvals.is_some() {
+        let mut reviews = serde_json::json!({
+            "required_approving_review_count": required_approvals.unwrap_or(1),
+        });
+        if block {
+            reviews["dismiss_stale_reviews"] = serde_json::Value::Bool(true);
+        }
+        body["required_pull_request_reviews"] = reviews;
+    }
+
+    body
+}
+
+/// Build the `PUT .../protection` body for an edit: the current rule (as
+/// returned by `GET .../protection`) with only the provided fields changed.
+fn github_protection_edit_body(current: &serde_json::Value, params: &BranchProtectionEditParams) -> serde_json::Value {
+    let mut body = github_protection_input(current);
+
+    if let Some(enforce) = params.enforce_admins {
+        body["enforce_admins"] = serde_json::Value::Bool(enforce);
+    }
+    if params.enable_push == Some(true) {
+        body["required_pull_request_reviews"] = serde_json::Value::Null;
+    }
+    // Restricting pushes or reviews on a rule without PR reviews turns them on.
+    let block = params.block_on_rejected_reviews;
+    let wants_reviews = params.enable_push == Some(false) || block == Some(true) || params.required_approvals.is_some();
+    if wants_reviews && body["required_pull_request_reviews"].is_null() {
+        body["required_pull_request_reviews"] = serde_json::json!({ "required_approving_review_count": 1 });
+    }
+    let reviews = &mut body["required_pull_request_reviews"];
+    if reviews.is_object() {
+        if let Some(approvals) = params.required_approvals {
+            reviews["required_approving_review_count"] = serde_json::json!(approvals);
+        }
+        if let Some(block) = block {
+            reviews["dismiss_stale_reviews"] = serde_json::Value::Bool(block);
+        }
+    }
+
+    body
+}
+
+/// Convert a `GET .../protection` response into the `PUT .../protection`
+/// input shape, which takes plain booleans and login/slug lists.
+fn github_protection_input(current: &serde_json::Value) -> serde_json::Value {
+    let enabled = |key: &str| current[key]["enabled"].as_bool().unwrap_or(false);
+
+    let checks = &current["required_status_checks"];
+    let required_status_checks = if checks.is_object() {
+        let mut input = serde_json::json!({ "strict": checks["strict"].as_bool().unwrap_or(false) });
+        match checks["checks"].as_array() {
+            Some(list) => {
+                input["checks"] = list
+                    .iter()
+                    .map(|c| serde_json::json!({ "context": c["context"], "app_id": c["app_id"] }))
+                    .collect();
+            }
+            None => input["contexts"] = checks["contexts"].clone(),
+        }
+        input
+    } else {
+        serde_json::Value::Null
+    };
+
+    let reviews = &current["required_pull_request_reviews"];
+    let required_pull_request_reviews = if reviews.is_object() {
+        let mut input = serde_json::json!({
+            "dismiss_stale_reviews": reviews["dismiss_stale_reviews"].as_bool().unwrap_or(false),
+            "require_code_owner_reviews": reviews["require_code_owner_reviews"].as_bool().unwrap_or(false),
+            "required_approving_review_count": reviews["required_approving_review_count"].as_i64().unwrap_or(1),
+            "require_last_push_approval": reviews["require_last_push_approval"].as_bool().unwrap_or(false),
+        });
+        for key in ["dismissal_restrictions", "bypass_pull_request_allowances"] {
+            if reviews[key].is_object() {
+                input[key] = actor_lists(&reviews[key]);
+            }
+        }
+        input
+    } else {
+        serde_json::Value::Null
+    };
+
+    let restrictions = if current["restrictions"].is_object() {
+        actor_lists(&current["restrictions"])
+    } else {
+        serde_json::Value::Null
+    };
+
+    serde_json::json!({
+        "required_status_checks": required_status_checks,
+        "enforce_admins": enabled("enforce_admins"),
+        "required_pull_request_reviews": required_pull_request_reviews,
+        "restrictions": restrictions,
+        "required_linear_history": enabled("required_linear_history"),
+        "allow_force_pushes": enabled("allow_force_pushes"),
+        "allow_deletions": enabled("allow_deletions"),
+        "block_creations": enabled("block_creations"),
+        "required_conversation_resolution": enabled("required_conversation_resolution"),
+        "lock_branch": enabled("lock_branch"),
+        "allow_fork_syncing": enabled("allow_fork_syncing"),
+    })
+}
+
+/// `{users: [{login}], teams: [{slug}], apps: [{slug}]}` as the
+/// `{users: [login], teams: [slug], apps: [slug]}` lists GitHub accepts.
+fn actor_lists(actors: &serde_json::Value) -> serde_json::Value {
+    let names = |key: &str, field: &str| -> Vec<serde_json::Value> {
+        actors[key]
+            .as_array()
+            .map(|list| list.iter().map(|a| a[field].clone()).filter(|v| v.is_string()).collect())
+            .unwrap_or_default()
+    };
+    serde_json::json!({
+        "users": names("users", "login"),
+        "teams": names("teams", "slug"),
+        "apps": names("apps", "slug"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edit_params() -> BranchProtectionEditParams {
+        BranchProtectionEditParams {
+            owner: None,
+            repo: None,
+            directory: None,
+            remote: None,
+            branch_name: "main".to_string(),
+            enable_push: None,
+            block_on_rejected_reviews: None,
+            required_approvals: None,
+            enforce_admins: None,
+        }
+    }
+
+    /// A trimmed `GET .../branches/main/protection` response.
+    fn current_protection() -> serde_json::Value {
+        serde_json::json!({
+            "required_status_checks": {
+                "strict": true,
+                "contexts": ["ci"],
+                "checks": [{ "context": "ci", "app_id": 15368 }]
+            },
+            "enforce_admins": { "enabled": true },
+            "required_pull_request_reviews": {
+                "dismiss_stale_reviews": false,
+                "require_code_owner_reviews": true,
+                "required_approving_review_count": 2,
+                "dismissal_restrictions": { "users": [{ "login": "octocat" }], "teams": [], "apps": [] }
+            },
+            "restrictions": {
+                "users": [{ "login": "release-bot" }],
+                "teams": [{ "slug": "maintainers" }],
+                "apps": []
+            },
+            "required_linear_history": { "enabled": true },
+            "allow_force_pushes": { "enabled": false }
+        })
+    }
+
+    #[test]
+    fn github_edit_keeps_unspecified_settings() {
+        let params = BranchProtectionEditParams { enforce_admins: Some(false), ..edit_params() };
+        let body = github_protection_edit_body(&current_protection(), &params);
+
+        assert_eq!(body["enforce_admins"], false);
+        assert_eq!(body["required_status_checks"]["strict"], true);
+        assert_eq!(body["required_status_checks"]["checks"][0]["context"], "ci");
+        assert_eq!(body["required_pull_request_reviews"]["required_approving_review_count"], 2);
+        assert_eq!(body["required_pull_request_reviews"]["require_code_owner_reviews"], true);
+        assert_eq!(body["required_pull_request_reviews"]["dismissal_restrictions"]["users"], serde_json::json!(["octocat"]));
+        assert_eq!(body["restrictions"]["users"], serde_json::json!(["release-bot"]));
+        assert_eq!(body["restrictions"]["teams"], serde_json::json!(["maintainers"]));
+        assert_eq!(body["required_linear_history"], true);
+
+        // An edit with no fields leaves the rule as it is.
+        let unchanged = github_protection_edit_body(&current_protection(), &edit_params());
+        assert_eq!(unchanged["enforce_admins"], true);
+    }
+
+    #[test]
+    fn github_edit_changes_only_given_review_fields() {
+        let params = BranchProtectionEditParams { required_approvals: Some(3), ..edit_params() };
+        let body = github_protection_edit_body(&current_protection(), &params);
+        assert_eq!(body["required_pull_request_reviews"]["required_approving_review_count"], 3);
+        assert_eq!(body["required_pull_request_reviews"]["require_code_owner_reviews"], true);
+
+        let params = BranchProtectionEditParams { enable_push: Some(true), ..edit_params() };
+        let body = github_protection_edit_body(&current_protection(), &params);
+        assert!(body["required_pull_request_reviews"].is_null());
+        assert_eq!(body["restrictions"]["users"], serde_json::json!(["release-bot"]));
+
+        let bare = serde_json::json!({ "enforce_admins": { "enabled": false } });
+        let params = BranchProtectionEditParams { block_on_rejected_reviews: Some(true), ..edit_params() };
+        let body = github_protection_edit_body(&bare, &params);
+        assert_eq!(body["required_pull_request_reviews"]["required_approving_review_count"], 1);
+        assert_eq!(body["required_pull_request_reviews"]["dismiss_stale_reviews"], true);
+        assert!(body["required_status_checks"].is_null());
+    }
+
+    fn create_params(required_approvals: Option<i64>) -> BranchProtectionCreateParams {
+        BranchProtectionCreateParams {
+            owner: None,
+            repo: None,
+            directory: None,
+            remote: None,
+            branch_name: "main".to_string(),
+            enable_push: None,
+            block_on_rejected_reviews: None,
+            required_approvals,
+            enforce_admins: None,
+        }
+    }
+
+    #[test]
+    fn github_body_uses_custom_approval_count() {
+        let body = github_protection_body(None, None, Some(3), Some(false));
+        assert_eq!(
+            body["required_pull_request_reviews"]["required_approving_review_count"],
+            3
+        );
+        assert_eq!(body["enforce_admins"], false);
+    }
+
+    #[test]
+    fn github_body_defaults() {
+        let body = github_protection_body(Some(false), None, None, None);
+        assert_eq!(
+            body["required_pull_request_reviews"]["required_approving_review_count"],
+            1
+        );
+        assert_eq!(body["enforce_admins"], true);
+        assert!(github_protection_body(None, None, None, None)["required_pull_request_reviews"].is_null());
+    }
+
+    #[test]
+    fn gitea_body_sets_required_approvals() {
+        let body = gitea_protection_create_body(&create_params(Some(2)));
+        assert_eq!(body["required_approvals"], 2);
+        assert_eq!(body["branch_name"], "main");
+        assert!(gitea_protection_create_body(&create_params(None))
+            .get("required_approvals")
+            .is_none());
+    }
+}
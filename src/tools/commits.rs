@@ -2,11 +2,14 @@ use rmcp::model::{CallToolResult, Content};
 use schemars::JsonSchema;
 use serde::Deserialize;
 
-use crate::client::GitClient;
-use crate::error::Result;
+use crate::client::{GitClient, RawMedia};
+use crate::error::{GitxError, Result};
+use crate::platform::Platform;
 use crate::response;
 use crate::repo_resolver::RepoInfo;
-use crate::server::resolve_owner_repo;
+use crate::server::{resolve_owner_repo, InstanceOverride};
+use crate::tools::files_batch::{self, FileChange};
+use crate::types::common::{query_refs, request_timeout, PageLimits, QueryBuilder};
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct CommitListParams {
@@ -16,10 +19,45 @@ pub struct CommitListParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Query a different instance (or with a different token) for this call only.
+    #[serde(flatten)]
+    pub instance: InstanceOverride,
     /// Branch name, tag name, or commit SHA to list commits from. Defaults to the default branch.
     pub sha: Option<String>,
     /// Filter commits by file path.
     pub path: Option<String>,
+    /// Only commits after this time (ISO 8601, e.g. "2024-05-01T00:00:00Z").
+    pub since: Option<String>,
+    /// Only commits before this time (ISO 8601).
+    pub until: Option<String>,
+    /// Only commits by this author (username, or author name/email on GitHub).
+    /// Gitea has no server-side filter, so the fetched page is filtered locally.
+    pub author: Option<String>,
+    /// Leave out merge commits (those with more than one parent). Applied to
+    /// the fetched page locally, so a page may hold fewer commits than the limit.
+    pub no_merges: Option<bool>,
+    /// Page number (1-based). Defaults to 1.
+    pub page: Option<i64>,
+    /// Items per page (max 50). Defaults to 20.
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct CommitSearchParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Text to find in commit messages. On GitHub this is full search syntax,
+    /// so qualifiers like `author:alice` also work; on Gitea it is a plain,
+    /// case-insensitive substring of the message.
+    pub query: String,
     /// Page number (1-based). Defaults to 1.
     pub page: Option<i64>,
     /// Items per page (max 50). Defaults to 20.
@@ -34,8 +72,15 @@ pub struct CommitGetParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Query a different instance (or with a different token) for this call only.
+    #[serde(flatten)]
+    pub instance: InstanceOverride,
     /// Commit SHA.
     pub sha: String,
+    /// Output format: "markdown" (default) or "json" for the raw API object.
+    pub format: Option<String>,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -46,8 +91,13 @@ pub struct CommitDiffParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
     /// Commit SHA.
     pub sha: String,
+    /// Timeout in seconds for this call's API requests, for very large results
+    /// (1-600). Defaults to the server's request timeout (`GITX_REQUEST_TIMEOUT`, 30s).
+    pub timeout_secs: Option<u64>,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -58,42 +108,150 @@ pub struct CommitCompareParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
     /// Base ref (branch, tag, or SHA).
     pub base: String,
     /// Head ref (branch, tag, or SHA).
     pub head: String,
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct CommitCreateParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Branch to commit to. Its current head becomes the parent of the new commit.
+    pub branch: String,
+    /// Commit message.
+    pub message: String,
+    /// File changes (create, update, delete) that make up the commit.
+    pub files: Vec<FileChange>,
+}
+
 pub async fn commit_list(
     client: &dyn GitClient,
     params: CommitListParams,
     default_repo: Option<&RepoInfo>,
 ) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
-    let mut query: Vec<(&str, String)> = Vec::new();
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let query = commit_list_query(client.platform(), client.settings().page_limits, &params);
 
-    if let Some(sha) = &params.sha {
-        query.push(("sha", sha.clone()));
-    }
-    if let Some(path) = &params.path {
-        query.push(("path", path.clone()));
-    }
-    query.push(("page", params.page.unwrap_or(1).to_string()));
-    query.push(("limit", params.limit.unwrap_or(20).min(50).to_string()));
-
-    let query_refs: Vec<(&str, &str)> = query.iter().map(|(k, v)| (*k, v.as_str())).collect();
     let val = client
-        .get_json_with_query(&format!("/repos/{owner}/{repo}/commits"), &query_refs)
+        .get_json_with_query(&format!("/repos/{owner}/{repo}/commits"), &query_refs(&query))
         .await?;
-    let commits = val.as_array().cloned().unwrap_or_default();
+    let mut commits = val.as_array().cloned().unwrap_or_default();
+    if let (Platform::Gitea, Some(author)) = (client.platform(), &params.author) {
+        commits.retain(|c| commit_by_author(c, author));
+    }
+    if params.no_merges.unwrap_or(false) {
+        commits.retain(|c| !is_merge_commit(c));
+    }
 
     Ok(CallToolResult::success(vec![Content::text(
         response::format_commit_list(&commits),
     )]))
 }
 
+pub async fn commit_search(
+    client: &dyn GitClient,
+    params: CommitSearchParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let needle = params.query.trim();
+    if needle.is_empty() {
+        return Err(GitxError::MissingParam("query".to_string()));
+    }
+
+    let text = match client.platform() {
+        Platform::Gitea => {
+            // Gitea has no commit search; match messages within the requested page of history.
+            let query = QueryBuilder::for_client(client).page(params.page).limit(params.limit).build();
+            let val = client
+                .get_json_with_query(&format!("/repos/{owner}/{repo}/commits"), &query_refs(&query))
+                .await?;
+            let scanned = val.as_array().cloned().unwrap_or_default();
+            let matches: Vec<_> = scanned.iter().filter(|c| message_contains(c, needle)).cloned().collect();
+            format!(
+                "{}\n\n_Searched the {} commits on page {} of the default branch's history; \
+                 other pages may hold more matches._",
+                response::format_commit_list(&matches),
+                scanned.len(),
+                params.page.unwrap_or(1),
+            )
+        }
+        Platform::GitLab => return Err(crate::client::gitlab_unsupported("commit_search")),
+        Platform::GitHub => {
+            let query = QueryBuilder::for_client(client)
+                .param("q", format!("{needle} repo:{owner}/{repo}"))
+                .page(params.page)
+                .limit(params.limit)
+                .build();
+            let val = client
+                .get_json_with_query("/search/commits", &query_refs(&query))
+                .await?;
+            let commits = val.get("items").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            response::format_commit_list(&commits)
+        }
+    };
+
+    Ok(CallToolResult::success(vec![Content::text(text)]))
+}
+
+/// Whether the commit message contains `needle`, ignoring case.
+fn message_contains(commit: &serde_json::Value, needle: &str) -> bool {
+    commit
+        .get("commit")
+        .and_then(|c| c.get("message"))
+        .and_then(|m| m.as_str())
+        .is_some_and(|m| m.to_lowercase().contains(&needle.to_lowercase()))
+}
+
+/// Query parameters for `commit_list`; optional filters are only sent when set.
+fn commit_list_query(platform: Platform, limits: PageLimits, params: &CommitListParams) -> Vec<(&'static str, String)> {
+    // Gitea has no author filter; `commit_list` filters the page locally instead.
+    let author = params.author.as_ref().filter(|_| platform != Platform::Gitea);
+    QueryBuilder::new(platform, limits)
+        .opt("sha", params.sha.as_ref())
+        .opt("path", params.path.as_ref())
+        .opt("since", params.since.as_ref())
+        .opt("until", params.until.as_ref())
+        .opt("author", author)
+        .page(params.page)
+        .limit(params.limit)
+        .build()
+}
+
+/// Whether `author` matches the commit's account login, git author name, or email.
+fn commit_by_author(commit: &serde_json::Value, author: &str) -> bool {
+    let git_author = commit.get("commit").and_then(|c| c.get("author"));
+    [
+        commit.get("author").and_then(|a| a.get("login")),
+        git_author.and_then(|a| a.get("name")),
+        git_author.and_then(|a| a.get("email")),
+    ]
+    .into_iter()
+    .flatten()
+    .filter_map(|v| v.as_str())
+    .any(|v| v.eq_ignore_ascii_case(author))
+}
+
+/// Whether the commit has more than one parent.
+fn is_merge_commit(commit: &serde_json::Value) -> bool {
+    commit
+        .get("parents")
+        .and_then(|v| v.as_array())
+        .is_some_and(|parents| parents.len() > 1)
+}
+
 pub async fn commit_get(client: &dyn GitClient, params: CommitGetParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
     let commit = client
         .get_json(&format!(
             "/repos/{owner}/{repo}/git/commits/{}",
@@ -102,7 +260,7 @@ pub async fn commit_get(client: &dyn GitClient, params: CommitGetParams, default
         .await?;
 
     Ok(CallToolResult::success(vec![Content::text(
-        response::format_commit(&commit),
+        response::render(&commit, params.format.as_deref(), response::format_commit),
     )]))
 }
 
@@ -111,12 +269,10 @@ pub async fn commit_diff(
     params: CommitDiffParams,
     default_repo: Option<&RepoInfo>,
 ) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let timeout = request_timeout(params.timeout_secs)?;
     let diff = client
-        .get_raw(&format!(
-            "/repos/{owner}/{repo}/git/commits/{}.diff",
-            params.sha
-        ))
+        .get_raw_with_timeout(&format!("/repos/{owner}/{repo}/git/commits/{}.diff", params.sha), &[], RawMedia::Diff, timeout)
         .await?;
 
     if diff.is_empty() {
@@ -135,7 +291,7 @@ pub async fn commit_compare(
     params: CommitCompareParams,
     default_repo: Option<&RepoInfo>,
 ) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
     let result = client
         .get_json(&format!(
             "/repos/{owner}/{repo}/compare/{}...{}",
@@ -143,7 +299,24 @@ pub async fn commit_compare(
         ))
         .await?;
 
+    // GitHub reports ahead/behind directly. Gitea only lists the commits
+    // head has over base, so "behind" comes from the reverse comparison.
+    let behind = match client.platform() {
+        Platform::Gitea => client
+            .get_json(&format!(
+                "/repos/{owner}/{repo}/compare/{}...{}",
+                params.head, params.base
+            ))
+            .await
+            .ok()
+            .and_then(|reverse| compare_commit_count(&reverse)),
+        Platform::GitHub | Platform::GitLab => None,
+    };
+
     let mut output = Vec::new();
+    if let Some(summary) = compare_summary(&result, behind) {
+        output.push(summary);
+    }
 
     // Show commits between the two refs
     if let Some(commits) = result.get("commits").and_then(|v| v.as_array()) {
@@ -190,3 +363,177 @@ pub async fn commit_compare(
         output.join("\n"),
     )]))
 }
+
+/// Number of commits in a compare result: Gitea's `total_commits`, else the list length.
+fn compare_commit_count(result: &serde_json::Value) -> Option<i64> {
+    result
+        .get("total_commits")
+        .and_then(|v| v.as_i64())
+        .or_else(|| result.get("commits").and_then(|v| v.as_array()).map(|c| c.len() as i64))
+}
+
+/// Ahead/behind header and total line changes for `commit_compare`.
+///
+/// Uses GitHub's `ahead_by`/`behind_by`/`status` when present; otherwise
+/// ahead is the commit count and `behind` comes from the caller.
+fn compare_summary(result: &serde_json::Value, behind: Option<i64>) -> Option<String> {
+    let ahead = result.get("ahead_by").and_then(|v| v.as_i64()).or_else(|| compare_commit_count(result));
+    let behind = result.get("behind_by").and_then(|v| v.as_i64()).or(behind);
+
+    let mut lines = Vec::new();
+    if let Some(ahead) = ahead {
+        let status = result
+            .get("status")
+            .and_then(|v| v.as_str())
+            .map(String::from)
+            .unwrap_or_else(|| match (ahead, behind.unwrap_or(0)) {
+                (0, 0) => "identical".to_string(),
+                (_, 0) => "ahead".to_string(),
+                (0, _) => "behind".to_string(),
+                _ => "diverged".to_string(),
+            });
+        match behind {
+            Some(behind) => lines.push(format!("**Status:** {status} (ahead by {ahead}, behind by {behind})")),
+            None => lines.push(format!("**Status:** {status} (ahead by {ahead})")),
+        }
+    }
+
+    let files = result.get("files").and_then(|v| v.as_array());
+    let has_stats = files.is_some_and(|f| f.iter().any(|f| f.get("additions").is_some()));
+    if let (Some(files), true) = (files, has_stats) {
+        let sum = |key: &str| files.iter().filter_map(|f| f.get(key).and_then(|v| v.as_i64())).sum::<i64>();
+        lines.push(format!("**Total:** +{} -{} across {} files", sum("additions"), sum("deletions"), files.len()));
+    }
+
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join("\n") + "\n")
+    }
+}
+
+pub async fn commit_create(
+    client: &dyn GitClient,
+    params: CommitCreateParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    files_batch::validate_changes(&params.files)?;
+
+    let sha = files_batch::commit_changes(
+        client,
+        &owner,
+        &repo,
+        &params.branch,
+        &params.message,
+        &params.files,
+    )
+    .await?;
+
+    Ok(CallToolResult::success(vec![Content::text(format!(
+        "Created commit {sha} on {}\n{}",
+        params.branch,
+        files_batch::summarize_changes(&params.files)
+    ))]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list_params() -> CommitListParams {
+        CommitListParams {
+            owner: None,
+            repo: None,
+            directory: None,
+            remote: None,
+            instance: InstanceOverride::default(),
+            sha: None,
+            path: None,
+            since: None,
+            until: None,
+            author: None,
+            no_merges: None,
+            page: None,
+            limit: None,
+        }
+    }
+
+    #[test]
+    fn message_search_ignores_case() {
+        let commit = serde_json::json!({"sha": "abc", "commit": {"message": "Fix Parser panic\n\nDetails"}});
+        assert!(message_contains(&commit, "parser"));
+        assert!(message_contains(&commit, "details"));
+        assert!(!message_contains(&commit, "lexer"));
+        assert!(!message_contains(&serde_json::json!({"sha": "abc"}), "parser"));
+    }
+
+    #[test]
+    fn commit_list_passes_date_range() {
+        let params = CommitListParams {
+            since: Some("2024-05-01T00:00:00Z".to_string()),
+            until: Some("2024-05-08T00:00:00Z".to_string()),
+            ..list_params()
+        };
+        let query = commit_list_query(Platform::GitHub, PageLimits::default(), &params);
+        assert!(query.contains(&("since", "2024-05-01T00:00:00Z".to_string())));
+        assert!(query.contains(&("until", "2024-05-08T00:00:00Z".to_string())));
+        assert!(!commit_list_query(Platform::GitHub, PageLimits::default(), &list_params()).iter().any(|(k, _)| *k == "since" || *k == "until"));
+    }
+
+    #[test]
+    fn author_filter_per_platform() {
+        let params = CommitListParams { author: Some("alice".to_string()), ..list_params() };
+        assert!(commit_list_query(Platform::GitHub, PageLimits::default(), &params).contains(&("author", "alice".to_string())));
+        assert!(!commit_list_query(Platform::Gitea, PageLimits::default(), &params).iter().any(|(k, _)| *k == "author"));
+
+        let commit = serde_json::json!({
+            "author": { "login": "alice" },
+            "commit": { "author": { "name": "Alice Smith", "email": "alice@example.com" } },
+        });
+        assert!(commit_by_author(&commit, "Alice"));
+        assert!(commit_by_author(&commit, "alice smith"));
+        assert!(!commit_by_author(&commit, "bob"));
+    }
+
+    #[test]
+    fn no_merges_drops_multi_parent_commits() {
+        let commits = [
+            serde_json::json!({ "sha": "aaa", "parents": [{ "sha": "p1" }] }),
+            serde_json::json!({ "sha": "bbb", "parents": [{ "sha": "p1" }, { "sha": "p2" }] }),
+            serde_json::json!({ "sha": "ccc", "parents": [] }),
+            serde_json::json!({ "sha": "ddd" }),
+        ];
+        let kept: Vec<&str> = commits
+            .iter()
+            .filter(|c| !is_merge_commit(c))
+            .filter_map(|c| c.get("sha").and_then(|v| v.as_str()))
+            .collect();
+        assert_eq!(kept, ["aaa", "ccc", "ddd"]);
+    }
+
+    #[test]
+    fn compare_summary_uses_github_counts() {
+        let result = serde_json::json!({
+            "status": "diverged",
+            "ahead_by": 2,
+            "behind_by": 5,
+            "commits": [{}, {}],
+            "files": [
+                { "filename": "a.rs", "additions": 10, "deletions": 2 },
+                { "filename": "b.rs", "additions": 1, "deletions": 0 },
+            ],
+        });
+        assert_eq!(
+            compare_summary(&result, None).unwrap(),
+            "**Status:** diverged (ahead by 2, behind by 5)\n**Total:** +11 -2 across 2 files\n"
+        );
+    }
+
+    #[test]
+    fn compare_summary_derives_gitea_status() {
+        let result = serde_json::json!({ "total_commits": 3, "commits": [] });
+        assert_eq!(compare_summary(&result, Some(0)).unwrap(), "**Status:** ahead (ahead by 3, behind by 0)\n");
+        assert_eq!(compare_summary(&result, None).unwrap(), "**Status:** ahead (ahead by 3)\n");
+    }
+}
@@ -2,11 +2,14 @@ use rmcp::model::{CallToolResult, Content};
 use schemars::JsonSchema;
 use serde::Deserialize;
 
-use crate::client::GitClient;
-use crate::error::Result;
+use crate::client::{GitClient, RawMedia};
+use crate::error::{GitxError, Result};
+use crate::platform::Platform;
 use crate::response;
 use crate::repo_resolver::RepoInfo;
-use crate::server::resolve_owner_repo;
+use crate::server::{resolve_owner_repo, InstanceOverride};
+use crate::tools::files_batch::{branch_head, commit_tree, tree_entry};
+use crate::types::common::request_timeout;
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct FileReadParams {
@@ -16,6 +19,49 @@ pub struct FileReadParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Query a different instance (or with a different token) for this call only.
+    #[serde(flatten)]
+    pub instance: InstanceOverride,
+    /// File path within the repository.
+    pub path: String,
+    /// Git ref (branch, tag, or commit SHA). Defaults to the default branch.
+    #[serde(rename = "ref")]
+    pub git_ref: Option<String>,
+    /// Maximum number of decoded bytes to return. Longer files are truncated. Defaults to 100000.
+    pub max_bytes: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct FileReadRawParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// File path within the repository.
+    pub path: String,
+    /// Git ref (branch, tag, or commit SHA). Defaults to the default branch.
+    #[serde(rename = "ref")]
+    pub git_ref: Option<String>,
+    /// Maximum number of bytes to return. Longer files are truncated. Defaults to 100000.
+    pub max_bytes: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct FileDownloadParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
     /// File path within the repository.
     pub path: String,
     /// Git ref (branch, tag, or commit SHA). Defaults to the default branch.
@@ -31,6 +77,11 @@ pub struct FileListParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Query a different instance (or with a different token) for this call only.
+    #[serde(flatten)]
+    pub instance: InstanceOverride,
     /// Directory path within the repository. Empty or "/" for root.
     pub path: Option<String>,
     /// Git ref (branch, tag, or commit SHA). Defaults to the default branch.
@@ -46,6 +97,8 @@ pub struct FileCreateParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
     /// File path to create.
     pub path: String,
     /// File content (plain text, will be base64-encoded automatically).
@@ -66,6 +119,8 @@ pub struct FileUpdateParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
     /// File path to update.
     pub path: String,
     /// New file content (plain text, will be base64-encoded automatically).
@@ -88,6 +143,8 @@ pub struct FileDeleteParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
     /// File path to delete.
     pub path: String,
     /// SHA of the file being deleted (from file_read).
@@ -98,6 +155,26 @@ pub struct FileDeleteParams {
     pub branch: Option<String>,
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct FileMoveParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Current path of the file.
+    pub from_path: String,
+    /// Path to move the file to.
+    pub to_path: String,
+    /// Commit message.
+    pub message: String,
+    /// Branch to commit to. Defaults to the default branch.
+    pub branch: Option<String>,
+}
+
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct TreeGetParams {
     /// Repository owner. Optional if `directory` is provided.
@@ -106,41 +183,128 @@ pub struct TreeGetParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Query a different instance (or with a different token) for this call only.
+    #[serde(flatten)]
+    pub instance: InstanceOverride,
     /// Git ref (branch, tag, or SHA). Defaults to the default branch.
     #[serde(rename = "ref")]
     pub git_ref: Option<String>,
+    /// Only return paths under this directory (e.g. "src/tools").
+    pub prefix: Option<String>,
+    /// Maximum number of entries to return. The listing is cut off with a marker beyond this.
+    pub max_entries: Option<usize>,
+    /// Only list this many directory levels below `prefix` (or the root); 1 = just the
+    /// top level, 0 is rejected. Fetches one directory at a time instead of the whole
+    /// tree. Defaults to the full recursive tree.
+    pub depth: Option<usize>,
+    /// Timeout in seconds for this call's API requests, for very large results
+    /// (1-600). Defaults to the server's request timeout (`GITX_REQUEST_TIMEOUT`, 30s).
+    pub timeout_secs: Option<u64>,
+}
+
+/// The ref to read at: `git_ref` when given, otherwise the repository's
+/// default branch, so every file and tree tool reads the same commit.
+async fn resolve_ref(client: &dyn GitClient, owner: &str, repo: &str, git_ref: Option<&str>) -> Result<String> {
+    match git_ref.filter(|r| !r.is_empty()) {
+        Some(git_ref) => Ok(git_ref.to_string()),
+        None => client.default_branch(owner, repo).await,
+    }
 }
 
 pub async fn file_read(client: &dyn GitClient, params: FileReadParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let path = params.path.trim_start_matches('/');
+    let git_ref = resolve_ref(client, &owner, &repo, params.git_ref.as_deref()).await?;
+    let file = client
+        .get_json_with_query(&format!("/repos/{owner}/{repo}/contents/{path}"), &[("ref", &git_ref)])
+        .await?;
+
+    Ok(CallToolResult::success(vec![Content::text(
+        response::format_file_content(
+            &file,
+            params.max_bytes.unwrap_or(response::DEFAULT_MAX_FILE_BYTES),
+        ),
+    )]))
+}
+
+pub async fn file_read_raw(
+    client: &dyn GitClient,
+    params: FileReadRawParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let path = params.path.trim_start_matches('/');
+    let git_ref = resolve_ref(client, &owner, &repo, params.git_ref.as_deref()).await?;
+
+    let text = client
+        .get_raw(&raw_file_path(client.platform(), &owner, &repo, path), &[("ref", &git_ref)], RawMedia::File)
+        .await?;
+
+    Ok(CallToolResult::success(vec![Content::text(response::format_raw_file(
+        path,
+        text,
+        params.max_bytes.unwrap_or(response::DEFAULT_MAX_FILE_BYTES),
+    ))]))
+}
+
+/// Where a file's raw contents are served: Gitea has a dedicated raw
+/// endpoint; GitHub serves raw bytes from the contents API when asked for
+/// [`RawMedia::File`].
+fn raw_file_path(platform: Platform, owner: &str, repo: &str, path: &str) -> String {
+    match platform {
+        Platform::Gitea => format!("/repos/{owner}/{repo}/raw/{path}"),
+        Platform::GitHub | Platform::GitLab => format!("/repos/{owner}/{repo}/contents/{path}"),
+    }
+}
+
+pub async fn file_download(
+    client: &dyn GitClient,
+    params: FileDownloadParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
     let path = params.path.trim_start_matches('/');
-    let mut url = format!("/repos/{owner}/{repo}/contents/{path}");
+    let git_ref = resolve_ref(client, &owner, &repo, params.git_ref.as_deref()).await?;
+    let file = client
+        .get_json_with_query(&format!("/repos/{owner}/{repo}/contents/{path}"), &[("ref", &git_ref)])
+        .await?;
 
-    if let Some(git_ref) = &params.git_ref {
-        url = format!("{url}?ref={git_ref}");
+    if file.get("type").and_then(|v| v.as_str()) == Some("dir") {
+        return Ok(CallToolResult::success(vec![Content::text(format!(
+            "**{path}/** is a directory. Use file_list to list its contents."
+        ))]));
     }
 
-    let file = client.get_json(&url).await?;
+    let size = file.get("size").and_then(|v| v.as_i64()).unwrap_or(0);
+    let sha = file.get("sha").and_then(|v| v.as_str()).unwrap_or("?");
+    // Both platforms wrap base64 at 60-76 chars; strip the newlines so the
+    // payload can be decoded or forwarded as-is.
+    let content = file
+        .get("content")
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+        .replace('\n', "");
 
-    Ok(CallToolResult::success(vec![Content::text(
-        response::format_file_content(&file),
-    )]))
+    Ok(CallToolResult::success(vec![Content::text(format!(
+        "**File:** {path} ({size} bytes)\n**SHA:** {sha}\n**Content-Type:** {}\n**Encoding:** base64\n\n{content}",
+        content_type_for_path(path)
+    ))]))
 }
 
 pub async fn file_list(client: &dyn GitClient, params: FileListParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
     let path = params
         .path
         .as_deref()
         .unwrap_or("")
         .trim_start_matches('/');
-    let mut url = format!("/repos/{owner}/{repo}/contents/{path}");
-
-    if let Some(git_ref) = &params.git_ref {
-        url = format!("{url}?ref={git_ref}");
-    }
+    let git_ref = resolve_ref(client, &owner, &repo, params.git_ref.as_deref()).await?;
 
-    let val = client.get_json(&url).await?;
+    let val = client
+        .get_json_with_query(&format!("/repos/{owner}/{repo}/contents/{path}"), &[("ref", &git_ref)])
+        .await?;
     let entries = val.as_array().cloned().unwrap_or_default();
 
     Ok(CallToolResult::success(vec![Content::text(
@@ -153,7 +317,7 @@ pub async fn file_create(
     params: FileCreateParams,
     default_repo: Option<&RepoInfo>,
 ) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
     let path = params.path.trim_start_matches('/');
 
     use base64::Engine;
@@ -191,7 +355,7 @@ pub async fn file_update(
     params: FileUpdateParams,
     default_repo: Option<&RepoInfo>,
 ) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
     let path = params.path.trim_start_matches('/');
 
     use base64::Engine;
@@ -212,7 +376,8 @@ pub async fn file_update(
 
     let _result = client
         .put_json(&format!("/repos/{owner}/{repo}/contents/{path}"), &body)
-        .await?;
+        .await
+        .map_err(explain_stale_sha)?;
 
     Ok(CallToolResult::success(vec![Content::text(format!(
         "File updated: {path}"
@@ -224,7 +389,7 @@ pub async fn file_delete(
     params: FileDeleteParams,
     default_repo: Option<&RepoInfo>,
 ) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
     let path = params.path.trim_start_matches('/');
 
     let mut body = serde_json::json!({
@@ -238,46 +403,570 @@ pub async fn file_delete(
 
     client
         .delete_with_body(&format!("/repos/{owner}/{repo}/contents/{path}"), &body)
-        .await?;
+        .await
+        .map_err(explain_stale_sha)?;
 
     Ok(CallToolResult::success(vec![Content::text(format!(
         "File deleted: {path}"
     ))]))
 }
 
+pub async fn file_move(
+    client: &dyn GitClient,
+    params: FileMoveParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let from = params.from_path.trim_matches('/');
+    let to = params.to_path.trim_matches('/');
+    if from == to {
+        return Err(GitxError::MissingParam("to_path (must differ from from_path)".to_string()));
+    }
+
+    let branch = match &params.branch {
+        Some(b) => b.clone(),
+        None => client.default_branch(&owner, &repo).await?,
+    };
+
+    let sha = match client.platform() {
+        Platform::Gitea => {
+            let file = client
+                .get_json_with_query(&format!("/repos/{owner}/{repo}/contents/{from}"), &[("ref", branch.as_str())])
+                .await?;
+            let blob_sha = file
+                .get("sha")
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| GitxError::InvalidParam(format!("{from} is not a file")))?;
+            // The content is re-uploaded still base64-encoded, so binary files
+            // survive; Gitea itself refuses to create over an existing to_path.
+            let content = move_content(&file, from)?;
+            let result = client
+                .post_json(
+                    &format!("/repos/{owner}/{repo}/contents"),
+                    &gitea_move_body(from, to, content, blob_sha, &params.message, &branch),
+                )
+                .await?;
+            result
+                .get("commit")
+                .and_then(|c| c.get("sha"))
+                .and_then(|v| v.as_str())
+                .unwrap_or("")
+                .to_string()
+        }
+        Platform::GitLab => return Err(crate::client::gitlab_unsupported("file_move")),
+        Platform::GitHub => {
+            // Re-point the existing blob at the new path, keeping its mode
+            // (executable bit, symlink); nothing is downloaded or re-uploaded.
+            let head = branch_head(client, &owner, &repo, &branch).await?;
+            let source = tree_entry(client, &owner, &repo, &head.tree, from)
+                .await?
+                .ok_or_else(|| GitxError::NotFound(format!("{from} on {branch}")))?;
+            let dest_exists = tree_entry(client, &owner, &repo, &head.tree, to).await?.is_some();
+            let tree = move_tree_entries(from, to, &source, dest_exists)?;
+            commit_tree(client, &owner, &repo, &branch, &params.message, &head, tree).await?
+        }
+    };
+    let short = &sha[..7.min(sha.len())];
+
+    Ok(CallToolResult::success(vec![Content::text(format!(
+        "File moved: {from} → {to} on {branch} (`{short}`)"
+    ))]))
+}
+
+/// The base64 content of a file from the contents API. The API leaves
+/// `content` out (or empty) for files it won't inline, such as oversized or
+/// LFS files; moving those by re-upload would write an empty file.
+fn move_content<'a>(file: &'a serde_json::Value, from: &str) -> Result<&'a str> {
+    let content = file.get("content").and_then(|v| v.as_str());
+    let size = file.get("size").and_then(|v| v.as_i64()).unwrap_or(0);
+    match content {
+        Some(content) if !content.is_empty() || size == 0 => Ok(content),
+        _ => Err(GitxError::Api(format!(
+            "{from} cannot be moved: the server did not return its content (the file may be too large or stored in LFS)"
+        ))),
+    }
+}
+
+/// GitHub tree entries that move the blob `source` from `from` to `to`.
+fn move_tree_entries(from: &str, to: &str, source: &serde_json::Value, dest_exists: bool) -> Result<Vec<serde_json::Value>> {
+    if source.get("type").and_then(|v| v.as_str()) != Some("blob") {
+        return Err(GitxError::InvalidParam(format!("{from} is not a file")));
+    }
+    if dest_exists {
+        return Err(GitxError::Conflict(format!(
+            "{to} already exists; delete it first or choose another to_path"
+        )));
+    }
+    let mode = source.get("mode").and_then(|v| v.as_str()).unwrap_or("100644");
+    let sha = source.get("sha").cloned().unwrap_or(serde_json::Value::Null);
+    Ok(vec![
+        serde_json::json!({ "path": from, "mode": mode, "type": "blob", "sha": null }),
+        serde_json::json!({ "path": to, "mode": mode, "type": "blob", "sha": sha }),
+    ])
+}
+
+/// Gitea/Forgejo multi-file `POST /contents` body that creates `to` with the
+/// existing base64 `content` and deletes `from` in the same commit.
+fn gitea_move_body(from: &str, to: &str, content: &str, sha: &str, message: &str, branch: &str) -> serde_json::Value {
+    // The contents API wraps its base64 at 60 columns; the write side wants it unbroken.
+    let content: String = content.chars().filter(|c| !c.is_whitespace()).collect();
+    serde_json::json!({
+        "files": [
+            { "operation": "create", "path": to, "content": content },
+            { "operation": "delete", "path": from, "sha": sha },
+        ],
+        "message": message,
+        "branch": branch,
+    })
+}
+
+/// Turn a rejected `sha` into an instruction to re-read the file. GitHub
+/// answers 409; Gitea answers 422 with a "sha does not match" message.
+fn explain_stale_sha(err: GitxError) -> GitxError {
+    match err {
+        GitxError::Status { status, body }
+            if status == 409 || (status == 422 && body.to_lowercase().contains("sha")) =>
+        {
+            GitxError::Conflict(format!(
+                "The file was modified since you read it — call file_read again to get the current SHA, then retry.\n\
+                 Server response (HTTP {status}): {body}"
+            ))
+        }
+        other => other,
+    }
+}
+
 pub async fn tree_get(client: &dyn GitClient, params: TreeGetParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
-    let git_ref = params.git_ref.as_deref().unwrap_or("HEAD");
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    if params.depth == Some(0) {
+        return Err(GitxError::InvalidParam("depth must be at least 1 (1 = just the top level)".to_string()));
+    }
+    let git_ref = resolve_ref(client, &owner, &repo, params.git_ref.as_deref()).await?;
+    let timeout = request_timeout(params.timeout_secs)?;
+
+    let mut walk_limited = false;
+    let (entries, truncated) = match params.depth {
+        Some(depth) => {
+            let walk = walk_tree(client, &owner, &repo, &git_ref, params.prefix.as_deref(), depth, timeout).await?;
+            walk_limited = walk.limited;
+            (walk.entries, walk.server_truncated)
+        }
+        None => {
+            let tree = client
+                .get_json_with_timeout(
+                    &format!("/repos/{owner}/{repo}/git/trees/{git_ref}"),
+                    &[("recursive", "true")],
+                    timeout,
+                )
+                .await?;
+            let entries = tree
+                .get("tree")
+                .and_then(|v| v.as_array())
+                .cloned()
+                .unwrap_or_default();
+            let truncated = tree.get("truncated").and_then(|v| v.as_bool()).unwrap_or(false);
+            (entries, truncated)
+        }
+    };
+
+    let mut text = format_tree(&entries, params.prefix.as_deref(), params.max_entries, truncated);
+    if walk_limited {
+        text.push_str(&format!(
+            "\n\n**Note:** the walk stopped after {MAX_WALK_REQUESTS} directories or {MAX_WALK_ENTRIES} entries, \
+             so the listing is incomplete. Narrow it with prefix or a smaller depth."
+        ));
+    }
+    Ok(CallToolResult::success(vec![Content::text(text)]))
+}
 
-    let tree = client
-        .get_json(&format!(
-            "/repos/{owner}/{repo}/git/trees/{git_ref}?recursive=true"
-        ))
-        .await?;
+/// Most non-recursive tree requests one `depth` walk makes.
+const MAX_WALK_REQUESTS: usize = 100;
 
-    let entries = tree
-        .get("tree")
-        .and_then(|v| v.as_array())
-        .cloned()
-        .unwrap_or_default();
+/// Most entries one `depth` walk collects.
+const MAX_WALK_ENTRIES: usize = 5000;
+
+/// Collect tree entries breadth-first, at most `depth` levels below `prefix`
+/// (or the root), with one non-recursive tree request per directory visited.
+/// Stops early at [`MAX_WALK_REQUESTS`] or [`MAX_WALK_ENTRIES`].
+async fn walk_tree(
+    client: &dyn GitClient,
+    owner: &str,
+    repo: &str,
+    git_ref: &str,
+    prefix: Option<&str>,
+    depth: usize,
+    timeout: Option<std::time::Duration>,
+) -> Result<TreeWalk> {
+    let mut walk = TreeWalk::new(git_ref, prefix, depth);
+    while let Some((tree_sha, dir)) = walk.next_tree() {
+        let tree = client
+            .get_json_with_timeout(&format!("/repos/{owner}/{repo}/git/trees/{tree_sha}"), &[], timeout)
+            .await?;
+        walk.visit(&dir, &tree);
+    }
+    Ok(walk)
+}
+
+/// State of a [`walk_tree`] walk, kept apart from the requests. Directories
+/// leading down to `prefix` are walked but not listed. Collected entries
+/// carry repository-relative paths, like a recursive tree.
+struct TreeWalk {
+    prefix: Option<String>,
+    depth: usize,
+    entries: Vec<serde_json::Value>,
+    /// Trees still to fetch, as (SHA, repository-relative directory).
+    pending: std::collections::VecDeque<(String, String)>,
+    requests: usize,
+    /// The server truncated one of the trees.
+    server_truncated: bool,
+    /// The walk hit a request or entry limit before finishing.
+    limited: bool,
+}
+
+impl TreeWalk {
+    fn new(root: &str, prefix: Option<&str>, depth: usize) -> Self {
+        TreeWalk {
+            prefix: prefix.map(|p| p.trim_matches('/')).filter(|p| !p.is_empty()).map(String::from),
+            depth,
+            entries: Vec::new(),
+            pending: std::collections::VecDeque::from([(root.to_string(), String::new())]),
+            requests: 0,
+            server_truncated: false,
+            limited: false,
+        }
+    }
 
-    if entries.is_empty() {
-        return Ok(CallToolResult::success(vec![Content::text(
-            "No files found in tree.",
-        )]));
+    /// The next tree to fetch, or `None` when the walk is done or out of budget.
+    fn next_tree(&mut self) -> Option<(String, String)> {
+        if self.pending.is_empty() {
+            return None;
+        }
+        if self.requests >= MAX_WALK_REQUESTS || self.entries.len() >= MAX_WALK_ENTRIES {
+            self.limited = true;
+            return None;
+        }
+        self.requests += 1;
+        self.pending.pop_front()
     }
 
-    let formatted: Vec<String> = entries
+    /// Record the entries of the tree fetched for `dir` and queue its subdirectories.
+    fn visit(&mut self, dir: &str, tree: &serde_json::Value) {
+        self.server_truncated |= tree.get("truncated").and_then(|v| v.as_bool()).unwrap_or(false);
+
+        for e in tree.get("tree").and_then(|v| v.as_array()).into_iter().flatten() {
+            let name = e.get("path").and_then(|v| v.as_str()).unwrap_or("?");
+            let path = if dir.is_empty() { name.to_string() } else { format!("{dir}/{name}") };
+            let entry_type = e.get("type").and_then(|v| v.as_str()).unwrap_or("blob");
+
+            // Levels below the prefix; `None` for the prefix itself and its ancestors.
+            let level = match self.prefix.as_deref() {
+                None => Some(path.matches('/').count() + 1),
+                Some(prefix) => match path.strip_prefix(prefix) {
+                    Some("") => None,
+                    Some(rest) if rest.starts_with('/') => Some(rest.matches('/').count()),
+                    _ if prefix.starts_with(&format!("{path}/")) => None,
+                    _ => continue,
+                },
+            };
+            if let Some(level) = level {
+                if self.entries.len() >= MAX_WALK_ENTRIES {
+                    self.limited = true;
+                    return;
+                }
+                self.entries.push(serde_json::json!({ "path": path, "type": entry_type }));
+                if level >= self.depth {
+                    continue;
+                }
+            }
+            if entry_type == "tree" {
+                if let Some(sha) = e.get("sha").and_then(|v| v.as_str()) {
+                    self.pending.push_back((sha.to_string(), path));
+                }
+            }
+        }
+    }
+}
+
+/// Render tree entries one path per line (directories get a trailing `/`),
+/// keeping only paths under `prefix` and at most `max_entries` of them.
+/// `server_truncated` is the API's own `truncated` flag.
+fn format_tree(
+    entries: &[serde_json::Value],
+    prefix: Option<&str>,
+    max_entries: Option<usize>,
+    server_truncated: bool,
+) -> String {
+    let prefix = prefix.map(|p| p.trim_matches('/')).filter(|p| !p.is_empty());
+
+    let matching: Vec<String> = entries
         .iter()
-        .map(|e| {
+        .filter_map(|e| {
             let path = e.get("path").and_then(|v| v.as_str()).unwrap_or("?");
+            if let Some(prefix) = prefix {
+                let under = path
+                    .strip_prefix(prefix)
+                    .is_some_and(|rest| rest.starts_with('/'));
+                if !under {
+                    return None;
+                }
+            }
             let entry_type = e.get("type").and_then(|v| v.as_str()).unwrap_or("blob");
             let icon = if entry_type == "tree" { "/" } else { "" };
-            format!("{path}{icon}")
+            Some(format!("{path}{icon}"))
         })
         .collect();
 
-    Ok(CallToolResult::success(vec![Content::text(
-        formatted.join("\n"),
-    )]))
+    let mut output = if matching.is_empty() {
+        match prefix {
+            Some(prefix) => format!("No files found under {prefix}/."),
+            None => "No files found in tree.".to_string(),
+        }
+    } else {
+        let total = matching.len();
+        let shown = max_entries.unwrap_or(total).min(total);
+        let mut text = matching[..shown].join("\n");
+        if shown < total {
+            text.push_str(&format!("\n... [truncated, {shown} of {total} entries shown]"));
+        }
+        text
+    };
+
+    if server_truncated {
+        output.push_str(
+            "\n\n**Note:** the server truncated this tree, so the listing is incomplete. \
+             Use file_list on a subdirectory to see the rest.",
+        );
+    }
+    output
+}
+
+/// Guess a MIME type from a file extension. Falls back to `application/octet-stream`.
+fn content_type_for_path(path: &str) -> &'static str {
+    let ext = path
+        .rsplit_once('.')
+        .map(|(_, ext)| ext.to_ascii_lowercase())
+        .unwrap_or_default();
+    match ext.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "svg" => "image/svg+xml",
+        "ico" => "image/x-icon",
+        "bmp" => "image/bmp",
+        "pdf" => "application/pdf",
+        "zip" => "application/zip",
+        "gz" | "tgz" => "application/gzip",
+        "tar" => "application/x-tar",
+        "wasm" => "application/wasm",
+        "json" => "application/json",
+        "xml" => "application/xml",
+        "html" | "htm" => "text/html",
+        "css" => "text/css",
+        "js" | "mjs" => "text/javascript",
+        "csv" => "text/csv",
+        "md" => "text/markdown",
+        "txt" => "text/plain",
+        "woff" => "font/woff",
+        "woff2" => "font/woff2",
+        "ttf" => "font/ttf",
+        "mp3" => "audio/mpeg",
+        "wav" => "audio/wav",
+        "mp4" => "video/mp4",
+        "webm" => "video/webm",
+        _ => "application/octet-stream",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn raw_file_path_per_platform() {
+        assert_eq!(raw_file_path(Platform::Gitea, "o", "r", "src/main.rs"), "/repos/o/r/raw/src/main.rs");
+        assert_eq!(raw_file_path(Platform::GitHub, "o", "r", "src/main.rs"), "/repos/o/r/contents/src/main.rs");
+    }
+
+    #[test]
+    fn gitea_move_creates_and_deletes_in_one_request() {
+        let body = gitea_move_body("old.txt", "docs/new.txt", "aGVs\nbG8=\n", "abc123", "Move it", "main");
+        assert_eq!(
+            body["files"],
+            serde_json::json!([
+                { "operation": "create", "path": "docs/new.txt", "content": "aGVsbG8=" },
+                { "operation": "delete", "path": "old.txt", "sha": "abc123" },
+            ])
+        );
+        assert_eq!(body["branch"], "main");
+    }
+
+    #[test]
+    fn move_refuses_files_without_content() {
+        let lfs = serde_json::json!({ "type": "file", "size": 5_000_000, "sha": "abc" });
+        assert!(move_content(&lfs, "big.bin").unwrap_err().to_string().contains("big.bin cannot be moved"));
+        let truncated = serde_json::json!({ "size": 12, "content": "" });
+        assert!(move_content(&truncated, "a.txt").is_err());
+
+        let empty = serde_json::json!({ "size": 0, "content": "" });
+        assert_eq!(move_content(&empty, "empty.txt").unwrap(), "");
+        let small = serde_json::json!({ "size": 5, "content": "aGVsbG8=" });
+        assert_eq!(move_content(&small, "a.txt").unwrap(), "aGVsbG8=");
+    }
+
+    #[test]
+    fn move_keeps_mode_and_refuses_overwrite() {
+        let script = serde_json::json!({ "path": "run.sh", "type": "blob", "mode": "100755", "sha": "b1" });
+        let tree = move_tree_entries("run.sh", "bin/run.sh", &script, false).unwrap();
+        assert_eq!(tree[0], serde_json::json!({ "path": "run.sh", "mode": "100755", "type": "blob", "sha": null }));
+        assert_eq!(tree[1], serde_json::json!({ "path": "bin/run.sh", "mode": "100755", "type": "blob", "sha": "b1" }));
+
+        let err = move_tree_entries("run.sh", "bin/run.sh", &script, true).unwrap_err();
+        assert!(matches!(err, GitxError::Conflict(_)));
+        let dir = serde_json::json!({ "path": "src", "type": "tree", "mode": "040000", "sha": "t1" });
+        assert!(move_tree_entries("src", "lib", &dir, false).is_err());
+    }
+
+    fn tree_entries() -> Vec<serde_json::Value> {
+        serde_json::json!([
+            { "path": "README.md", "type": "blob" },
+            { "path": "src", "type": "tree" },
+            { "path": "src/main.rs", "type": "blob" },
+            { "path": "src/tools", "type": "tree" },
+            { "path": "src/tools/files.rs", "type": "blob" },
+            { "path": "src-gen/out.rs", "type": "blob" },
+        ])
+        .as_array()
+        .cloned()
+        .unwrap()
+    }
+
+    #[test]
+    fn stale_sha_becomes_retry_instruction() {
+        let err = explain_stale_sha(GitxError::Status { status: 409, body: "is at abc but expected def".to_string() });
+        assert!(matches!(err, GitxError::Conflict(ref m) if m.starts_with("The file was modified since you read it")));
+        let err = explain_stale_sha(GitxError::Status { status: 422, body: "sha does not match [given: x]".to_string() });
+        assert!(matches!(err, GitxError::Conflict(_)));
+        let err = explain_stale_sha(GitxError::Status { status: 422, body: "path is invalid".to_string() });
+        assert!(matches!(err, GitxError::Status { status: 422, .. }));
+    }
+
+    #[test]
+    fn tree_prefix_filtering() {
+        let out = format_tree(&tree_entries(), Some("src/"), None, false);
+        assert_eq!(out, "src/main.rs\nsrc/tools/\nsrc/tools/files.rs");
+
+        let out = format_tree(&tree_entries(), Some("docs"), None, false);
+        assert_eq!(out, "No files found under docs/.");
+    }
+
+    #[test]
+    fn tree_truncation_markers() {
+        let out = format_tree(&tree_entries(), None, Some(2), false);
+        assert_eq!(out, "README.md\nsrc/\n... [truncated, 2 of 6 entries shown]");
+
+        let out = format_tree(&tree_entries(), Some("src/tools"), Some(10), true);
+        assert!(out.starts_with("src/tools/files.rs\n\n**Note:** the server truncated"));
+    }
+
+    /// Non-recursive trees keyed by tree SHA.
+    fn two_level_tree() -> std::collections::HashMap<&'static str, serde_json::Value> {
+        std::collections::HashMap::from([
+            (
+                "main",
+                serde_json::json!({ "tree": [
+                    { "path": "README.md", "type": "blob", "sha": "r1" },
+                    { "path": "src", "type": "tree", "sha": "t-src" },
+                ] }),
+            ),
+            (
+                "t-src",
+                serde_json::json!({ "tree": [
+                    { "path": "main.rs", "type": "blob", "sha": "m1" },
+                    { "path": "tools", "type": "tree", "sha": "t-tools" },
+                ] }),
+            ),
+            ("t-tools", serde_json::json!({ "tree": [{ "path": "files.rs", "type": "blob", "sha": "f1" }] })),
+        ])
+    }
+
+    /// Drive a walk over `trees` the way `walk_tree` does over the API.
+    fn walk(trees: &std::collections::HashMap<&str, serde_json::Value>, prefix: Option<&str>, depth: usize) -> TreeWalk {
+        let mut walk = TreeWalk::new("main", prefix, depth);
+        while let Some((sha, dir)) = walk.next_tree() {
+            walk.visit(&dir, &trees[sha.as_str()]);
+        }
+        walk
+    }
+
+    fn walked_paths(walk: &TreeWalk) -> Vec<&str> {
+        walk.entries.iter().filter_map(|e| e.get("path").and_then(|v| v.as_str())).collect()
+    }
+
+    #[test]
+    fn tree_walk_stops_at_depth() {
+        let trees = two_level_tree();
+        let shallow = walk(&trees, None, 1);
+        assert_eq!(walked_paths(&shallow), ["README.md", "src"]);
+        assert!(!shallow.server_truncated && !shallow.limited);
+        assert_eq!(shallow.requests, 1);
+
+        let deeper = walk(&trees, None, 2);
+        assert_eq!(walked_paths(&deeper), ["README.md", "src", "src/main.rs", "src/tools"]);
+        assert_eq!(format_tree(&deeper.entries, None, None, false), "README.md\nsrc/\nsrc/main.rs\nsrc/tools/");
+    }
+
+    #[test]
+    fn tree_walk_depth_counts_from_prefix() {
+        let trees = two_level_tree();
+        assert_eq!(walked_paths(&walk(&trees, Some("src/tools/"), 1)), ["src/tools/files.rs"]);
+        assert_eq!(walked_paths(&walk(&trees, Some("src"), 1)), ["src/main.rs", "src/tools"]);
+    }
+
+    #[tokio::test]
+    async fn tree_depth_zero_is_rejected() {
+        let config = crate::config::Config::new("http://127.0.0.1:9".to_string(), "t".to_string(), Platform::Gitea);
+        let client = crate::client::GiteaClient::new(&config).unwrap();
+        let params: TreeGetParams = serde_json::from_value(serde_json::json!({ "owner": "o", "repo": "r", "depth": 0 })).unwrap();
+        let err = tree_get(&client, params, None).await.unwrap_err();
+        assert!(matches!(err, GitxError::InvalidParam(_)), "{err}");
+    }
+
+    #[test]
+    fn tree_walk_stops_at_request_limit() {
+        // A chain of nested directories deeper than the request budget.
+        let trees: std::collections::HashMap<String, serde_json::Value> = (0..=MAX_WALK_REQUESTS)
+            .map(|i| {
+                let sha = if i == 0 { "main".to_string() } else { format!("t{i}") };
+                (sha, serde_json::json!({ "tree": [{ "path": "d", "type": "tree", "sha": format!("t{}", i + 1) }] }))
+            })
+            .collect();
+        let mut walk = TreeWalk::new("main", None, usize::MAX);
+        while let Some((sha, dir)) = walk.next_tree() {
+            walk.visit(&dir, &trees[&sha]);
+        }
+        assert!(walk.limited);
+        assert_eq!(walk.requests, MAX_WALK_REQUESTS);
+        assert_eq!(walk.entries.len(), MAX_WALK_REQUESTS);
+    }
+
+    #[test]
+    fn tree_walk_stops_at_entry_limit() {
+        let files: Vec<_> = (0..MAX_WALK_ENTRIES + 10)
+            .map(|i| serde_json::json!({ "path": format!("f{i}"), "type": "blob", "sha": "b" }))
+            .collect();
+        let mut walk = TreeWalk::new("main", None, 1);
+        walk.next_tree();
+        walk.visit("", &serde_json::json!({ "tree": files }));
+        assert!(walk.limited);
+        assert_eq!(walk.entries.len(), MAX_WALK_ENTRIES);
+    }
+
+    #[test]
+    fn content_type_from_extension() {
+        assert_eq!(content_type_for_path("assets/logo.PNG"), "image/png");
+        assert_eq!(content_type_for_path("docs/manual.pdf"), "application/pdf");
+        assert_eq!(content_type_for_path("Makefile"), "application/octet-stream");
+        assert_eq!(content_type_for_path("dist/app.tar.gz"), "application/gzip");
+    }
 }
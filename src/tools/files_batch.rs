@@ -0,0 +1,436 @@
+use rmcp::model::{CallToolResult, Content};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::client::GitClient;
+use crate::error::{GitxError, Result};
+use crate::platform::Platform;
+use crate::repo_resolver::RepoInfo;
+use crate::server::resolve_owner_repo;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum FileOperation {
+    Create,
+    Update,
+    Delete,
+}
+
+impl FileOperation {
+    fn as_str(self) -> &'static str {
+        match self {
+            FileOperation::Create => "create",
+            FileOperation::Update => "update",
+            FileOperation::Delete => "delete",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct FileChange {
+    /// File path within the repository.
+    pub path: String,
+    /// File content (plain text, will be base64-encoded automatically). Ignored for deletes.
+    pub content: Option<String>,
+    /// One of "create", "update", or "delete".
+    pub operation: FileOperation,
+    /// SHA of the existing file (update/delete). Looked up automatically when omitted.
+    pub sha: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct FileCommitBatchParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Files to create, update, or delete in a single commit.
+    pub files: Vec<FileChange>,
+    /// Commit message.
+    pub message: String,
+    /// Branch to commit to. Defaults to the default branch.
+    pub branch: Option<String>,
+}
+
+pub async fn file_commit_batch(
+    client: &dyn GitClient,
+    params: FileCommitBatchParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+
+    validate_changes(&params.files)?;
+
+    let branch = match &params.branch {
+        Some(b) => b.clone(),
+        None => client.default_branch(&owner, &repo).await?,
+    };
+
+    let sha = commit_changes(client, &owner, &repo, &branch, &params.message, &params.files).await?;
+    let short = &sha[..7.min(sha.len())];
+
+    Ok(CallToolResult::success(vec![Content::text(format!(
+        "Committed {} file change(s) to {branch} (`{short}`)\n{}",
+        params.files.len(),
+        summarize_changes(&params.files)
+    ))]))
+}
+
+/// Reject an empty change list and creates/updates without content.
+pub(crate) fn validate_changes(files: &[FileChange]) -> Result<()> {
+    if files.is_empty() {
+        return Err(GitxError::MissingParam("files (at least one change is required)".to_string()));
+    }
+    for change in files {
+        if change.operation != FileOperation::Delete && change.content.is_none() {
+            return Err(GitxError::MissingParam(format!(
+                "content for {} of {}",
+                change.operation.as_str(),
+                change.path
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// One `- <operation> <path>` line per change.
+pub(crate) fn summarize_changes(files: &[FileChange]) -> String {
+    files
+        .iter()
+        .map(|f| format!("- {} {}", f.operation.as_str(), f.path.trim_start_matches('/')))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Commit `files` to `branch` as a single commit and return its SHA.
+pub(crate) async fn commit_changes(
+    client: &dyn GitClient,
+    owner: &str,
+    repo: &str,
+    branch: &str,
+    message: &str,
+    files: &[FileChange],
+) -> Result<String> {
+    match client.platform() {
+        Platform::Gitea => commit_gitea(client, owner, repo, branch, message, files).await,
+        Platform::GitLab => Err(crate::client::gitlab_unsupported("Committing files")),
+        Platform::GitHub => commit_github(client, owner, repo, branch, message, files).await,
+    }
+}
+
+/// Look up the blob SHA of an existing file on `branch`.
+async fn existing_sha(
+    client: &dyn GitClient,
+    owner: &str,
+    repo: &str,
+    branch: &str,
+    path: &str,
+) -> Result<String> {
+    let file = client
+        .get_json_with_query(&format!("/repos/{owner}/{repo}/contents/{path}"), &[("ref", branch)])
+        .await?;
+    file.get("sha")
+        .and_then(|v| v.as_str())
+        .map(String::from)
+        .ok_or_else(|| GitxError::Api(format!("No SHA returned for {path}")))
+}
+
+/// Gitea/Forgejo: a single `POST /contents` with a `files` array.
+async fn commit_gitea(
+    client: &dyn GitClient,
+    owner: &str,
+    repo: &str,
+    branch: &str,
+    message: &str,
+    files: &[FileChange],
+) -> Result<String> {
+    use base64::Engine;
+
+    let mut entries = Vec::with_capacity(files.len());
+    for change in files {
+        let path = change.path.trim_start_matches('/');
+        let mut entry = serde_json::json!({
+            "operation": change.operation.as_str(),
+            "path": path,
+        });
+        if let Some(content) = &change.content {
+            entry["content"] = serde_json::Value::String(
+                base64::engine::general_purpose::STANDARD.encode(content.as_bytes()),
+            );
+        }
+        if change.operation != FileOperation::Create {
+            let sha = match &change.sha {
+                Some(sha) => sha.clone(),
+                None => existing_sha(client, owner, repo, branch, path).await?,
+            };
+            entry["sha"] = serde_json::Value::String(sha);
+        }
+        entries.push(entry);
+    }
+
+    let body = serde_json::json!({
+        "files": entries,
+        "message": message,
+        "branch": branch,
+    });
+
+    let result = client
+        .post_json(&format!("/repos/{owner}/{repo}/contents"), &body)
+        .await?;
+
+    Ok(result
+        .get("commit")
+        .and_then(|c| c.get("sha"))
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+        .to_string())
+}
+
+/// GitHub: check every change against the branch's current tree, upload a
+/// blob per created/updated file, then commit them all through [`commit_tree`].
+async fn commit_github(
+    client: &dyn GitClient,
+    owner: &str,
+    repo: &str,
+    branch: &str,
+    message: &str,
+    files: &[FileChange],
+) -> Result<String> {
+    use base64::Engine;
+
+    let head = branch_head(client, owner, repo, branch).await?;
+    // Validate the whole batch before uploading anything.
+    let mut modes = Vec::with_capacity(files.len());
+    for change in files {
+        let current = tree_entry(client, owner, repo, &head.tree, change.path.trim_start_matches('/')).await?;
+        modes.push(check_change(change, current.as_ref())?);
+    }
+
+    let mut tree = Vec::with_capacity(files.len());
+    for (change, mode) in files.iter().zip(modes) {
+        let path = change.path.trim_start_matches('/');
+        let blob_sha = match (&change.operation, &change.content) {
+            (FileOperation::Delete, _) | (_, None) => serde_json::Value::Null,
+            (_, Some(content)) => {
+                let blob = client
+                    .post_json(
+                        &format!("/repos/{owner}/{repo}/git/blobs"),
+                        &serde_json::json!({
+                            "content": base64::engine::general_purpose::STANDARD.encode(content.as_bytes()),
+                            "encoding": "base64",
+                        }),
+                    )
+                    .await?;
+                blob.get("sha").cloned().unwrap_or(serde_json::Value::Null)
+            }
+        };
+        tree.push(serde_json::json!({
+            "path": path,
+            "mode": mode,
+            "type": "blob",
+            "sha": blob_sha,
+        }));
+    }
+
+    commit_tree(client, owner, repo, branch, message, &head, tree).await
+}
+
+/// Check one change against the tree entry currently at its path, the way
+/// Gitea's contents API does server-side, and return the file mode to commit
+/// with: creates need a free path, updates and deletes an existing file whose
+/// SHA matches `sha` when one was given. Existing files keep their mode.
+fn check_change(change: &FileChange, current: Option<&serde_json::Value>) -> Result<String> {
+    let path = change.path.trim_start_matches('/');
+    let Some(current) = current else {
+        return match change.operation {
+            FileOperation::Create => Ok("100644".to_string()),
+            FileOperation::Update | FileOperation::Delete => Err(GitxError::NotFound(path.to_string())),
+        };
+    };
+    if change.operation == FileOperation::Create {
+        return Err(GitxError::Conflict(format!("{path} already exists; use operation \"update\" to change it")));
+    }
+    if current.get("type").and_then(|v| v.as_str()) != Some("blob") {
+        return Err(GitxError::InvalidParam(format!("{path} is not a file")));
+    }
+    let current_sha = current.get("sha").and_then(|v| v.as_str()).unwrap_or("");
+    if let Some(sha) = change.sha.as_deref().filter(|s| *s != current_sha) {
+        return Err(GitxError::Conflict(format!(
+            "{path} has changed: sha {sha} was given but the branch has {current_sha}"
+        )));
+    }
+    Ok(current.get("mode").and_then(|v| v.as_str()).unwrap_or("100644").to_string())
+}
+
+/// The commit a branch points at and that commit's root tree.
+pub(crate) struct BranchHead {
+    pub commit: String,
+    pub tree: String,
+}
+
+/// GitHub: resolve `branch` to its head commit and root tree.
+pub(crate) async fn branch_head(client: &dyn GitClient, owner: &str, repo: &str, branch: &str) -> Result<BranchHead> {
+    let git_ref = client
+        .get_json(&format!("/repos/{owner}/{repo}/git/ref/heads/{branch}"))
+        .await?;
+    let commit = git_ref
+        .get("object")
+        .and_then(|o| o.get("sha"))
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| GitxError::Api(format!("Could not resolve branch {branch}")))?
+        .to_string();
+
+    let parent = client
+        .get_json(&format!("/repos/{owner}/{repo}/git/commits/{commit}"))
+        .await?;
+    let tree = parent
+        .get("tree")
+        .and_then(|t| t.get("sha"))
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| GitxError::Api(format!("Could not resolve tree of {commit}")))?
+        .to_string();
+
+    Ok(BranchHead { commit, tree })
+}
+
+/// Find the entry at `path` below the tree `tree_sha`, fetching one directory
+/// level at a time. `None` if nothing exists at `path`.
+pub(crate) async fn tree_entry(
+    client: &dyn GitClient,
+    owner: &str,
+    repo: &str,
+    tree_sha: &str,
+    path: &str,
+) -> Result<Option<serde_json::Value>> {
+    let names: Vec<&str> = path.split('/').filter(|n| !n.is_empty()).collect();
+    let mut tree_sha = tree_sha.to_string();
+    for (i, name) in names.iter().enumerate() {
+        let tree = client
+            .get_json(&format!("/repos/{owner}/{repo}/git/trees/{tree_sha}"))
+            .await?;
+        let Some(entry) = child_entry(&tree, name) else {
+            return Ok(None);
+        };
+        if i + 1 == names.len() {
+            return Ok(Some(entry.clone()));
+        }
+        match (entry.get("type").and_then(|v| v.as_str()), entry.get("sha").and_then(|v| v.as_str())) {
+            (Some("tree"), Some(sha)) => tree_sha = sha.to_string(),
+            _ => return Ok(None),
+        }
+    }
+    Ok(None)
+}
+
+/// The entry named `name` directly inside a (non-recursive) tree object.
+fn child_entry<'a>(tree: &'a serde_json::Value, name: &str) -> Option<&'a serde_json::Value> {
+    tree.get("tree")?
+        .as_array()?
+        .iter()
+        .find(|e| e.get("path").and_then(|v| v.as_str()) == Some(name))
+}
+
+/// GitHub: commit `tree` entries on top of `head` (new tree → commit → move
+/// the branch ref) and return the commit SHA. An entry with a null `sha`
+/// deletes its path.
+pub(crate) async fn commit_tree(
+    client: &dyn GitClient,
+    owner: &str,
+    repo: &str,
+    branch: &str,
+    message: &str,
+    head: &BranchHead,
+    tree: Vec<serde_json::Value>,
+) -> Result<String> {
+    let new_tree = client
+        .post_json(
+            &format!("/repos/{owner}/{repo}/git/trees"),
+            &serde_json::json!({ "base_tree": head.tree, "tree": tree }),
+        )
+        .await?;
+    let tree_sha = new_tree
+        .get("sha")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| GitxError::Api("Tree creation returned no SHA".to_string()))?;
+
+    let commit = client
+        .post_json(
+            &format!("/repos/{owner}/{repo}/git/commits"),
+            &serde_json::json!({
+                "message": message,
+                "tree": tree_sha,
+                "parents": [head.commit],
+            }),
+        )
+        .await?;
+    let commit_sha = commit
+        .get("sha")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| GitxError::Api("Commit creation returned no SHA".to_string()))?
+        .to_string();
+
+    client
+        .patch_json(
+            &format!("/repos/{owner}/{repo}/git/refs/heads/{branch}"),
+            &serde_json::json!({ "sha": commit_sha }),
+        )
+        .await?;
+
+    Ok(commit_sha)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(operation: FileOperation, sha: Option<&str>) -> FileChange {
+        FileChange {
+            path: "/bin/run.sh".to_string(),
+            content: Some("echo hi".to_string()),
+            operation,
+            sha: sha.map(String::from),
+        }
+    }
+
+    #[test]
+    fn check_change_keeps_mode_and_checks_sha() {
+        let current = serde_json::json!({ "path": "run.sh", "type": "blob", "mode": "100755", "sha": "b1" });
+        assert_eq!(check_change(&change(FileOperation::Update, None), Some(&current)).unwrap(), "100755");
+        assert_eq!(check_change(&change(FileOperation::Update, Some("b1")), Some(&current)).unwrap(), "100755");
+
+        let err = check_change(&change(FileOperation::Update, Some("stale")), Some(&current)).unwrap_err();
+        assert!(matches!(err, GitxError::Conflict(_)));
+        assert_eq!(err.to_string(), "bin/run.sh has changed: sha stale was given but the branch has b1");
+        assert!(matches!(
+            check_change(&change(FileOperation::Delete, Some("stale")), Some(&current)),
+            Err(GitxError::Conflict(_))
+        ));
+    }
+
+    #[test]
+    fn check_change_requires_free_path_for_create() {
+        let current = serde_json::json!({ "path": "run.sh", "type": "blob", "mode": "100644", "sha": "b1" });
+        assert!(matches!(check_change(&change(FileOperation::Create, None), Some(&current)), Err(GitxError::Conflict(_))));
+        assert_eq!(check_change(&change(FileOperation::Create, None), None).unwrap(), "100644");
+        assert!(matches!(check_change(&change(FileOperation::Update, None), None), Err(GitxError::NotFound(_))));
+        assert!(matches!(check_change(&change(FileOperation::Delete, None), None), Err(GitxError::NotFound(_))));
+
+        let dir = serde_json::json!({ "path": "run.sh", "type": "tree", "mode": "040000", "sha": "t1" });
+        assert!(matches!(check_change(&change(FileOperation::Update, None), Some(&dir)), Err(GitxError::InvalidParam(_))));
+    }
+
+    #[test]
+    fn child_entry_matches_exact_names() {
+        let tree = serde_json::json!({ "tree": [
+            { "path": "src", "type": "tree", "sha": "t1" },
+            { "path": "run.sh", "type": "blob", "mode": "100755", "sha": "b1" },
+        ]});
+        assert_eq!(child_entry(&tree, "run.sh").and_then(|e| e["mode"].as_str()), Some("100755"));
+        assert!(child_entry(&tree, "run").is_none());
+        assert!(child_entry(&serde_json::json!({}), "src").is_none());
+    }
+}
@@ -0,0 +1,79 @@
+use rmcp::model::{CallToolResult, Content};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::client::GitClient;
+use crate::error::{GitxError, Result};
+use crate::platform::Platform;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct GithubGraphqlParams {
+    /// GraphQL query or mutation document.
+    pub query: String,
+    /// Values for the variables the query declares, as a JSON object.
+    pub variables: Option<serde_json::Value>,
+}
+
+pub async fn github_graphql(client: &dyn GitClient, params: GithubGraphqlParams) -> Result<CallToolResult> {
+    require_github(client.platform())?;
+    let result = client
+        .post_json("/graphql", &graphql_body(&params))
+        .await?;
+
+    // GraphQL reports query errors in an `errors` array next to partial
+    // `data`, with HTTP 200, so the response is passed through whole.
+    Ok(CallToolResult::success(vec![Content::text(format!(
+        "```json\n{}\n```",
+        serde_json::to_string_pretty(&result)?
+    ))]))
+}
+
+/// GraphQL is a GitHub API; Gitea and GitLab (here) only speak REST.
+fn require_github(platform: Platform) -> Result<()> {
+    match platform {
+        Platform::GitHub => Ok(()),
+        Platform::Gitea | Platform::GitLab => Err(GitxError::Api(
+            "github_graphql is only available on GitHub. Use the REST-based tools on this platform.".to_string(),
+        )),
+    }
+}
+
+/// Build the `POST /graphql` body; `variables` is left out when not given.
+fn graphql_body(params: &GithubGraphqlParams) -> serde_json::Value {
+    let mut body = serde_json::json!({ "query": params.query });
+    if let Some(variables) = &params.variables {
+        body["variables"] = variables.clone();
+    }
+    body
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn graphql_body_shape() {
+        let params = GithubGraphqlParams {
+            query: "query($owner: String!) { repositoryOwner(login: $owner) { id } }".to_string(),
+            variables: Some(serde_json::json!({ "owner": "octocat" })),
+        };
+        assert_eq!(
+            graphql_body(&params),
+            serde_json::json!({
+                "query": "query($owner: String!) { repositoryOwner(login: $owner) { id } }",
+                "variables": { "owner": "octocat" },
+            })
+        );
+
+        let params = GithubGraphqlParams { query: "{ viewer { login } }".to_string(), variables: None };
+        assert_eq!(graphql_body(&params), serde_json::json!({ "query": "{ viewer { login } }" }));
+    }
+
+    #[test]
+    fn graphql_requires_github() {
+        assert!(require_github(Platform::GitHub).is_ok());
+        let err = require_github(Platform::Gitea).unwrap_err();
+        assert!(err.to_string().contains("only available on GitHub"));
+        assert!(require_github(Platform::GitLab).is_err());
+    }
+}
@@ -16,6 +16,8 @@ pub struct IssueCommentListParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
     /// Issue number.
     pub index: i64,
 }
@@ -28,18 +30,50 @@ pub struct IssueCommentCreateParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
     /// Issue number.
     pub index: i64,
     /// Comment body in markdown.
     pub body: String,
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct IssueCommentEditParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Comment ID (from issue_comment_list).
+    pub comment_id: i64,
+    /// New comment body in markdown.
+    pub body: String,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct IssueCommentDeleteParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Comment ID (from issue_comment_list).
+    pub comment_id: i64,
+}
+
 pub async fn issue_comment_list(
     client: &dyn GitClient,
     params: IssueCommentListParams,
     default_repo: Option<&RepoInfo>,
 ) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
     let val = client
         .get_json(&format!(
             "/repos/{owner}/{repo}/issues/{}/comments",
@@ -49,7 +83,7 @@ pub async fn issue_comment_list(
     let comments = val.as_array().cloned().unwrap_or_default();
 
     Ok(CallToolResult::success(vec![Content::text(
-        response::format_comment_list(&comments),
+        response::format_comment_list(&comments, client.settings()),
     )]))
 }
 
@@ -58,7 +92,7 @@ pub async fn issue_comment_create(
     params: IssueCommentCreateParams,
     default_repo: Option<&RepoInfo>,
 ) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
     let body = serde_json::json!({ "body": params.body });
     let comment = client
         .post_json(
@@ -68,6 +102,41 @@ pub async fn issue_comment_create(
         .await?;
 
     Ok(CallToolResult::success(vec![Content::text(
-        response::format_comment(&comment),
+        response::format_comment(&comment, client.settings()),
     )]))
 }
+
+pub async fn issue_comment_edit(
+    client: &dyn GitClient,
+    params: IssueCommentEditParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let body = serde_json::json!({ "body": params.body });
+    let comment = client
+        .patch_json(
+            &format!("/repos/{owner}/{repo}/issues/comments/{}", params.comment_id),
+            &body,
+        )
+        .await?;
+
+    Ok(CallToolResult::success(vec![Content::text(
+        response::format_comment(&comment, client.settings()),
+    )]))
+}
+
+pub async fn issue_comment_delete(
+    client: &dyn GitClient,
+    params: IssueCommentDeleteParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    client
+        .delete(&format!("/repos/{owner}/{repo}/issues/comments/{}", params.comment_id))
+        .await?;
+
+    Ok(CallToolResult::success(vec![Content::text(format!(
+        "Comment #{} deleted.",
+        params.comment_id
+    ))]))
+}
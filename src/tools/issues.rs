@@ -3,10 +3,13 @@ use schemars::JsonSchema;
 use serde::Deserialize;
 
 use crate::client::GitClient;
+use crate::config::ToolSettings;
 use crate::error::Result;
+use crate::platform::Platform;
 use crate::response;
 use crate::repo_resolver::RepoInfo;
-use crate::server::resolve_owner_repo;
+use crate::server::{resolve_owner_repo, InstanceOverride};
+use crate::types::common::{query_refs, validate_sort, QueryBuilder};
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct IssueListParams {
@@ -16,12 +19,49 @@ pub struct IssueListParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Query a different instance (or with a different token) for this call only.
+    #[serde(flatten)]
+    pub instance: InstanceOverride,
     /// Filter by state: open, closed, or all. Defaults to open.
     pub state: Option<String>,
     /// Filter by comma-separated label names.
     pub labels: Option<String>,
     /// Filter by milestone name.
     pub milestone: Option<String>,
+    /// Order by: created, updated, or comments. Defaults to created.
+    pub sort: Option<String>,
+    /// Sort direction: asc or desc. Defaults to desc.
+    pub direction: Option<String>,
+    /// Page number (1-based). Defaults to 1.
+    pub page: Option<i64>,
+    /// Items per page (max 50). Defaults to 20.
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct IssueSearchParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Full-text keyword matched against title and body.
+    pub keyword: Option<String>,
+    /// Only items created by this username.
+    pub author: Option<String>,
+    /// Only items assigned to this username.
+    pub assignee: Option<String>,
+    /// Filter by state: open, closed, or all. Defaults to open.
+    pub state: Option<String>,
+    /// Filter by comma-separated label names.
+    pub labels: Option<String>,
+    /// Restrict to "issues" or "pulls". Defaults to both.
+    pub kind: Option<String>,
     /// Page number (1-based). Defaults to 1.
     pub page: Option<i64>,
     /// Items per page (max 50). Defaults to 20.
@@ -36,8 +76,126 @@ pub struct IssueGetParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Query a different instance (or with a different token) for this call only.
+    #[serde(flatten)]
+    pub instance: InstanceOverride,
     /// Issue number.
     pub index: i64,
+    /// Output format: "markdown" (default) or "json" for the raw API object.
+    pub format: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct IssueGetManyParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Issue numbers to fetch (at most 50).
+    pub indexes: Vec<i64>,
+    /// Output format: "markdown" (default) or "json" for the raw API objects.
+    pub format: Option<String>,
+}
+
+/// Upper bound on `issue_get_many` so one call can't fan out unboundedly.
+const MAX_ISSUES_PER_BATCH: usize = 50;
+
+/// Require between 1 and [`MAX_ISSUES_PER_BATCH`] issue numbers.
+fn check_batch_size(indexes: &[i64]) -> Result<()> {
+    if indexes.is_empty() {
+        return Err(crate::error::GitxError::MissingParam("indexes (at least one issue number)".to_string()));
+    }
+    if indexes.len() > MAX_ISSUES_PER_BATCH {
+        return Err(crate::error::GitxError::InvalidParam(format!(
+            "indexes may list at most {MAX_ISSUES_PER_BATCH} issue numbers (got {})",
+            indexes.len()
+        )));
+    }
+    Ok(())
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct IssueDependenciesParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Issue or pull request number.
+    pub index: i64,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct IssueTimeAddParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Issue or pull request number.
+    pub index: i64,
+    /// Time spent, in seconds (e.g. 5400 for 1h 30m).
+    pub time: i64,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct IssueTimesListParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Issue or pull request number.
+    pub index: i64,
+    /// Only entries logged by this username.
+    pub user: Option<String>,
+    /// Page number (1-based). Defaults to 1.
+    pub page: Option<i64>,
+    /// Items per page (max 50). Defaults to 20.
+    pub limit: Option<i64>,
+}
+
+/// Shown by the time-tracking tools on platforms without a time-tracking API.
+const TIME_TRACKING_UNSUPPORTED: &str =
+    "Time tracking is only available on Gitea. GitHub has no time-tracking API.";
+
+/// Keywords that make a `#N` reference in an issue or PR body close it on merge.
+const CLOSING_KEYWORDS: &[&str] = &["close", "closes", "closed", "fix", "fixes", "fixed", "resolve", "resolves", "resolved"];
+
+/// Upper bound on how many body references `issue_dependencies` resolves.
+const MAX_LINKED_ISSUES: usize = 20;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct IssueEventsParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Issue or pull request number.
+    pub index: i64,
+    /// Page number (1-based). Defaults to 1.
+    pub page: Option<i64>,
+    /// Items per page (max 50). Defaults to 30.
+    pub limit: Option<i64>,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -48,6 +206,8 @@ pub struct IssueCreateParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
     /// Issue title.
     pub title: String,
     /// Issue body/description in markdown.
@@ -58,8 +218,26 @@ pub struct IssueCreateParams {
     pub milestone: Option<i64>,
     /// Usernames to assign.
     pub assignees: Option<Vec<String>>,
+    /// Markdown issue template to start from (file name from issue_templates_list, e.g. "bug_report.md").
+    /// Its body is placed before `body`.
+    pub template: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct IssueTemplatesListParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
 }
 
+/// Where Gitea/Forgejo and GitHub look for issue templates, in lookup order.
+const ISSUE_TEMPLATE_DIRS: &[&str] = &[".gitea/ISSUE_TEMPLATE", ".github/ISSUE_TEMPLATE"];
+
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct IssueEditParams {
     /// Repository owner. Optional if `directory` is provided.
@@ -68,6 +246,8 @@ pub struct IssueEditParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
     /// Issue number.
     pub index: i64,
     /// New title.
@@ -84,68 +264,512 @@ pub struct IssueEditParams {
     pub assignees: Option<Vec<String>>,
 }
 
-pub async fn issue_list(client: &dyn GitClient, params: IssueListParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
-    use crate::platform::Platform;
-
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
-    let mut query: Vec<(&str, String)> = Vec::new();
-
-    let state = params.state.unwrap_or_else(|| "open".to_string());
-    query.push(("state", state));
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct IssueStateParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Issue number.
+    pub index: i64,
+}
 
-    // Gitea needs type=issues to exclude PRs; GitHub doesn't need this
-    if client.platform() == Platform::Gitea {
-        query.push(("type", "issues".to_string()));
-    }
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct IssueAssigneesParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Issue or pull request number.
+    pub index: i64,
+    /// Usernames to add or remove. Other assignees are left untouched.
+    pub assignees: Vec<String>,
+}
 
-    if let Some(labels) = &params.labels {
-        query.push(("labels", labels.clone()));
-    }
-    if let Some(milestone) = &params.milestone {
-        query.push(("milestones", milestone.clone()));
-    }
-    query.push(("page", params.page.unwrap_or(1).to_string()));
-    query.push(("limit", params.limit.unwrap_or(20).min(50).to_string()));
+pub async fn issue_list(client: &dyn GitClient, params: IssueListParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    validate_sort(params.sort.as_deref(), params.direction.as_deref(), ISSUE_SORTS)?;
+    let gitea = client.platform() == Platform::Gitea;
+    let query = QueryBuilder::for_client(client)
+        .param("state", params.state.as_deref().unwrap_or("open"))
+        // Gitea needs type=issues to exclude PRs; GitHub doesn't need this
+        .opt("type", gitea.then_some("issues"))
+        .opt("labels", params.labels.as_ref())
+        .opt("milestones", params.milestone.as_ref())
+        // Gitea's issue list cannot be ordered, so the page is sorted below instead.
+        .opt("sort", params.sort.as_ref().filter(|_| !gitea))
+        .opt("direction", params.direction.as_ref().filter(|_| !gitea))
+        .page(params.page)
+        .limit(params.limit)
+        .build();
 
-    let query_refs: Vec<(&str, &str)> = query.iter().map(|(k, v)| (*k, v.as_str())).collect();
     let val = client
-        .get_json_with_query(&format!("/repos/{owner}/{repo}/issues"), &query_refs)
+        .get_json_with_query(&format!("/repos/{owner}/{repo}/issues"), &query_refs(&query))
         .await?;
     let all_items = val.as_array().cloned().unwrap_or_default();
 
     // On GitHub, filter out pull requests (they have a "pull_request" key)
-    let issues: Vec<serde_json::Value> = if client.platform() == Platform::GitHub {
+    let mut issues: Vec<serde_json::Value> = if client.platform() == Platform::GitHub {
         all_items.into_iter().filter(|i| i.get("pull_request").is_none()).collect()
     } else {
         all_items
     };
+    let page_sorted = gitea && (params.sort.is_some() || params.direction.is_some());
+    if page_sorted {
+        sort_issues(&mut issues, params.sort.as_deref().unwrap_or("created"), params.direction.as_deref());
+    }
+
+    let mut text = response::format_issue_list(&issues);
+    if page_sorted && !issues.is_empty() {
+        text.push_str(PAGE_SORT_NOTE);
+    }
+
+    Ok(CallToolResult::success(vec![Content::text(text)]))
+}
+
+/// Appended when `issue_list` sorted a Gitea page locally.
+const PAGE_SORT_NOTE: &str = "\n\n_Gitea cannot order issues server-side, so only this page was sorted; \
+     other pages follow their own order._";
+
+/// Sort keys `issue_list` accepts, matching GitHub's issue list `sort` values.
+const ISSUE_SORTS: &[&str] = &["created", "updated", "comments"];
+
+/// Order a fetched page of issues by `sort` (one of [`ISSUE_SORTS`]); descending unless `direction` is "asc".
+fn sort_issues(issues: &mut [serde_json::Value], sort: &str, direction: Option<&str>) {
+    match sort {
+        "comments" => issues.sort_by_key(|i| i.get("comments").and_then(|c| c.as_i64()).unwrap_or(0)),
+        _ => {
+            let field = if sort == "updated" { "updated_at" } else { "created_at" };
+            issues.sort_by(|a, b| {
+                let at = |i: &serde_json::Value| i.get(field).and_then(|v| v.as_str()).unwrap_or("").to_string();
+                at(a).cmp(&at(b))
+            });
+        }
+    }
+    if direction != Some("asc") {
+        issues.reverse();
+    }
+}
+
+pub async fn issue_search(
+    client: &dyn GitClient,
+    params: IssueSearchParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let issues = match client.platform() {
+        Platform::Gitea => {
+            let query = QueryBuilder::for_client(client)
+                .param("state", params.state.as_deref().unwrap_or("open"))
+                .opt("q", params.keyword.as_ref())
+                .opt("created_by", params.author.as_ref())
+                .opt("assigned_by", params.assignee.as_ref())
+                .opt("labels", params.labels.as_ref())
+                .opt("type", params.kind.as_deref().filter(|k| matches!(*k, "issues" | "pulls")))
+                .page(params.page)
+                .limit(params.limit)
+                .build();
+
+            let val = client
+                .get_json_with_query(&format!("/repos/{owner}/{repo}/issues"), &query_refs(&query))
+                .await?;
+            val.as_array().cloned().unwrap_or_default()
+        }
+        Platform::GitLab => return Err(crate::client::gitlab_unsupported("issue_search")),
+        Platform::GitHub => {
+            let query = QueryBuilder::for_client(client)
+                .param("q", github_search_query(&owner, &repo, &params))
+                .page(params.page)
+                .limit(params.limit)
+                .build();
+            let val = client
+                .get_json_with_query("/search/issues", &query_refs(&query))
+                .await?;
+            val.get("items")
+                .and_then(|v| v.as_array())
+                .cloned()
+                .unwrap_or_default()
+        }
+    };
 
     Ok(CallToolResult::success(vec![Content::text(
         response::format_issue_list(&issues),
     )]))
 }
 
+/// Build a GitHub `/search/issues` query string from structured search params.
+fn github_search_query(owner: &str, repo: &str, params: &IssueSearchParams) -> String {
+    let mut terms = vec![format!("repo:{owner}/{repo}")];
+
+    if let Some(keyword) = &params.keyword {
+        terms.push(keyword.clone());
+    }
+    if let Some(author) = &params.author {
+        terms.push(format!("author:{author}"));
+    }
+    if let Some(assignee) = &params.assignee {
+        terms.push(format!("assignee:{assignee}"));
+    }
+    match params.state.as_deref().unwrap_or("open") {
+        "all" => {}
+        state => terms.push(format!("state:{state}")),
+    }
+    if let Some(labels) = &params.labels {
+        for label in labels.split(',').map(str::trim).filter(|l| !l.is_empty()) {
+            terms.push(format!("label:\"{label}\""));
+        }
+    }
+    match params.kind.as_deref() {
+        Some("issues") => terms.push("is:issue".to_string()),
+        Some("pulls") => terms.push("is:pr".to_string()),
+        _ => {}
+    }
+
+    terms.join(" ")
+}
+
 pub async fn issue_get(client: &dyn GitClient, params: IssueGetParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
     let issue = client
         .get_json(&format!("/repos/{owner}/{repo}/issues/{}", params.index))
         .await?;
 
     Ok(CallToolResult::success(vec![Content::text(
-        response::format_issue(&issue),
+        response::render(&issue, params.format.as_deref(), |v| response::format_issue(v, client.settings())),
+    )]))
+}
+
+pub async fn issue_get_many(
+    client: &dyn GitClient,
+    params: IssueGetManyParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    check_batch_size(&params.indexes)?;
+
+    let paths: Vec<String> = params
+        .indexes
+        .iter()
+        .map(|index| format!("/repos/{owner}/{repo}/issues/{index}"))
+        .collect();
+    let results = crate::tools::batch::get_many(client, &paths).await;
+
+    let sections: Vec<String> = params
+        .indexes
+        .iter()
+        .zip(results)
+        .map(|(index, result)| match result {
+            Ok(issue) => response::render(&issue, params.format.as_deref(), |v| response::format_issue(v, client.settings())),
+            Err(e) => format!("## Issue #{index}\nError: {e}"),
+        })
+        .collect();
+
+    Ok(CallToolResult::success(vec![Content::text(sections.join("\n\n---\n\n"))]))
+}
+
+pub async fn issue_dependencies(
+    client: &dyn GitClient,
+    params: IssueDependenciesParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let path = format!("/repos/{owner}/{repo}/issues/{}", params.index);
+    let issue = client.get_json(&path).await?;
+    let mut sections = vec![format!("## #{} dependencies", params.index)];
+
+    // Only Gitea tracks dependencies as data; elsewhere the body is all there is.
+    if client.platform() == Platform::Gitea {
+        for (heading, endpoint) in [("Blocked by", "dependencies"), ("Blocks", "blocks")] {
+            let text = match client.get_json(&format!("{path}/{endpoint}")).await {
+                Ok(val) => {
+                    let issues = val.as_array().cloned().unwrap_or_default();
+                    if issues.is_empty() { "None.".to_string() } else { response::format_issue_list(&issues) }
+                }
+                Err(crate::error::GitxError::NotFound(_)) => "Dependencies are not enabled for this repository.".to_string(),
+                Err(e) => return Err(e),
+            };
+            sections.push(format!("**{heading}** (tracked by the API):\n{text}"));
+        }
+    }
+
+    let body = issue.get("body").and_then(|v| v.as_str()).unwrap_or("");
+    let mut references = closing_references(body);
+    references.truncate(MAX_LINKED_ISSUES);
+    let text = if references.is_empty() {
+        "None.".to_string()
+    } else {
+        let this_repo = format!("{owner}/{repo}");
+        let paths: Vec<String> = references
+            .iter()
+            .map(|(other, number)| format!("/repos/{}/issues/{number}", other.as_deref().unwrap_or(&this_repo)))
+            .collect();
+        let results = crate::tools::batch::get_many(client, &paths).await;
+        references
+            .iter()
+            .zip(results)
+            .map(|((other, number), result)| {
+                let reference = format!("{}#{number}", other.as_deref().unwrap_or(""));
+                match result {
+                    Ok(linked) => format!(
+                        "- {reference} {} ({})",
+                        linked.get("title").and_then(|v| v.as_str()).unwrap_or("(untitled)"),
+                        linked.get("state").and_then(|v| v.as_str()).unwrap_or("unknown"),
+                    ),
+                    Err(e) => format!("- {reference} Error: {e}"),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    sections.push(format!("**Closes** (parsed from the body's closing keywords, not tracked by the API):\n{text}"));
+
+    Ok(CallToolResult::success(vec![Content::text(sections.join("\n\n"))]))
+}
+
+/// Issues a body closes via "Closes #N" / "fixes owner/repo#N" style references,
+/// in order of first mention. The repository is `None` for same-repo references.
+fn closing_references(body: &str) -> Vec<(Option<String>, i64)> {
+    let words: Vec<&str> = body.split_whitespace().collect();
+    let mut references = Vec::new();
+    for pair in words.windows(2) {
+        let keyword = pair[0].trim_end_matches(':').to_lowercase();
+        if !CLOSING_KEYWORDS.contains(&keyword.as_str()) {
+            continue;
+        }
+        let target = pair[1].trim_end_matches([',', '.', ';', ':', ')']);
+        let Some((other, number)) = target.rsplit_once('#') else { continue };
+        let Ok(number) = number.parse::<i64>() else { continue };
+        let other = match other {
+            "" => None,
+            other if other.split('/').count() == 2 && !other.starts_with('/') && !other.ends_with('/') => {
+                Some(other.to_string())
+            }
+            _ => continue,
+        };
+        if number > 0 && !references.contains(&(other.clone(), number)) {
+            references.push((other, number));
+        }
+    }
+    references
+}
+
+pub async fn issue_time_add(
+    client: &dyn GitClient,
+    params: IssueTimeAddParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    if client.platform() != Platform::Gitea {
+        return Ok(CallToolResult::success(vec![Content::text(TIME_TRACKING_UNSUPPORTED)]));
+    }
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    if params.time <= 0 {
+        return Err(crate::error::GitxError::InvalidParam("time must be a positive number of seconds".to_string()));
+    }
+
+    client
+        .post_json(
+            &format!("/repos/{owner}/{repo}/issues/{}/times", params.index),
+            &serde_json::json!({ "time": params.time }),
+        )
+        .await?;
+
+    Ok(CallToolResult::success(vec![Content::text(format!(
+        "Logged {} on #{}.",
+        format_tracked_time(params.time),
+        params.index
+    ))]))
+}
+
+pub async fn issue_times_list(
+    client: &dyn GitClient,
+    params: IssueTimesListParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    if client.platform() != Platform::Gitea {
+        return Ok(CallToolResult::success(vec![Content::text(TIME_TRACKING_UNSUPPORTED)]));
+    }
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let query = QueryBuilder::for_client(client)
+        .opt("user", params.user.as_ref())
+        .page(params.page)
+        .limit(params.limit)
+        .build();
+
+    let val = client
+        .get_json_with_query(&format!("/repos/{owner}/{repo}/issues/{}/times", params.index), &query_refs(&query))
+        .await?;
+    let entries = val.as_array().cloned().unwrap_or_default();
+
+    Ok(CallToolResult::success(vec![Content::text(format_times_list(params.index, &entries, client.settings()))]))
+}
+
+/// One line per tracked-time entry (user, time, date), then the total for the page.
+fn format_times_list(index: i64, entries: &[serde_json::Value], settings: ToolSettings) -> String {
+    if entries.is_empty() {
+        return format!("No time tracked on #{index}.");
+    }
+
+    let mut total = 0;
+    let mut lines: Vec<String> = entries
+        .iter()
+        .map(|e| {
+            let user = e.get("user_name").and_then(|v| v.as_str()).unwrap_or("?");
+            let time = e.get("time").and_then(|v| v.as_i64()).unwrap_or(0);
+            let created = e.get("created").and_then(|v| v.as_str()).unwrap_or("");
+            let id = e.get("id").and_then(|v| v.as_i64()).unwrap_or(0);
+            total += time;
+            format!(
+                "- {user}: {} on {} [id: {id}]",
+                format_tracked_time(time),
+                response::format_timestamp(created, settings)
+            )
+        })
+        .collect();
+    lines.push(format!("\n**Total:** {}", format_tracked_time(total)));
+    lines.join("\n")
+}
+
+/// Render seconds as "1h 30m", "45m", or "20s".
+fn format_tracked_time(secs: i64) -> String {
+    let (hours, minutes, seconds) = (secs / 3600, secs % 3600 / 60, secs % 60);
+    match (hours, minutes) {
+        (0, 0) => format!("{seconds}s"),
+        (0, m) => format!("{m}m"),
+        (h, 0) => format!("{h}h"),
+        (h, m) => format!("{h}h {m}m"),
+    }
+}
+
+pub async fn issue_events(
+    client: &dyn GitClient,
+    params: IssueEventsParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let query = QueryBuilder::for_client(client)
+        .page(params.page)
+        .limit(params.limit.or(Some(30)))
+        .build();
+
+    let val = client
+        .get_json_with_query(
+            &format!("/repos/{owner}/{repo}/issues/{}/timeline", params.index),
+            &query_refs(&query),
+        )
+        .await?;
+    let events = val.as_array().cloned().unwrap_or_default();
+
+    if events.is_empty() {
+        return Ok(CallToolResult::success(vec![Content::text(
+            "No events found.",
+        )]));
+    }
+
+    let formatted: Vec<String> = events
+        .iter()
+        .map(|e| format_timeline_event(client.platform(), e, client.settings()))
+        .collect();
+
+    Ok(CallToolResult::success(vec![Content::text(
+        formatted.join("\n"),
     )]))
 }
 
+/// One timeline line: `- {time} {actor}: {what happened}`.
+fn format_timeline_event(platform: Platform, event: &serde_json::Value, settings: ToolSettings) -> String {
+    let s = |v: &serde_json::Value, path: &[&str]| -> String {
+        path.iter()
+            .try_fold(v, |v, key| v.get(key))
+            .and_then(|v| v.as_str().map(String::from).or_else(|| v.as_i64().map(|n| n.to_string())))
+            .unwrap_or_default()
+    };
+
+    let actor = [&["actor", "login"][..], &["user", "login"], &["author", "name"]]
+        .iter()
+        .map(|path| s(event, path))
+        .find(|a| !a.is_empty())
+        .unwrap_or_else(|| "unknown".to_string());
+    let time = [&["created_at"][..], &["submitted_at"], &["author", "date"]]
+        .iter()
+        .map(|path| s(event, path))
+        .find(|t| !t.is_empty())
+        .map(|t| response::format_timestamp(&t, settings))
+        .unwrap_or_else(|| "?".to_string());
+
+    let what = match platform {
+        // Gitea's timeline is a list of typed comments.
+        Platform::Gitea => match s(event, &["type"]).as_str() {
+            "comment" => "commented".to_string(),
+            "label" => format!("labeled {}", s(event, &["label", "name"])),
+            "assignees" if event.get("removed_assignee").and_then(|v| v.as_bool()).unwrap_or(false) => {
+                format!("unassigned {}", s(event, &["assignee", "login"]))
+            }
+            "assignees" => format!("assigned {}", s(event, &["assignee", "login"])),
+            "milestone" => format!("set milestone {}", s(event, &["milestone", "title"])),
+            "change_title" => format!("renamed from \"{}\" to \"{}\"", s(event, &["old_title"]), s(event, &["new_title"])),
+            "close" => "closed".to_string(),
+            "reopen" => "reopened".to_string(),
+            "merge_pull" => "merged".to_string(),
+            "issue_ref" | "pull_ref" | "comment_ref" | "commit_ref" => match s(event, &["ref_issue", "number"]).as_str() {
+                "" => "referenced this".to_string(),
+                n => format!("referenced this from #{n}"),
+            },
+            "review" => "reviewed".to_string(),
+            "" => "event".to_string(),
+            other => other.replace('_', " "),
+        },
+        Platform::GitHub | Platform::GitLab => match s(event, &["event"]).as_str() {
+            "labeled" => format!("labeled {}", s(event, &["label", "name"])),
+            "unlabeled" => format!("unlabeled {}", s(event, &["label", "name"])),
+            "assigned" => format!("assigned {}", s(event, &["assignee", "login"])),
+            "unassigned" => format!("unassigned {}", s(event, &["assignee", "login"])),
+            "milestoned" => format!("set milestone {}", s(event, &["milestone", "title"])),
+            "renamed" => format!("renamed from \"{}\" to \"{}\"", s(event, &["rename", "from"]), s(event, &["rename", "to"])),
+            "review_requested" => format!("requested review from {}", s(event, &["requested_reviewer", "login"])),
+            "reviewed" => format!("reviewed ({})", s(event, &["state"]).to_lowercase()),
+            "cross-referenced" => match s(event, &["source", "issue", "number"]).as_str() {
+                "" => "referenced this".to_string(),
+                n => format!("referenced this from #{n}"),
+            },
+            "committed" => {
+                let sha = s(event, &["sha"]);
+                format!("committed {}", &sha[..7.min(sha.len())])
+            }
+            "" => "event".to_string(),
+            other => other.replace('_', " "),
+        },
+    };
+
+    format!("- {time} {actor}: {what}")
+}
+
 pub async fn issue_create(
     client: &dyn GitClient,
     params: IssueCreateParams,
     default_repo: Option<&RepoInfo>,
 ) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
     let mut body = serde_json::json!({ "title": params.title });
 
-    if let Some(b) = &params.body {
-        body["body"] = serde_json::Value::String(b.clone());
+    let template_body = match &params.template {
+        Some(name) => Some(load_template_body(client, &owner, &repo, name).await?),
+        None => None,
+    };
+    let text = match (template_body, &params.body) {
+        (Some(template), Some(b)) => Some(format!("{}\n\n{b}", template.trim_end())),
+        (Some(template), None) => Some(template),
+        (None, b) => b.clone(),
+    };
+    if let Some(b) = text {
+        body["body"] = serde_json::Value::String(b);
     }
     if let Some(labels) = &params.labels {
         body["labels"] = serde_json::json!(labels);
@@ -162,16 +786,153 @@ pub async fn issue_create(
         .await?;
 
     Ok(CallToolResult::success(vec![Content::text(
-        response::format_issue(&issue),
+        response::format_issue(&issue, client.settings()),
     )]))
 }
 
+pub async fn issue_templates_list(
+    client: &dyn GitClient,
+    params: IssueTemplatesListParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let templates = fetch_templates(client, &owner, &repo).await?;
+
+    if templates.is_empty() {
+        return Ok(CallToolResult::success(vec![Content::text(
+            "No issue templates found.",
+        )]));
+    }
+
+    let formatted: Vec<String> = templates
+        .iter()
+        .map(|(file, text)| {
+            let template = parse_template(text);
+            let mut line = format!("- `{file}`");
+            if let Some(name) = &template.name {
+                line.push_str(&format!(": {name}"));
+            }
+            if let Some(title) = &template.title {
+                line.push_str(&format!(" (title: \"{title}\")"));
+            }
+            if !file.ends_with(".md") {
+                line.push_str(" [issue form, cannot be used as a template body]");
+            }
+            line
+        })
+        .collect();
+
+    Ok(CallToolResult::success(vec![Content::text(
+        formatted.join("\n"),
+    )]))
+}
+
+/// Template files (name, decoded text) from the first template directory that exists.
+async fn fetch_templates(client: &dyn GitClient, owner: &str, repo: &str) -> Result<Vec<(String, String)>> {
+    for dir in ISSUE_TEMPLATE_DIRS {
+        let listing = match client.get_json(&format!("/repos/{owner}/{repo}/contents/{dir}")).await {
+            Ok(listing) => listing,
+            Err(crate::error::GitxError::NotFound(_)) => continue,
+            Err(e) => return Err(e),
+        };
+        let files: Vec<&serde_json::Value> = listing
+            .as_array()
+            .map(|entries| {
+                entries
+                    .iter()
+                    .filter(|e| e.get("type").and_then(|v| v.as_str()) == Some("file"))
+                    .filter(|e| {
+                        let name = e.get("name").and_then(|v| v.as_str()).unwrap_or("");
+                        // config.yml configures the chooser; it isn't a template.
+                        !name.starts_with("config.") && [".md", ".yml", ".yaml"].iter().any(|ext| name.ends_with(ext))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let paths: Vec<String> = files
+            .iter()
+            .filter_map(|f| f.get("path").and_then(|v| v.as_str()))
+            .map(|path| format!("/repos/{owner}/{repo}/contents/{path}"))
+            .collect();
+        let contents = crate::tools::batch::get_many(client, &paths).await;
+
+        let mut templates = Vec::new();
+        for (file, content) in files.iter().zip(contents) {
+            let name = file.get("name").and_then(|v| v.as_str()).unwrap_or("").to_string();
+            templates.push((name, decode_content(&content?)));
+        }
+        return Ok(templates);
+    }
+    Ok(Vec::new())
+}
+
+/// Body of the markdown template named `name` (with or without `.md`), front matter removed.
+async fn load_template_body(client: &dyn GitClient, owner: &str, repo: &str, name: &str) -> Result<String> {
+    let templates = fetch_templates(client, owner, repo).await?;
+    let wanted = name.trim_end_matches(".md");
+    let (file, text) = templates
+        .iter()
+        .find(|(file, _)| file.ends_with(".md") && file.trim_end_matches(".md").eq_ignore_ascii_case(wanted))
+        .ok_or_else(|| {
+            crate::error::GitxError::MissingParam(format!(
+                "template \"{name}\" is not a markdown issue template in {owner}/{repo}. Use issue_templates_list to see the available ones."
+            ))
+        })?;
+    tracing::debug!("Using issue template {file}");
+    Ok(parse_template(text).body)
+}
+
+/// Decode the base64 `content` of a contents-API file.
+fn decode_content(file: &serde_json::Value) -> String {
+    use base64::Engine;
+    let content = file.get("content").and_then(|v| v.as_str()).unwrap_or("").replace('\n', "");
+    base64::engine::general_purpose::STANDARD
+        .decode(content)
+        .ok()
+        .and_then(|bytes| String::from_utf8(bytes).ok())
+        .unwrap_or_default()
+}
+
+/// The parts of an issue template that matter here.
+#[derive(Debug, Default, PartialEq)]
+struct IssueTemplate {
+    name: Option<String>,
+    title: Option<String>,
+    body: String,
+}
+
+/// Split a template into its YAML front matter fields (`name`, `title`) and
+/// markdown body. Issue forms (`.yml`) are all "front matter": their top-level
+/// `name`/`title` are read the same way.
+fn parse_template(text: &str) -> IssueTemplate {
+    let (header, body) = match text.strip_prefix("---") {
+        Some(rest) => match rest.split_once("\n---") {
+            Some((header, body)) => (header, body.trim_start_matches(['\r', '\n'])),
+            None => (rest, ""),
+        },
+        None => (text, text),
+    };
+    let field = |key: &str| {
+        header.lines().find_map(|line| {
+            let value = line.strip_prefix(key)?.strip_prefix(':')?.trim();
+            let value = value.trim_matches(|c| c == '"' || c == '\'');
+            (!value.is_empty()).then(|| value.to_string())
+        })
+    };
+    IssueTemplate {
+        name: field("name"),
+        title: field("title"),
+        body: body.to_string(),
+    }
+}
+
 pub async fn issue_edit(
     client: &dyn GitClient,
     params: IssueEditParams,
     default_repo: Option<&RepoInfo>,
 ) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
     let mut body = serde_json::json!({});
 
     if let Some(title) = &params.title {
@@ -201,6 +962,265 @@ pub async fn issue_edit(
         .await?;
 
     Ok(CallToolResult::success(vec![Content::text(
-        response::format_issue(&issue),
+        response::format_issue(&issue, client.settings()),
     )]))
 }
+
+pub async fn issue_close(client: &dyn GitClient, params: IssueStateParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
+    issue_edit(client, state_edit(params, "closed"), default_repo).await
+}
+
+pub async fn issue_reopen(client: &dyn GitClient, params: IssueStateParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
+    issue_edit(client, state_edit(params, "open"), default_repo).await
+}
+
+/// An `issue_edit` that only changes the state.
+fn state_edit(params: IssueStateParams, state: &str) -> IssueEditParams {
+    IssueEditParams {
+        owner: params.owner,
+        repo: params.repo,
+        directory: params.directory,
+        remote: params.remote,
+        index: params.index,
+        title: None,
+        body: None,
+        state: Some(state.to_string()),
+        labels: None,
+        milestone: None,
+        assignees: None,
+    }
+}
+
+pub async fn issue_add_assignees(
+    client: &dyn GitClient,
+    params: IssueAssigneesParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    change_assignees(client, params, default_repo, true).await
+}
+
+pub async fn issue_remove_assignees(
+    client: &dyn GitClient,
+    params: IssueAssigneesParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    change_assignees(client, params, default_repo, false).await
+}
+
+/// Add (`add = true`) or remove assignees without touching the others.
+///
+/// GitHub has dedicated endpoints for this. Gitea has none, so the current
+/// list is read and written back with the change applied.
+async fn change_assignees(
+    client: &dyn GitClient,
+    params: IssueAssigneesParams,
+    default_repo: Option<&RepoInfo>,
+    add: bool,
+) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    if params.assignees.is_empty() {
+        return Err(crate::error::GitxError::MissingParam("assignees (at least one username)".to_string()));
+    }
+    let issue_path = format!("/repos/{owner}/{repo}/issues/{}", params.index);
+
+    let issue = match client.platform() {
+        Platform::Gitea => {
+            let current = client.get_json(&issue_path).await?;
+            let updated = updated_assignees(&assignee_logins(&current), &params.assignees, add);
+            client
+                .patch_json(&issue_path, &serde_json::json!({ "assignees": updated }))
+                .await?
+        }
+        Platform::GitLab => return Err(crate::client::gitlab_unsupported(if add { "issue_add_assignees" } else { "issue_remove_assignees" })),
+        Platform::GitHub => {
+            let body = serde_json::json!({ "assignees": params.assignees });
+            let path = format!("{issue_path}/assignees");
+            if add {
+                client.post_json(&path, &body).await?
+            } else {
+                client.delete_with_body(&path, &body).await?;
+                client.get_json(&issue_path).await?
+            }
+        }
+    };
+
+    let assignees = assignee_logins(&issue);
+    let text = if assignees.is_empty() {
+        format!("Issue #{} now has no assignees.", params.index)
+    } else {
+        format!("**Assignees of #{}:** {}", params.index, assignees.join(", "))
+    };
+    Ok(CallToolResult::success(vec![Content::text(text)]))
+}
+
+fn assignee_logins(issue: &serde_json::Value) -> Vec<String> {
+    issue
+        .get("assignees")
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|a| a.get("login").and_then(|v| v.as_str()).map(String::from))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Apply an add/remove to the current assignee list, keeping its order and
+/// comparing usernames case-insensitively.
+fn updated_assignees(current: &[String], change: &[String], add: bool) -> Vec<String> {
+    let in_change = |name: &str| change.iter().any(|c| c.eq_ignore_ascii_case(name));
+    if add {
+        let mut result = current.to_vec();
+        for name in change {
+            if !result.iter().any(|r| r.eq_ignore_ascii_case(name)) {
+                result.push(name.clone());
+            }
+        }
+        result
+    } else {
+        current.iter().filter(|name| !in_change(name)).cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn batch_size_is_bounded() {
+        assert!(check_batch_size(&[1, 2, 3]).is_ok());
+        assert!(matches!(check_batch_size(&[]), Err(crate::error::GitxError::MissingParam(_))));
+        let too_many: Vec<i64> = (1..=MAX_ISSUES_PER_BATCH as i64 + 1).collect();
+        assert!(matches!(check_batch_size(&too_many), Err(crate::error::GitxError::InvalidParam(_))));
+    }
+
+    #[test]
+    fn tracked_times_format_per_entry_and_total() {
+        assert_eq!(format_tracked_time(20), "20s");
+        assert_eq!(format_tracked_time(2700), "45m");
+        assert_eq!(format_tracked_time(7200), "2h");
+        assert_eq!(format_tracked_time(5430), "1h 30m");
+
+        let entries = [
+            serde_json::json!({ "id": 1, "user_name": "alice", "time": 3600, "created": "2024-05-01T10:00:00Z" }),
+            serde_json::json!({ "id": 2, "user_name": "bob", "time": 1800, "created": "2024-05-02T09:30:00Z" }),
+        ];
+        assert_eq!(
+            format_times_list(4, &entries, ToolSettings::default()),
+            "- alice: 1h on 2024-05-01T10:00:00Z [id: 1]\n- bob: 30m on 2024-05-02T09:30:00Z [id: 2]\n\n**Total:** 1h 30m"
+        );
+        assert_eq!(format_times_list(4, &[], ToolSettings::default()), "No time tracked on #4.");
+    }
+
+    #[test]
+    fn closing_references_parse_keywords() {
+        let body = "Fixes #12, closes: octo/lib#7.\nSee #3 and resolved #12 again. Fix the bug #4 / nope#x";
+        assert_eq!(
+            closing_references(body),
+            vec![(None, 12), (Some("octo/lib".to_string()), 7)]
+        );
+        assert!(closing_references("Closes https://example.com/a/b/c#5").is_empty());
+    }
+
+    #[test]
+    fn sort_issues_orders_gitea_pages() {
+        let mut issues = vec![
+            serde_json::json!({ "number": 1, "created_at": "2024-01-01T00:00:00Z", "updated_at": "2024-03-01T00:00:00Z", "comments": 5 }),
+            serde_json::json!({ "number": 2, "created_at": "2024-02-01T00:00:00Z", "updated_at": "2024-02-15T00:00:00Z", "comments": 0 }),
+            serde_json::json!({ "number": 3, "created_at": "2024-03-01T00:00:00Z", "updated_at": "2024-03-02T00:00:00Z", "comments": 2 }),
+        ];
+        let numbers = |issues: &[serde_json::Value]| issues.iter().map(|i| i["number"].as_i64().unwrap()).collect::<Vec<_>>();
+
+        sort_issues(&mut issues, "updated", None);
+        assert_eq!(numbers(&issues), vec![3, 1, 2]);
+        sort_issues(&mut issues, "created", Some("asc"));
+        assert_eq!(numbers(&issues), vec![1, 2, 3]);
+        sort_issues(&mut issues, "comments", None);
+        assert_eq!(numbers(&issues), vec![1, 3, 2]);
+    }
+
+    fn search_params() -> IssueSearchParams {
+        IssueSearchParams {
+            owner: None,
+            repo: None,
+            directory: None,
+            remote: None,
+            keyword: None,
+            author: None,
+            assignee: None,
+            state: None,
+            labels: None,
+            kind: None,
+            page: None,
+            limit: None,
+        }
+    }
+
+    #[test]
+    fn template_front_matter_is_split_off() {
+        let text = "---\nname: Bug report\nabout: Something broke\ntitle: \"[Bug]: \"\nlabels: bug\n---\n\n## Steps to reproduce\n";
+        assert_eq!(
+            parse_template(text),
+            IssueTemplate {
+                name: Some("Bug report".to_string()),
+                title: Some("[Bug]: ".to_string()),
+                body: "## Steps to reproduce\n".to_string(),
+            }
+        );
+        assert_eq!(parse_template("Just a body").body, "Just a body");
+    }
+
+    #[test]
+    fn timeline_events_per_platform() {
+        let github = serde_json::json!({
+            "event": "labeled",
+            "actor": { "login": "alice" },
+            "created_at": "2024-05-01T10:00:00Z",
+            "label": { "name": "bug" },
+        });
+        assert_eq!(format_timeline_event(Platform::GitHub, &github, ToolSettings::default()), "- 2024-05-01T10:00:00Z alice: labeled bug");
+
+        let gitea = serde_json::json!({
+            "type": "assignees",
+            "removed_assignee": true,
+            "user": { "login": "bob" },
+            "assignee": { "login": "carol" },
+            "created_at": "2024-05-02T08:00:00Z",
+        });
+        assert_eq!(format_timeline_event(Platform::Gitea, &gitea, ToolSettings::default()), "- 2024-05-02T08:00:00Z bob: unassigned carol");
+    }
+
+    #[test]
+    fn assignee_changes_keep_others() {
+        let current = vec!["alice".to_string(), "bob".to_string()];
+        assert_eq!(
+            updated_assignees(&current, &["Bob".to_string(), "carol".to_string()], true),
+            vec!["alice", "bob", "carol"]
+        );
+        assert_eq!(updated_assignees(&current, &["ALICE".to_string()], false), vec!["bob"]);
+    }
+
+    #[test]
+    fn github_query_defaults_to_open() {
+        let q = github_search_query("o", "r", &search_params());
+        assert_eq!(q, "repo:o/r state:open");
+    }
+
+    #[test]
+    fn github_query_includes_all_filters() {
+        let params = IssueSearchParams {
+            keyword: Some("crash on start".to_string()),
+            author: Some("alice".to_string()),
+            assignee: Some("bob".to_string()),
+            state: Some("all".to_string()),
+            labels: Some("bug, good first issue".to_string()),
+            kind: Some("pulls".to_string()),
+            ..search_params()
+        };
+        let q = github_search_query("o", "r", &params);
+        assert_eq!(
+            q,
+            "repo:o/r crash on start author:alice assignee:bob label:\"bug\" label:\"good first issue\" is:pr"
+        );
+    }
+}
@@ -3,9 +3,11 @@ use schemars::JsonSchema;
 use serde::Deserialize;
 
 use crate::client::GitClient;
-use crate::error::Result;
+use crate::error::{GitxError, Result};
+use crate::platform::Platform;
 use crate::repo_resolver::RepoInfo;
 use crate::server::resolve_owner_repo;
+use crate::types::common::{query_refs, QueryBuilder};
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct LabelListParams {
@@ -15,6 +17,14 @@ pub struct LabelListParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct OrgLabelsParams {
+    /// Organization name.
+    pub org: String,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -25,6 +35,18 @@ pub struct LabelCreateParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Label name.
+    pub name: String,
+    /// Label color as hex (e.g. "#ff0000" or "ff0000").
+    pub color: String,
+    /// Label description.
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct LabelSpec {
     /// Label name.
     pub name: String,
     /// Label color as hex (e.g. "#ff0000" or "ff0000").
@@ -33,6 +55,40 @@ pub struct LabelCreateParams {
     pub description: Option<String>,
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct LabelCreateBatchParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Labels to create. Combined with `preset`; a label here replaces a preset label of the same name.
+    #[serde(default)]
+    pub labels: Vec<LabelSpec>,
+    /// Built-in label set to create: "default" (bug, documentation, duplicate,
+    /// enhancement, good first issue, help wanted, invalid, question, wontfix).
+    pub preset: Option<String>,
+}
+
+/// Upper bound on `label_create_batch` so one call can't fan out unboundedly.
+const MAX_LABELS_PER_BATCH: usize = 50;
+
+/// The "default" preset: GitHub's stock labels as (name, color, description).
+const DEFAULT_LABELS: &[(&str, &str, &str)] = &[
+    ("bug", "d73a4a", "Something isn't working"),
+    ("documentation", "0075ca", "Improvements or additions to documentation"),
+    ("duplicate", "cfd3d7", "This issue or pull request already exists"),
+    ("enhancement", "a2eeef", "New feature or request"),
+    ("good first issue", "7057ff", "Good for newcomers"),
+    ("help wanted", "008672", "Extra attention is needed"),
+    ("invalid", "e4e669", "This doesn't seem right"),
+    ("question", "d876e3", "Further information is requested"),
+    ("wontfix", "ffffff", "This will not be worked on"),
+];
+
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct LabelEditParams {
     /// Repository owner. Optional if `directory` is provided.
@@ -41,6 +97,8 @@ pub struct LabelEditParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
     /// Label ID (from label_list).
     pub id: i64,
     /// New label name.
@@ -51,20 +109,78 @@ pub struct LabelEditParams {
     pub description: Option<String>,
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct IssueAddLabelsParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Issue or pull request number.
+    pub index: i64,
+    /// Labels to add, by name. On Gitea, numeric label IDs (from label_list) are accepted too.
+    pub labels: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct IssueRemoveLabelParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Issue or pull request number.
+    pub index: i64,
+    /// Label to remove, by name. On Gitea, a numeric label ID (from label_list) is accepted too.
+    pub label: String,
+}
+
+/// Most pages of labels read when looking a label up by name.
+const MAX_LABEL_PAGES: i64 = 20;
+
 pub async fn label_list(client: &dyn GitClient, params: LabelListParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
     let val = client
         .get_json(&format!("/repos/{owner}/{repo}/labels"))
         .await?;
     let labels = val.as_array().cloned().unwrap_or_default();
 
-    if labels.is_empty() {
+    Ok(CallToolResult::success(vec![Content::text(
+        format_label_list(&labels),
+    )]))
+}
+
+pub async fn org_labels(client: &dyn GitClient, params: OrgLabelsParams) -> Result<CallToolResult> {
+    if client.platform() != Platform::Gitea {
         return Ok(CallToolResult::success(vec![Content::text(
-            "No labels found.",
+            "Org-level labels are not available on GitHub. Labels are defined per repository — use label_list.",
         )]));
     }
 
-    let formatted: Vec<String> = labels
+    let val = client
+        .get_json(&format!("/orgs/{}/labels", params.org))
+        .await?;
+    let labels = val.as_array().cloned().unwrap_or_default();
+
+    Ok(CallToolResult::success(vec![Content::text(
+        format_label_list(&labels),
+    )]))
+}
+
+/// One line per label with name, color, ID, and description, as shown by
+/// `label_list` and `org_labels`.
+fn format_label_list(labels: &[serde_json::Value]) -> String {
+    if labels.is_empty() {
+        return "No labels found.".to_string();
+    }
+
+    labels
         .iter()
         .map(|l| {
             let name = l.get("name").and_then(|v| v.as_str()).unwrap_or("?");
@@ -80,11 +196,8 @@ pub async fn label_list(client: &dyn GitClient, params: LabelListParams, default
                 format!("- {name} (#{color}) [id: {id}] - {desc}")
             }
         })
-        .collect();
-
-    Ok(CallToolResult::success(vec![Content::text(
-        formatted.join("\n"),
-    )]))
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
 pub async fn label_create(
@@ -92,16 +205,10 @@ pub async fn label_create(
     params: LabelCreateParams,
     default_repo: Option<&RepoInfo>,
 ) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
-    let color = if params.color.starts_with('#') {
-        params.color.clone()
-    } else {
-        format!("#{}", params.color)
-    };
-
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
     let mut body = serde_json::json!({
         "name": params.name,
-        "color": color,
+        "color": hex_color(&params.color)?,
     });
 
     if let Some(desc) = &params.description {
@@ -122,24 +229,132 @@ pub async fn label_create(
     ))]))
 }
 
+pub async fn label_create_batch(
+    client: &dyn GitClient,
+    params: LabelCreateBatchParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let labels = batch_labels(params.preset.as_deref(), params.labels)?;
+    let colors = labels.iter().map(|l| hex_color(&l.color)).collect::<Result<Vec<_>>>()?;
+
+    let existing = all_labels(client, &format!("/repos/{owner}/{repo}/labels")).await?;
+
+    let mut created = Vec::new();
+    let mut skipped = Vec::new();
+    let mut failed = Vec::new();
+    for (label, color) in labels.into_iter().zip(colors) {
+        if find_label_id(&existing, &label.name).is_some() {
+            skipped.push(label.name);
+            continue;
+        }
+        let mut body = serde_json::json!({
+            "name": label.name,
+            "color": color,
+        });
+        if let Some(desc) = &label.description {
+            body["description"] = serde_json::Value::String(desc.clone());
+        }
+        match client.post_json(&format!("/repos/{owner}/{repo}/labels"), &body).await {
+            Ok(_) => created.push(label.name),
+            // Created concurrently since the lookup; other 409/422s are real failures.
+            Err(GitxError::Status { status: 409 | 422, body }) if body.contains("already_exists") => {
+                skipped.push(label.name)
+            }
+            Err(e @ GitxError::DryRun(_)) => return Err(e),
+            Err(e) => failed.push(format!("{}: {e}", label.name)),
+        }
+    }
+
+    Ok(CallToolResult::success(vec![Content::text(format_batch_summary(
+        &created, &skipped, &failed,
+    ))]))
+}
+
+/// Expand `preset` and merge in the explicit labels, which win on a (case-insensitive) name clash.
+fn batch_labels(preset: Option<&str>, labels: Vec<LabelSpec>) -> Result<Vec<LabelSpec>> {
+    let mut merged: Vec<LabelSpec> = match preset {
+        None => Vec::new(),
+        Some("default") => DEFAULT_LABELS
+            .iter()
+            .map(|(name, color, description)| LabelSpec {
+                name: name.to_string(),
+                color: color.to_string(),
+                description: Some(description.to_string()),
+            })
+            .collect(),
+        Some(other) => {
+            return Err(GitxError::InvalidParam(format!("preset must be \"default\" (got \"{other}\")")));
+        }
+    };
+    for label in labels {
+        merged.retain(|l| !l.name.eq_ignore_ascii_case(&label.name));
+        merged.push(label);
+    }
+
+    if merged.is_empty() || merged.len() > MAX_LABELS_PER_BATCH {
+        return Err(GitxError::MissingParam(format!(
+            "labels or preset (between 1 and {MAX_LABELS_PER_BATCH} labels)"
+        )));
+    }
+    Ok(merged)
+}
+
+fn format_batch_summary(created: &[String], skipped: &[String], failed: &[String]) -> String {
+    let mut lines = vec![format!("**Created {}:** {}", created.len(), list_or_none(created))];
+    if !skipped.is_empty() {
+        lines.push(format!("**Skipped {} (already exist):** {}", skipped.len(), skipped.join(", ")));
+    }
+    if !failed.is_empty() {
+        lines.push(format!("**Failed {}:**", failed.len()));
+        lines.extend(failed.iter().map(|f| format!("- {f}")));
+    }
+    lines.join("\n")
+}
+
+fn list_or_none(names: &[String]) -> String {
+    if names.is_empty() { "none".to_string() } else { names.join(", ") }
+}
+
+/// Label colors are sent with a leading `#`, whichever form the caller used.
+/// Anything but six hex digits is rejected before it reaches the server.
+fn hex_color(color: &str) -> Result<String> {
+    let digits = color.strip_prefix('#').unwrap_or(color);
+    if digits.len() != 6 || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(GitxError::InvalidParam(format!(
+            "color \"{color}\" must be a six-digit hex color such as \"#ff0000\""
+        )));
+    }
+    Ok(format!("#{digits}"))
+}
+
+/// Every label at `path`, reading page after page until one comes back empty.
+async fn all_labels(client: &dyn GitClient, path: &str) -> Result<serde_json::Value> {
+    let mut labels = Vec::new();
+    for page in 1..=MAX_LABEL_PAGES {
+        let query = QueryBuilder::for_client(client).page(Some(page)).limit(Some(MAX_LABELS_PER_BATCH as i64)).build();
+        let val = client.get_json_with_query(path, &query_refs(&query)).await?;
+        match val.as_array() {
+            Some(batch) if !batch.is_empty() => labels.extend(batch.iter().cloned()),
+            _ => break,
+        }
+    }
+    Ok(serde_json::Value::Array(labels))
+}
+
 pub async fn label_edit(
     client: &dyn GitClient,
     params: LabelEditParams,
     default_repo: Option<&RepoInfo>,
 ) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
     let mut body = serde_json::json!({});
 
     if let Some(name) = &params.name {
         body["name"] = serde_json::Value::String(name.clone());
     }
     if let Some(color) = &params.color {
-        let c = if color.starts_with('#') {
-            color.clone()
-        } else {
-            format!("#{color}")
-        };
-        body["color"] = serde_json::Value::String(c);
+        body["color"] = serde_json::Value::String(hex_color(color)?);
     }
     if let Some(desc) = &params.description {
         body["description"] = serde_json::Value::String(desc.clone());
@@ -158,3 +373,176 @@ pub async fn label_edit(
         "Label updated: {name}"
     ))]))
 }
+
+pub async fn issue_add_labels(
+    client: &dyn GitClient,
+    params: IssueAddLabelsParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    if params.labels.is_empty() {
+        return Err(GitxError::MissingParam("labels (at least one label)".to_string()));
+    }
+
+    // Gitea's endpoint takes label IDs, GitHub's takes names.
+    let labels = match client.platform() {
+        Platform::Gitea => {
+            let mut ids = Vec::with_capacity(params.labels.len());
+            for label in &params.labels {
+                ids.push(gitea_label_id(client, &owner, &repo, label).await?);
+            }
+            serde_json::json!(ids)
+        }
+        Platform::GitLab => return Err(crate::client::gitlab_unsupported("issue_add_labels")),
+        Platform::GitHub => serde_json::json!(params.labels),
+    };
+
+    let current = client
+        .post_json(
+            &format!("/repos/{owner}/{repo}/issues/{}/labels", params.index),
+            &serde_json::json!({ "labels": labels }),
+        )
+        .await?;
+
+    Ok(CallToolResult::success(vec![Content::text(format_issue_labels(params.index, &current))]))
+}
+
+pub async fn issue_remove_label(
+    client: &dyn GitClient,
+    params: IssueRemoveLabelParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+
+    let segment = match client.platform() {
+        Platform::Gitea => gitea_label_id(client, &owner, &repo, &params.label).await?.to_string(),
+        // Label names may contain spaces or slashes; encode them as a single path segment.
+        Platform::GitLab => return Err(crate::client::gitlab_unsupported("issue_remove_label")),
+        Platform::GitHub => url::form_urlencoded::byte_serialize(params.label.as_bytes())
+            .collect::<String>()
+            .replace('+', "%20"),
+    };
+    let labels_path = format!("/repos/{owner}/{repo}/issues/{}/labels", params.index);
+    client.delete(&format!("{labels_path}/{segment}")).await?;
+
+    let current = client.get_json(&labels_path).await?;
+    Ok(CallToolResult::success(vec![Content::text(format_issue_labels(params.index, &current))]))
+}
+
+/// Resolve a label given by name (or already by ID) to its Gitea label ID,
+/// looking through every page of the repo's labels and then its org's.
+async fn gitea_label_id(client: &dyn GitClient, owner: &str, repo: &str, label: &str) -> Result<i64> {
+    if let Ok(id) = label.parse::<i64>() {
+        return Ok(id);
+    }
+    let labels = all_labels(client, &format!("/repos/{owner}/{repo}/labels")).await?;
+    if let Some(id) = find_label_id(&labels, label) {
+        return Ok(id);
+    }
+    // Org labels apply to every repo of the org; a user-owned repo has none.
+    let org_labels = match all_labels(client, &format!("/orgs/{owner}/labels")).await {
+        Err(GitxError::NotFound(_)) => serde_json::Value::Null,
+        other => other?,
+    };
+    find_label_id(&org_labels, label).ok_or_else(|| {
+        GitxError::MissingParam(format!(
+            "label \"{label}\" does not exist in {owner}/{repo} or its organization. Use label_list or org_labels to see available labels."
+        ))
+    })
+}
+
+fn find_label_id(labels: &serde_json::Value, name: &str) -> Option<i64> {
+    labels.as_array()?.iter().find_map(|l| {
+        let matches = l
+            .get("name")
+            .and_then(|v| v.as_str())
+            .is_some_and(|n| n.eq_ignore_ascii_case(name));
+        if matches {
+            l.get("id").and_then(|v| v.as_i64())
+        } else {
+            None
+        }
+    })
+}
+
+fn format_issue_labels(index: i64, labels: &serde_json::Value) -> String {
+    let names: Vec<&str> = labels
+        .as_array()
+        .map(|arr| arr.iter().filter_map(|l| l.get("name").and_then(|v| v.as_str())).collect())
+        .unwrap_or_default();
+    if names.is_empty() {
+        format!("Issue #{index} now has no labels.")
+    } else {
+        format!("**Labels of #{index}:** {}", names.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn label_names_resolve_to_ids() {
+        let labels = serde_json::json!([
+            { "id": 1, "name": "bug" },
+            { "id": 4, "name": "Needs Triage" },
+        ]);
+        assert_eq!(find_label_id(&labels, "needs triage"), Some(4));
+        assert_eq!(find_label_id(&labels, "wontfix"), None);
+        assert_eq!(
+            format_issue_labels(3, &labels),
+            "**Labels of #3:** bug, Needs Triage"
+        );
+    }
+
+    #[test]
+    fn batch_labels_merge_preset_and_explicit() {
+        let explicit = vec![
+            LabelSpec { name: "Bug".to_string(), color: "ff0000".to_string(), description: None },
+            LabelSpec { name: "infra".to_string(), color: "#0052cc".to_string(), description: None },
+        ];
+        let labels = batch_labels(Some("default"), explicit).unwrap();
+        assert_eq!(labels.len(), DEFAULT_LABELS.len() + 1);
+        let bug = labels.iter().find(|l| l.name.eq_ignore_ascii_case("bug")).unwrap();
+        assert_eq!(bug.color, "ff0000");
+
+        assert!(matches!(batch_labels(Some("fancy"), Vec::new()), Err(GitxError::InvalidParam(_))));
+        assert!(batch_labels(None, Vec::new()).is_err());
+    }
+
+    #[test]
+    fn hex_colors_are_validated() {
+        assert_eq!(hex_color("ff0000").unwrap(), "#ff0000");
+        assert_eq!(hex_color("#0052CC").unwrap(), "#0052CC");
+        for bad in ["red", "#fff", "ff00000", "#gg0000", ""] {
+            assert!(matches!(hex_color(bad), Err(GitxError::InvalidParam(_))), "{bad}");
+        }
+    }
+
+    #[test]
+    fn batch_summary_lists_created_and_skipped() {
+        let summary = format_batch_summary(
+            &["infra".to_string()],
+            &["bug".to_string(), "question".to_string()],
+            &["wip: HTTP 500: oops".to_string()],
+        );
+        assert_eq!(
+            summary,
+            "**Created 1:** infra\n**Skipped 2 (already exist):** bug, question\n**Failed 1:**\n- wip: HTTP 500: oops"
+        );
+        assert_eq!(format_batch_summary(&[], &[], &[]), "**Created 0:** none");
+    }
+
+    #[test]
+    fn label_list_format_includes_ids() {
+        let labels = [
+            serde_json::json!({ "id": 2, "name": "bug", "color": "ee0701" }),
+            serde_json::json!({ "id": 9, "name": "infra", "color": "0052cc", "description": "Org-wide" }),
+        ];
+        assert_eq!(
+            format_label_list(&labels),
+            "- bug (#ee0701) [id: 2]\n- infra (#0052cc) [id: 9] - Org-wide"
+        );
+        assert_eq!(format_label_list(&[]), "No labels found.");
+    }
+}
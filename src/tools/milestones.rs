@@ -7,6 +7,7 @@ use crate::error::Result;
 use crate::response;
 use crate::repo_resolver::RepoInfo;
 use crate::server::resolve_owner_repo;
+use crate::types::common::{query_refs, QueryBuilder};
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct MilestoneListParams {
@@ -16,6 +17,8 @@ pub struct MilestoneListParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
     /// Filter by state: open, closed, or all. Defaults to open.
     pub state: Option<String>,
 }
@@ -28,6 +31,8 @@ pub struct MilestoneGetParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
     /// Milestone ID (from milestone_list).
     pub id: i64,
 }
@@ -40,6 +45,8 @@ pub struct MilestoneCreateParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
     /// Milestone title.
     pub title: String,
     /// Milestone description.
@@ -53,12 +60,13 @@ pub async fn milestone_list(
     params: MilestoneListParams,
     default_repo: Option<&RepoInfo>,
 ) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
-    let state = params.state.unwrap_or_else(|| "open".to_string());
-    let query: Vec<(&str, &str)> = vec![("state", state.as_str())];
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let query = QueryBuilder::for_client(client)
+        .param("state", params.state.as_deref().unwrap_or("open"))
+        .build();
 
     let val = client
-        .get_json_with_query(&format!("/repos/{owner}/{repo}/milestones"), &query)
+        .get_json_with_query(&format!("/repos/{owner}/{repo}/milestones"), &query_refs(&query))
         .await?;
     let milestones = val.as_array().cloned().unwrap_or_default();
 
@@ -96,7 +104,7 @@ pub async fn milestone_get(
     params: MilestoneGetParams,
     default_repo: Option<&RepoInfo>,
 ) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
     let milestone = client
         .get_json(&format!(
             "/repos/{owner}/{repo}/milestones/{}",
@@ -105,7 +113,7 @@ pub async fn milestone_get(
         .await?;
 
     Ok(CallToolResult::success(vec![Content::text(
-        response::format_value(&milestone),
+        response::format_value(&milestone, client.settings()),
     )]))
 }
 
@@ -114,7 +122,7 @@ pub async fn milestone_create(
     params: MilestoneCreateParams,
     default_repo: Option<&RepoInfo>,
 ) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
     let mut body = serde_json::json!({ "title": params.title });
 
     if let Some(desc) = &params.description {
@@ -1,9 +1,12 @@
 pub mod issues;
 pub mod issue_comments;
+pub mod reactions;
 pub mod pulls;
 pub mod pull_reviews;
 pub mod pull_files;
 pub mod files;
+pub mod files_batch;
+pub mod batch;
 pub mod branches;
 pub mod commits;
 pub mod labels;
@@ -16,3 +19,4 @@ pub mod tags;
 pub mod wiki;
 pub mod orgs;
 pub mod actions;
+pub mod graphql;
@@ -3,10 +3,22 @@ use schemars::JsonSchema;
 use serde::Deserialize;
 
 use crate::client::GitClient;
-use crate::error::Result;
+use crate::error::{GitxError, Result};
+use crate::platform::Platform;
+use crate::response;
+use crate::server::resolve_owner_repo;
+use crate::types::common::{query_refs, QueryBuilder};
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct NotificationListParams {
+    /// Repository owner. When a repository is given, only its notifications are listed. Requires `repo`.
+    pub owner: Option<String>,
+    /// Repository name. Requires `owner`. Omit owner, repo, and directory to list notifications from all repositories.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config, scoping to that repository.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
     /// Filter by status: unread, read, or all. Defaults to unread.
     pub status: Option<String>,
     /// Page number (1-based). Defaults to 1.
@@ -21,41 +33,46 @@ pub struct NotificationMarkReadParams {
     pub id: Option<i64>,
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct NotificationThreadGetParams {
+    /// Notification thread ID (from notification_list).
+    pub id: i64,
+}
+
 pub async fn notification_list(
     client: &dyn GitClient,
     params: NotificationListParams,
 ) -> Result<CallToolResult> {
-    use crate::platform::Platform;
+    let path = if is_scoped(&params)? {
+        let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, None, client.settings().strict_repo)?;
+        format!("/repos/{owner}/{repo}/notifications")
+    } else {
+        "/notifications".to_string()
+    };
 
-    let mut query: Vec<(&str, String)> = Vec::new();
+    let mut query = QueryBuilder::for_client(client);
 
     if let Some(status) = &params.status {
         match client.platform() {
             Platform::Gitea => {
                 // Gitea API uses status-types parameter
-                query.push(("status-types", status.clone()));
+                query = query.param("status-types", status);
             }
+            Platform::GitLab => return Err(crate::client::gitlab_unsupported("notification_list")),
             Platform::GitHub => {
                 // GitHub uses all=true to show all, or participating=true
                 match status.as_str() {
-                    "all" => query.push(("all", "true".to_string())),
-                    "read" => query.push(("all", "true".to_string())),
-                    "participating" => query.push(("participating", "true".to_string())),
+                    "all" | "read" => query = query.param("all", "true"),
+                    "participating" => query = query.param("participating", "true"),
                     _ => {} // "unread" is the default on GitHub
                 }
             }
         }
     }
-    query.push(("page", params.page.unwrap_or(1).to_string()));
-    if client.platform() == Platform::Gitea {
-        query.push(("limit", params.limit.unwrap_or(20).min(50).to_string()));
-    } else {
-        query.push(("per_page", params.limit.unwrap_or(20).min(50).to_string()));
-    }
+    let query = query.page(params.page).limit(params.limit).build();
 
-    let query_refs: Vec<(&str, &str)> = query.iter().map(|(k, v)| (*k, v.as_str())).collect();
     let val = client
-        .get_json_with_query("/notifications", &query_refs)
+        .get_json_with_query(&path, &query_refs(&query))
         .await?;
     let notifications = val.as_array().cloned().unwrap_or_default();
 
@@ -68,7 +85,7 @@ pub async fn notification_list(
     let formatted: Vec<String> = notifications
         .iter()
         .map(|n| {
-            let id = n.get("id").and_then(|v| v.as_i64()).unwrap_or(0);
+            let id = thread_id(n);
             let subject_title = n
                 .get("subject")
                 .and_then(|v| v.get("title"))
@@ -110,11 +127,158 @@ pub async fn notification_mark_read(
             "Notification #{id} marked as read."
         ))]))
     } else {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        // GitHub answers 202 with an empty body; put_json maps that to Null.
         let _ = client
-            .put_json("/notifications", &serde_json::json!({}))
+            .put_json("/notifications", &mark_all_read_body(client.platform(), now))
             .await?;
         Ok(CallToolResult::success(vec![Content::text(
             "All notifications marked as read.",
         )]))
     }
 }
+
+pub async fn notification_thread_get(
+    client: &dyn GitClient,
+    params: NotificationThreadGetParams,
+) -> Result<CallToolResult> {
+    let thread = client
+        .get_json(&format!("/notifications/threads/{}", params.id))
+        .await?;
+
+    Ok(CallToolResult::success(vec![Content::text(format_thread(&thread))]))
+}
+
+/// Whether `notification_list` is scoped to one repository. Global by
+/// default: the server's detected repository is deliberately not applied, only
+/// an explicitly requested one, so a lone `owner` or `repo` is refused rather
+/// than completed from the working directory.
+fn is_scoped(params: &NotificationListParams) -> Result<bool> {
+    let given = |v: &Option<String>| v.as_deref().is_some_and(|s| !s.is_empty());
+    match (given(&params.owner), given(&params.repo)) {
+        (true, true) => Ok(true),
+        (false, false) => Ok(given(&params.directory) || given(&params.remote)),
+        _ => Err(GitxError::InvalidParam(
+            "owner and repo must be given together to scope notification_list".to_string(),
+        )),
+    }
+}
+
+/// Body for "mark all as read". GitHub wants `last_read_at` so that
+/// notifications arriving mid-request stay unread; Gitea takes no body.
+fn mark_all_read_body(platform: Platform, now_unix: u64) -> serde_json::Value {
+    match platform {
+        Platform::Gitea => serde_json::json!({}),
+        Platform::GitHub | Platform::GitLab => serde_json::json!({
+            "last_read_at": response::rfc3339_utc(now_unix),
+        }),
+    }
+}
+
+/// A notification's thread ID: a number on Gitea, a numeric string on GitHub.
+fn thread_id(thread: &serde_json::Value) -> i64 {
+    thread
+        .get("id")
+        .and_then(|v| v.as_i64().or_else(|| v.as_str().and_then(|s| s.parse().ok())))
+        .unwrap_or(0)
+}
+
+/// Format a notification thread. `reason` and `last_read_at` are GitHub-only.
+fn format_thread(thread: &serde_json::Value) -> String {
+    let str_field = |v: Option<&serde_json::Value>| v.and_then(|v| v.as_str()).map(String::from);
+    let subject = thread.get("subject");
+
+    let id = thread_id(thread);
+    let title = str_field(subject.and_then(|s| s.get("title"))).unwrap_or_else(|| "(no title)".to_string());
+    let mut parts = vec![format!("## Notification #{id}: {title}")];
+
+    if let Some(kind) = str_field(subject.and_then(|s| s.get("type"))) {
+        match str_field(subject.and_then(|s| s.get("state"))) {
+            Some(state) => parts.push(format!("**Type:** {kind} ({state})")),
+            None => parts.push(format!("**Type:** {kind}")),
+        }
+    }
+    if let Some(repo) = str_field(thread.get("repository").and_then(|r| r.get("full_name"))) {
+        parts.push(format!("**Repository:** {repo}"));
+    }
+    if let Some(reason) = str_field(thread.get("reason")) {
+        parts.push(format!("**Reason:** {reason}"));
+    }
+    let unread = thread.get("unread").and_then(|v| v.as_bool()).unwrap_or(false);
+    parts.push(format!("**Status:** {}", if unread { "unread" } else { "read" }));
+    if let Some(updated) = str_field(thread.get("updated_at")) {
+        parts.push(format!("**Updated:** {updated}"));
+    }
+    if let Some(last_read) = str_field(thread.get("last_read_at")) {
+        parts.push(format!("**Last read:** {last_read}"));
+    }
+    if let Some(url) = str_field(subject.and_then(|s| s.get("html_url")).or_else(|| subject.and_then(|s| s.get("url")))) {
+        parts.push(format!("**URL:** {url}"));
+    }
+
+    parts.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mark_all_read_body_per_platform() {
+        assert_eq!(mark_all_read_body(Platform::Gitea, 0), serde_json::json!({}));
+        assert_eq!(
+            mark_all_read_body(Platform::GitHub, 1_714_557_600),
+            serde_json::json!({ "last_read_at": "2024-05-01T10:00:00Z" })
+        );
+    }
+
+    fn list_params(owner: Option<&str>, repo: Option<&str>, directory: Option<&str>) -> NotificationListParams {
+        NotificationListParams {
+            owner: owner.map(String::from),
+            repo: repo.map(String::from),
+            directory: directory.map(String::from),
+            remote: None,
+            status: None,
+            page: None,
+            limit: None,
+        }
+    }
+
+    #[test]
+    fn scope_needs_both_owner_and_repo() {
+        assert!(!is_scoped(&list_params(None, None, None)).unwrap());
+        assert!(is_scoped(&list_params(Some("acme"), Some("web"), None)).unwrap());
+        assert!(is_scoped(&list_params(None, None, Some("/src/web"))).unwrap());
+        assert!(matches!(is_scoped(&list_params(Some("acme"), None, None)), Err(GitxError::InvalidParam(_))));
+        assert!(matches!(is_scoped(&list_params(None, Some("web"), Some("/src/web"))), Err(GitxError::InvalidParam(_))));
+    }
+
+    #[test]
+    fn thread_ids_parse_from_numbers_and_strings() {
+        assert_eq!(thread_id(&serde_json::json!({ "id": 7 })), 7);
+        assert_eq!(thread_id(&serde_json::json!({ "id": "1234567" })), 1_234_567);
+        assert_eq!(thread_id(&serde_json::json!({ "id": "abc" })), 0);
+    }
+
+    #[test]
+    fn thread_formatting_includes_github_fields() {
+        let thread = serde_json::json!({
+            "id": "42",
+            "unread": true,
+            "reason": "review_requested",
+            "updated_at": "2024-05-01T10:00:00Z",
+            "last_read_at": "2024-04-30T09:00:00Z",
+            "subject": { "title": "Fix login", "type": "PullRequest" },
+            "repository": { "full_name": "acme/web" },
+        });
+        let out = format_thread(&thread);
+        assert!(out.starts_with("## Notification #42: Fix login"));
+        assert!(out.contains("**Repository:** acme/web"));
+        assert!(out.contains("**Reason:** review_requested"));
+        assert!(out.contains("**Status:** unread"));
+        assert!(out.contains("**Last read:** 2024-04-30T09:00:00Z"));
+    }
+}
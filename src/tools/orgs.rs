@@ -4,6 +4,7 @@ use serde::Deserialize;
 
 use crate::client::GitClient;
 use crate::error::Result;
+use crate::platform::Platform;
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct OrgListParams {}
@@ -20,6 +21,20 @@ pub struct OrgTeamsParams {
     pub org: String,
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct TeamMembersParams {
+    /// Team ID (from org_teams).
+    pub id: i64,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct TeamMemberParams {
+    /// Team ID (from org_teams).
+    pub id: i64,
+    /// Username to add or remove.
+    pub username: String,
+}
+
 pub async fn org_list(client: &dyn GitClient) -> Result<CallToolResult> {
     let val = client.get_json("/user/orgs").await?;
     let orgs = val.as_array().cloned().unwrap_or_default();
@@ -116,3 +131,62 @@ pub async fn org_teams(client: &dyn GitClient, params: OrgTeamsParams) -> Result
         formatted.join("\n"),
     )]))
 }
+
+pub async fn team_members(client: &dyn GitClient, params: TeamMembersParams) -> Result<CallToolResult> {
+    let val = client
+        .get_json(&format!("/teams/{}/members", params.id))
+        .await?;
+    let members = val.as_array().cloned().unwrap_or_default();
+
+    if members.is_empty() {
+        return Ok(CallToolResult::success(vec![Content::text(
+            "No team members found.",
+        )]));
+    }
+
+    let formatted: Vec<String> = members
+        .iter()
+        .map(|m| {
+            let login = m.get("login").and_then(|v| v.as_str()).unwrap_or("?");
+            format!("- {login}")
+        })
+        .collect();
+
+    Ok(CallToolResult::success(vec![Content::text(
+        formatted.join("\n"),
+    )]))
+}
+
+/// Team membership path: Gitea uses `/members/{user}`, GitHub `/memberships/{user}`.
+fn team_member_path(platform: Platform, id: i64, username: &str) -> Result<String> {
+    match platform {
+        Platform::Gitea => Ok(format!("/teams/{id}/members/{username}")),
+        Platform::GitHub => Ok(format!("/teams/{id}/memberships/{username}")),
+        Platform::GitLab => Err(crate::client::gitlab_unsupported("Team membership")),
+    }
+}
+
+pub async fn team_add_member(client: &dyn GitClient, params: TeamMemberParams) -> Result<CallToolResult> {
+    client
+        .put_json(
+            &team_member_path(client.platform(), params.id, &params.username)?,
+            &serde_json::json!({}),
+        )
+        .await?;
+
+    Ok(CallToolResult::success(vec![Content::text(format!(
+        "Added {} to team {}",
+        params.username, params.id
+    ))]))
+}
+
+pub async fn team_remove_member(client: &dyn GitClient, params: TeamMemberParams) -> Result<CallToolResult> {
+    client
+        .delete(&team_member_path(client.platform(), params.id, &params.username)?)
+        .await?;
+
+    Ok(CallToolResult::success(vec![Content::text(format!(
+        "Removed {} from team {}",
+        params.username, params.id
+    ))]))
+}
@@ -1,11 +1,15 @@
 use rmcp::model::{CallToolResult, Content};
 use schemars::JsonSchema;
 use serde::Deserialize;
+use serde_json::Value;
 
-use crate::client::GitClient;
+use crate::client::{GitClient, RawMedia};
 use crate::error::Result;
+use crate::platform::Platform;
+use crate::response;
 use crate::repo_resolver::RepoInfo;
 use crate::server::resolve_owner_repo;
+use crate::types::common::{query_refs, request_timeout, QueryBuilder};
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct PrFilesParams {
@@ -15,6 +19,24 @@ pub struct PrFilesParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Pull request number.
+    pub index: i64,
+    /// Include each file's patch (its diff hunks) beneath its stats line. Defaults to false.
+    pub include_patch: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct PrDiffstatParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
     /// Pull request number.
     pub index: i64,
 }
@@ -27,12 +49,35 @@ pub struct PrDiffParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
     /// Pull request number.
     pub index: i64,
+    /// Timeout in seconds for this call's API requests, for very large results
+    /// (1-600). Defaults to the server's request timeout (`GITX_REQUEST_TIMEOUT`, 30s).
+    pub timeout_secs: Option<u64>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct PrCommitsParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Pull request number.
+    pub index: i64,
+    /// Page number (1-based). Defaults to 1.
+    pub page: Option<i64>,
+    /// Items per page (max 50). Defaults to 20.
+    pub limit: Option<i64>,
 }
 
 pub async fn pr_files(client: &dyn GitClient, params: PrFilesParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
     let val = client
         .get_json(&format!(
             "/repos/{owner}/{repo}/pulls/{}/files",
@@ -47,35 +92,156 @@ pub async fn pr_files(client: &dyn GitClient, params: PrFilesParams, default_rep
         )]));
     }
 
+    let include_patch = params.include_patch.unwrap_or(false);
     let formatted: Vec<String> = files
+        .iter()
+        .map(|f| format_pr_file(f, include_patch))
+        .collect();
+
+    let sep = if include_patch { "\n\n" } else { "\n" };
+    Ok(CallToolResult::success(vec![Content::text(
+        formatted.join(sep),
+    )]))
+}
+
+/// Cap on a single file's patch when `include_patch` is set, so one huge file
+/// cannot crowd out the rest of the listing.
+const MAX_PATCH_BYTES: usize = 20_000;
+
+/// Format one changed-file entry, optionally followed by its patch in a diff block.
+fn format_pr_file(f: &Value, include_patch: bool) -> String {
+    let filename = f
+        .get("filename")
+        .and_then(|v| v.as_str())
+        .unwrap_or("unknown");
+    let status = f
+        .get("status")
+        .and_then(|v| v.as_str())
+        .unwrap_or("modified");
+    let additions = f.get("additions").and_then(|v| v.as_i64()).unwrap_or(0);
+    let deletions = f.get("deletions").and_then(|v| v.as_i64()).unwrap_or(0);
+    let line = format!("- {filename} ({status}) +{additions} -{deletions}");
+    if !include_patch {
+        return line;
+    }
+    match f.get("patch").and_then(|v| v.as_str()) {
+        Some(patch) if !patch.is_empty() => {
+            let patch = response::truncate_content(patch.to_string(), MAX_PATCH_BYTES);
+            format!("{line}\n\n```diff\n{patch}\n```")
+        }
+        // Binary files and very large diffs come back without a patch.
+        _ => format!("{line}\n\n(no patch available)"),
+    }
+}
+
+/// Files requested per page when collecting a diffstat (both platforms honor 50).
+const DIFFSTAT_PAGE_SIZE: usize = 50;
+/// Stop collecting after this many pages (2500 files).
+const DIFFSTAT_MAX_PAGES: usize = 50;
+
+pub async fn pr_diffstat(client: &dyn GitClient, params: PrDiffstatParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let limit_key = if client.platform() == Platform::Gitea { "limit" } else { "per_page" };
+    let path = format!("/repos/{owner}/{repo}/pulls/{}/files", params.index);
+    let limit = DIFFSTAT_PAGE_SIZE.to_string();
+
+    // The totals need every file, not just the first page.
+    let mut files = Vec::new();
+    let mut complete = false;
+    for page in 1..=DIFFSTAT_MAX_PAGES {
+        let page = page.to_string();
+        let val = client
+            .get_json_with_query(&path, &[("page", &page), (limit_key, &limit)])
+            .await?;
+        let batch = val.as_array().cloned().unwrap_or_default();
+        let last = batch.len() < DIFFSTAT_PAGE_SIZE;
+        files.extend(batch);
+        if last {
+            complete = true;
+            break;
+        }
+    }
+
+    if files.is_empty() {
+        return Ok(CallToolResult::success(vec![Content::text(
+            "No changed files.",
+        )]));
+    }
+
+    let mut text = format_diffstat(&files);
+    if !complete {
+        text.push_str(&format!(
+            "\n\n**Note:** stopped after {} files; the PR changes more.",
+            files.len()
+        ));
+    }
+    Ok(CallToolResult::success(vec![Content::text(text)]))
+}
+
+/// Widest +/- bar in a diffstat line, as in `git diff --stat`.
+const DIFFSTAT_BAR_WIDTH: i64 = 40;
+
+/// Render `git diff --stat`-style output: one line per file with its change
+/// count and a +/- bar, then the totals.
+fn format_diffstat(files: &[Value]) -> String {
+    let rows: Vec<(&str, i64, i64)> = files
         .iter()
         .map(|f| {
-            let filename = f
-                .get("filename")
-                .and_then(|v| v.as_str())
-                .unwrap_or("unknown");
-            let status = f
-                .get("status")
-                .and_then(|v| v.as_str())
-                .unwrap_or("modified");
+            let filename = f.get("filename").and_then(|v| v.as_str()).unwrap_or("unknown");
             let additions = f.get("additions").and_then(|v| v.as_i64()).unwrap_or(0);
             let deletions = f.get("deletions").and_then(|v| v.as_i64()).unwrap_or(0);
-            format!("- {filename} ({status}) +{additions} -{deletions}")
+            (filename, additions, deletions)
         })
         .collect();
 
-    Ok(CallToolResult::success(vec![Content::text(
-        formatted.join("\n"),
-    )]))
+    let name_width = rows.iter().map(|(name, _, _)| name.chars().count()).max().unwrap_or(0);
+    let max_changes = rows.iter().map(|(_, a, d)| a + d).max().unwrap_or(0);
+    let count_width = max_changes.to_string().len();
+    // Scale bars down only when the largest change would not fit.
+    let scale = |n: i64| {
+        if max_changes <= DIFFSTAT_BAR_WIDTH || n == 0 {
+            n
+        } else {
+            (n * DIFFSTAT_BAR_WIDTH / max_changes).max(1)
+        }
+    };
+
+    let mut lines: Vec<String> = rows
+        .iter()
+        .map(|(name, additions, deletions)| {
+            let bar = format!(
+                "{}{}",
+                "+".repeat(scale(*additions) as usize),
+                "-".repeat(scale(*deletions) as usize)
+            );
+            format!(
+                "{name:<name_width$} | {:>count_width$} {bar}",
+                additions + deletions
+            )
+            .trim_end()
+            .to_string()
+        })
+        .collect();
+
+    let total_additions: i64 = rows.iter().map(|(_, a, _)| a).sum();
+    let total_deletions: i64 = rows.iter().map(|(_, _, d)| d).sum();
+    let plural = |n: i64| if n == 1 { "" } else { "s" };
+    let file_count = rows.len() as i64;
+    lines.push(format!(
+        "{file_count} file{} changed, {total_additions} insertion{}(+), {total_deletions} deletion{}(-)",
+        plural(file_count),
+        plural(total_additions),
+        plural(total_deletions)
+    ));
+
+    format!("```\n{}\n```", lines.join("\n"))
 }
 
 pub async fn pr_diff(client: &dyn GitClient, params: PrDiffParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let timeout = request_timeout(params.timeout_secs)?;
     let diff = client
-        .get_raw(&format!(
-            "/repos/{owner}/{repo}/pulls/{}.diff",
-            params.index
-        ))
+        .get_raw_with_timeout(&format!("/repos/{owner}/{repo}/pulls/{}.diff", params.index), &[], RawMedia::Diff, timeout)
         .await?;
 
     if diff.is_empty() {
@@ -88,3 +254,73 @@ pub async fn pr_diff(client: &dyn GitClient, params: PrDiffParams, default_repo:
         "```diff\n{diff}\n```"
     ))]))
 }
+
+pub async fn pr_commits(client: &dyn GitClient, params: PrCommitsParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let query = QueryBuilder::for_client(client).page(params.page).limit(params.limit).build();
+    let val = client
+        .get_json_with_query(
+            &format!("/repos/{owner}/{repo}/pulls/{}/commits", params.index),
+            &query_refs(&query),
+        )
+        .await?;
+    let commits = val.as_array().cloned().unwrap_or_default();
+
+    Ok(CallToolResult::success(vec![Content::text(
+        response::format_commit_list(&commits),
+    )]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn pr_file_without_patch_is_stats_line() {
+        let f = json!({"filename": "src/lib.rs", "status": "modified", "additions": 3, "deletions": 1, "patch": "@@ -1 +1 @@"});
+        assert_eq!(format_pr_file(&f, false), "- src/lib.rs (modified) +3 -1");
+    }
+
+    #[test]
+    fn pr_file_patch_rendered_in_diff_block() {
+        let f = json!({"filename": "a.txt", "status": "added", "additions": 1, "deletions": 0, "patch": "@@ -0,0 +1 @@\n+hi"});
+        assert_eq!(
+            format_pr_file(&f, true),
+            "- a.txt (added) +1 -0\n\n```diff\n@@ -0,0 +1 @@\n+hi\n```"
+        );
+        let bin = json!({"filename": "logo.png", "status": "added"});
+        assert!(format_pr_file(&bin, true).ends_with("(no patch available)"));
+    }
+
+    #[test]
+    fn pr_file_large_patch_truncated() {
+        let f = json!({"filename": "big.rs", "patch": "+".repeat(MAX_PATCH_BYTES + 10)});
+        assert!(format_pr_file(&f, true).contains("[truncated, 20000 of 20010 bytes shown]"));
+    }
+
+    #[test]
+    fn diffstat_lines_and_totals() {
+        let files = [
+            json!({"filename": "src/lib.rs", "additions": 3, "deletions": 1}),
+            json!({"filename": "README.md", "additions": 1, "deletions": 0}),
+            json!({"filename": "logo.png", "additions": 0, "deletions": 0}),
+        ];
+        assert_eq!(
+            format_diffstat(&files),
+            "```\nsrc/lib.rs | 4 +++-\nREADME.md  | 1 +\nlogo.png   | 0\n\
+             3 files changed, 4 insertions(+), 1 deletion(-)\n```"
+        );
+    }
+
+    #[test]
+    fn diffstat_bars_scale_to_width() {
+        let files = [
+            json!({"filename": "big.rs", "additions": 300, "deletions": 100}),
+            json!({"filename": "small.rs", "additions": 1, "deletions": 0}),
+        ];
+        let out = format_diffstat(&files);
+        assert!(out.contains(&format!("big.rs   | 400 {}{}\n", "+".repeat(30), "-".repeat(10))));
+        assert!(out.contains("small.rs |   1 +\n"));
+    }
+}
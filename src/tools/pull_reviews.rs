@@ -4,8 +4,11 @@ use serde::Deserialize;
 
 use crate::client::GitClient;
 use crate::error::Result;
+use crate::platform::Platform;
 use crate::repo_resolver::RepoInfo;
+use crate::response;
 use crate::server::resolve_owner_repo;
+use crate::types::common::{query_refs, QueryBuilder};
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct PrReviewListParams {
@@ -15,8 +18,16 @@ pub struct PrReviewListParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
     /// Pull request number.
     pub index: i64,
+    /// Only show reviews in this state: APPROVED, CHANGES_REQUESTED, or COMMENTED.
+    pub state: Option<String>,
+    /// Page number (1-based). Defaults to 1.
+    pub page: Option<i64>,
+    /// Items per page (max 50). Defaults to 20.
+    pub limit: Option<i64>,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -27,12 +38,45 @@ pub struct PrReviewCreateParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
     /// Pull request number.
     pub index: i64,
     /// Review event type: APPROVED, REQUEST_CHANGES, or COMMENT.
     pub event: String,
     /// Review body/comment.
     pub body: Option<String>,
+    /// Inline comments attached to specific files and lines in the diff.
+    pub comments: Option<Vec<ReviewComment>>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct PrReviewersParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Pull request number.
+    pub index: i64,
+    /// Usernames of the reviewers.
+    pub reviewers: Vec<String>,
+    /// Team names (slugs) of the reviewer teams.
+    pub team_reviewers: Option<Vec<String>>,
+}
+
+/// A single inline review comment anchored to a file and line.
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ReviewComment {
+    /// File path relative to the repository root (as shown by pr_files).
+    pub path: String,
+    /// Line number in the new version of the file to comment on.
+    pub line: i64,
+    /// Comment body in markdown.
+    pub body: String,
 }
 
 pub async fn pr_review_list(
@@ -40,14 +84,22 @@ pub async fn pr_review_list(
     params: PrReviewListParams,
     default_repo: Option<&RepoInfo>,
 ) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let query = QueryBuilder::for_client(client).page(params.page).limit(params.limit).build();
     let val = client
-        .get_json(&format!(
-            "/repos/{owner}/{repo}/pulls/{}/reviews",
-            params.index
-        ))
+        .get_json_with_query(
+            &format!("/repos/{owner}/{repo}/pulls/{}/reviews", params.index),
+            &query_refs(&query),
+        )
         .await?;
-    let reviews = val.as_array().cloned().unwrap_or_default();
+    let mut reviews = val.as_array().cloned().unwrap_or_default();
+    if let Some(wanted) = &params.state {
+        reviews.retain(|r| {
+            r.get("state")
+                .and_then(|v| v.as_str())
+                .is_some_and(|state| canonical_review_state(state) == canonical_review_state(wanted))
+        });
+    }
 
     if reviews.is_empty() {
         return Ok(CallToolResult::success(vec![Content::text(
@@ -72,10 +124,15 @@ pub async fn pr_review_list(
                 .and_then(|v| v.as_str())
                 .unwrap_or("");
             let id = r.get("id").and_then(|v| v.as_i64()).unwrap_or(0);
+            let submitted = r
+                .get("submitted_at")
+                .and_then(|v| v.as_str())
+                .map(|t| format!(" ({})", response::format_timestamp(t, client.settings())))
+                .unwrap_or_default();
             if body.is_empty() {
-                format!("- Review #{id} by {user}: {state}")
+                format!("- Review #{id} by {user}: {state}{submitted}")
             } else {
-                format!("- Review #{id} by {user}: {state}\n  {body}")
+                format!("- Review #{id} by {user}: {state}{submitted}\n  {body}")
             }
         })
         .collect();
@@ -90,12 +147,8 @@ pub async fn pr_review_create(
     params: PrReviewCreateParams,
     default_repo: Option<&RepoInfo>,
 ) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
-    let mut body = serde_json::json!({ "event": params.event });
-
-    if let Some(b) = &params.body {
-        body["body"] = serde_json::Value::String(b.clone());
-    }
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let body = build_review_body(client.platform(), &params);
 
     let review = client
         .post_json(
@@ -113,3 +166,182 @@ pub async fn pr_review_create(
         "Review submitted: {state}"
     ))]))
 }
+
+pub async fn pr_request_reviewers(
+    client: &dyn GitClient,
+    params: PrReviewersParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let path = format!("/repos/{owner}/{repo}/pulls/{}/requested_reviewers", params.index);
+
+    let _ = client.post_json(&path, &build_reviewers_body(&params)).await?;
+
+    requested_reviewers_summary(client, &owner, &repo, params.index).await
+}
+
+pub async fn pr_remove_reviewers(
+    client: &dyn GitClient,
+    params: PrReviewersParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let path = format!("/repos/{owner}/{repo}/pulls/{}/requested_reviewers", params.index);
+
+    client
+        .delete_with_body(&path, &build_reviewers_body(&params))
+        .await?;
+
+    requested_reviewers_summary(client, &owner, &repo, params.index).await
+}
+
+fn build_reviewers_body(params: &PrReviewersParams) -> serde_json::Value {
+    let mut body = serde_json::json!({ "reviewers": params.reviewers });
+    if let Some(teams) = &params.team_reviewers {
+        body["team_reviewers"] = serde_json::json!(teams);
+    }
+    body
+}
+
+/// Re-fetch the PR and report who is currently requested for review.
+async fn requested_reviewers_summary(
+    client: &dyn GitClient,
+    owner: &str,
+    repo: &str,
+    index: i64,
+) -> Result<CallToolResult> {
+    let pr = client
+        .get_json(&format!("/repos/{owner}/{repo}/pulls/{index}"))
+        .await?;
+
+    let names = |key: &str, field: &str| -> Vec<String> {
+        pr.get(key)
+            .and_then(|v| v.as_array())
+            .map(|arr| {
+                arr.iter()
+                    .filter_map(|u| u.get(field).and_then(|v| v.as_str()))
+                    .map(|s| s.to_string())
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    let users = names("requested_reviewers", "login");
+    let teams = names("requested_teams", "name");
+
+    if users.is_empty() && teams.is_empty() {
+        return Ok(CallToolResult::success(vec![Content::text(format!(
+            "PR #{index} has no reviewers currently requested."
+        ))]));
+    }
+
+    let mut parts = vec![format!("**Requested reviewers on PR #{index}:**")];
+    if !users.is_empty() {
+        parts.push(format!("**Users:** {}", users.join(", ")));
+    }
+    if !teams.is_empty() {
+        parts.push(format!("**Teams:** {}", teams.join(", ")));
+    }
+
+    Ok(CallToolResult::success(vec![Content::text(
+        parts.join("\n"),
+    )]))
+}
+
+/// Build the review request body. Inline comments use `line` on GitHub and
+/// `new_position` on Gitea.
+fn build_review_body(platform: Platform, params: &PrReviewCreateParams) -> serde_json::Value {
+    let mut body = serde_json::json!({ "event": params.event });
+
+    if let Some(b) = &params.body {
+        body["body"] = serde_json::Value::String(b.clone());
+    }
+    if let Some(comments) = &params.comments {
+        let line_key = match platform {
+            Platform::Gitea => "new_position",
+            Platform::GitHub | Platform::GitLab => "line",
+        };
+        let items: Vec<serde_json::Value> = comments
+            .iter()
+            .map(|c| {
+                let mut item = serde_json::json!({
+                    "path": c.path,
+                    "body": c.body,
+                });
+                item[line_key] = serde_json::json!(c.line);
+                item
+            })
+            .collect();
+        body["comments"] = serde_json::Value::Array(items);
+    }
+
+    body
+}
+
+/// Map Gitea (`REQUEST_CHANGES`, `COMMENT`) and GitHub (`CHANGES_REQUESTED`,
+/// `COMMENTED`) review states onto one spelling so either works as a filter.
+pub(crate) fn canonical_review_state(state: &str) -> String {
+    match state.to_ascii_uppercase().as_str() {
+        "REQUEST_CHANGES" | "CHANGES_REQUESTED" => "CHANGES_REQUESTED".to_string(),
+        "COMMENT" | "COMMENTED" => "COMMENTED".to_string(),
+        "APPROVE" | "APPROVED" => "APPROVED".to_string(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params_with_comment() -> PrReviewCreateParams {
+        PrReviewCreateParams {
+            owner: None,
+            repo: None,
+            directory: None,
+            remote: None,
+            index: 1,
+            event: "COMMENT".to_string(),
+            body: Some("Looks good overall".to_string()),
+            comments: Some(vec![ReviewComment {
+                path: "src/main.rs".to_string(),
+                line: 42,
+                body: "Consider handling the error here".to_string(),
+            }]),
+        }
+    }
+
+    #[test]
+    fn review_states_match_across_platforms() {
+        assert_eq!(canonical_review_state("REQUEST_CHANGES"), canonical_review_state("changes_requested"));
+        assert_eq!(canonical_review_state("COMMENT"), canonical_review_state("COMMENTED"));
+        assert_ne!(canonical_review_state("APPROVED"), canonical_review_state("COMMENTED"));
+    }
+
+    #[test]
+    fn review_comments_use_new_position_on_gitea() {
+        let body = build_review_body(Platform::Gitea, &params_with_comment());
+        let comments = body["comments"].as_array().unwrap();
+        assert_eq!(comments.len(), 1);
+        assert_eq!(comments[0]["path"], "src/main.rs");
+        assert_eq!(comments[0]["new_position"], 42);
+        assert_eq!(comments[0]["body"], "Consider handling the error here");
+        assert!(comments[0].get("line").is_none());
+    }
+
+    #[test]
+    fn review_comments_use_line_on_github() {
+        let body = build_review_body(Platform::GitHub, &params_with_comment());
+        let comments = body["comments"].as_array().unwrap();
+        assert_eq!(comments[0]["line"], 42);
+        assert!(comments[0].get("new_position").is_none());
+        assert_eq!(body["event"], "COMMENT");
+        assert_eq!(body["body"], "Looks good overall");
+    }
+
+    #[test]
+    fn review_without_comments_omits_array() {
+        let mut params = params_with_comment();
+        params.comments = None;
+        let body = build_review_body(Platform::GitHub, &params);
+        assert!(body.get("comments").is_none());
+    }
+}
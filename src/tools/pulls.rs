@@ -3,10 +3,14 @@ use schemars::JsonSchema;
 use serde::Deserialize;
 
 use crate::client::GitClient;
-use crate::error::Result;
+use crate::config::ToolSettings;
+use crate::error::{GitxError, Result};
+use crate::platform::Platform;
 use crate::response;
 use crate::repo_resolver::RepoInfo;
-use crate::server::resolve_owner_repo;
+use crate::server::{resolve_owner_repo, InstanceOverride};
+use crate::tools::pull_reviews::canonical_review_state;
+use crate::types::common::{query_refs, validate_sort, PageLimits, QueryBuilder};
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct PrListParams {
@@ -16,8 +20,23 @@ pub struct PrListParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Query a different instance (or with a different token) for this call only.
+    #[serde(flatten)]
+    pub instance: InstanceOverride,
     /// Filter by state: open, closed, or all. Defaults to open.
     pub state: Option<String>,
+    /// Only PRs targeting this branch (e.g. "release/2.0").
+    pub base: Option<String>,
+    /// Only PRs from this source branch. On GitHub, "user:branch" selects a fork's branch.
+    pub head: Option<String>,
+    /// Only PRs opened by this username.
+    pub author: Option<String>,
+    /// Order by: created, updated, or popularity (comment count). Defaults to created.
+    pub sort: Option<String>,
+    /// Sort direction: asc or desc. Defaults to desc.
+    pub direction: Option<String>,
     /// Page number (1-based). Defaults to 1.
     pub page: Option<i64>,
     /// Items per page (max 50). Defaults to 20.
@@ -32,8 +51,17 @@ pub struct PrGetParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Query a different instance (or with a different token) for this call only.
+    #[serde(flatten)]
+    pub instance: InstanceOverride,
     /// Pull request number.
     pub index: i64,
+    /// Output format: "markdown" (default) or "json" for the raw API object.
+    pub format: Option<String>,
+    /// Poll until the server has computed `mergeable` (up to ~8 seconds).
+    pub wait_for_mergeable: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -44,9 +72,12 @@ pub struct PrCreateParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
     /// PR title.
     pub title: String,
-    /// Head branch (source).
+    /// Head branch (source). For a pull request from a fork, use "user:branch"
+    /// where user is the fork's owner, and set owner/repo to the upstream repository.
     pub head: String,
     /// Base branch (target).
     pub base: String,
@@ -58,6 +89,24 @@ pub struct PrCreateParams {
     pub milestone: Option<i64>,
     /// Assignee usernames.
     pub assignees: Option<Vec<String>>,
+    /// Open the PR as a draft. On GitHub this sets the native draft flag; on Gitea the title is prefixed with "WIP:".
+    pub draft: Option<bool>,
+    /// Allow upstream maintainers to push to the head branch of a cross-fork PR (GitHub only).
+    pub maintainer_can_modify: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct PrMarkReadyParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Pull request number.
+    pub index: i64,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -68,6 +117,8 @@ pub struct PrEditParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
     /// Pull request number.
     pub index: i64,
     /// New title.
@@ -82,6 +133,20 @@ pub struct PrEditParams {
     pub assignees: Option<Vec<String>>,
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct PrStateParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Pull request number.
+    pub index: i64,
+}
+
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct PrMergeParams {
     /// Repository owner. Optional if `directory` is provided.
@@ -90,6 +155,8 @@ pub struct PrMergeParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
     /// Pull request number.
     pub index: i64,
     /// Merge strategy: merge, rebase, or squash. Defaults to merge.
@@ -100,41 +167,189 @@ pub struct PrMergeParams {
     pub delete_branch_after_merge: Option<bool>,
 }
 
-pub async fn pr_list(client: &dyn GitClient, params: PrListParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
-    let mut query: Vec<(&str, String)> = Vec::new();
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct PrMergeCheckParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Pull request number.
+    pub index: i64,
+}
 
-    let state = params.state.unwrap_or_else(|| "open".to_string());
-    query.push(("state", state));
-    query.push(("page", params.page.unwrap_or(1).to_string()));
-    query.push(("limit", params.limit.unwrap_or(20).min(50).to_string()));
+pub async fn pr_list(client: &dyn GitClient, params: PrListParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    validate_sort(params.sort.as_deref(), params.direction.as_deref(), PULL_SORTS)?;
+    let query = pr_list_query(client.platform(), client.settings().page_limits, &owner, &params);
 
-    let query_refs: Vec<(&str, &str)> = query.iter().map(|(k, v)| (*k, v.as_str())).collect();
     let val = client
-        .get_json_with_query(&format!("/repos/{owner}/{repo}/pulls"), &query_refs)
+        .get_json_with_query(&format!("/repos/{owner}/{repo}/pulls"), &query_refs(&query))
         .await?;
-    let prs = val.as_array().cloned().unwrap_or_default();
+    let mut prs = val.as_array().cloned().unwrap_or_default();
+    let scanned = prs.len();
+    // Gitea's list endpoint has no branch or author filters, and GitHub's has no
+    // author filter, so whatever the server could not narrow is filtered here.
+    prs.retain(|pr| pr_matches_filters(pr, &params));
 
-    Ok(CallToolResult::success(vec![Content::text(
-        response::format_pr_list(&prs),
-    )]))
+    let mut text = response::format_pr_list(&prs);
+    if let Some(note) = local_filter_note(client.platform(), &params, scanned, prs.len()) {
+        text.push_str(&note);
+    }
+    Ok(CallToolResult::success(vec![Content::text(text)]))
+}
+
+/// When filters were applied to the fetched page rather than by the server,
+/// say how many PRs were scanned and matched: other pages may hold more.
+fn local_filter_note(platform: Platform, params: &PrListParams, scanned: usize, matched: usize) -> Option<String> {
+    let local = match platform {
+        Platform::Gitea => params.base.is_some() || params.head.is_some() || params.author.is_some(),
+        Platform::GitHub | Platform::GitLab => params.author.is_some(),
+    };
+    local.then(|| {
+        format!(
+            "\n\n_Filtered the {scanned} pull requests on page {} locally; {matched} matched. \
+             Other pages may hold more matches._",
+            params.page.unwrap_or(1)
+        )
+    })
 }
 
 pub async fn pr_get(client: &dyn GitClient, params: PrGetParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let path = format!("/repos/{owner}/{repo}/pulls/{}", params.index);
+    let mut pr = client.get_json(&path).await?;
+
+    if !params.wait_for_mergeable.unwrap_or(false) {
+        return Ok(CallToolResult::success(vec![Content::text(
+            response::render(&pr, params.format.as_deref(), |v| response::format_pull_request(v, client.settings())),
+        )]));
+    }
+
+    // `mergeable` is null while the server is still computing it (typically
+    // right after the PR is created or its branches move).
+    let mut attempts = 1;
+    while pr.get("mergeable").is_none_or(|v| v.is_null()) && attempts < MERGEABLE_POLL_ATTEMPTS {
+        tokio::time::sleep(MERGEABLE_POLL_INTERVAL).await;
+        pr = client.get_json(&path).await?;
+        attempts += 1;
+    }
+
+    Ok(CallToolResult::success(vec![Content::text(render_with_mergeable(
+        pr,
+        attempts,
+        params.format.as_deref(),
+        client.settings(),
+    ))]))
+}
+
+/// Render a PR after polling for mergeability: markdown leads with a
+/// `Mergeable:` line, JSON carries the outcome in `mergeable_status` and
+/// `mergeable_checks` so the output stays a single parseable object.
+fn render_with_mergeable(mut pr: serde_json::Value, attempts: u32, format: Option<&str>, settings: ToolSettings) -> String {
+    let mergeable = pr.get("mergeable").and_then(|v| v.as_bool());
+    if format.is_some_and(|f| f.eq_ignore_ascii_case("json")) {
+        if let Some(obj) = pr.as_object_mut() {
+            let status = match mergeable {
+                Some(true) => "yes",
+                Some(false) => "no",
+                None => "unknown",
+            };
+            obj.insert("mergeable_status".to_string(), serde_json::Value::from(status));
+            obj.insert("mergeable_checks".to_string(), serde_json::Value::from(attempts));
+        }
+        return response::render(&pr, format, |v| response::format_pull_request(v, settings));
+    }
+
+    let status = match mergeable {
+        Some(true) => "**Mergeable:** yes".to_string(),
+        Some(false) => "**Mergeable:** no (conflicts or blocked)".to_string(),
+        None => format!("**Mergeable:** unknown (still computing after {attempts} checks)"),
+    };
+    format!("{status}\n\n{}", response::format_pull_request(&pr, settings))
+}
+
+/// Turn a refused merge into `MergeBlocked` with a readable reason when the
+/// server's message matches a known cause. A refusal (405/409) with an
+/// unrecognized message carries the server's own message; other errors pass through.
+fn explain_merge_failure(err: GitxError) -> GitxError {
+    match err {
+        GitxError::Status { status, body } => match merge_block_reason(&body) {
+            Some(reason) => GitxError::MergeBlocked(format!("{reason}\nServer response (HTTP {status}): {body}")),
+            None if matches!(status, 405 | 409) => {
+                GitxError::MergeBlocked(format!("{} (HTTP {status})", api_message(&body)))
+            }
+            None => GitxError::Status { status, body },
+        },
+        other => other,
+    }
+}
+
+/// The `message` field of a JSON error body, or the body itself.
+fn api_message(body: &str) -> String {
+    serde_json::from_str::<serde_json::Value>(body)
+        .ok()
+        .and_then(|v| v.get("message").and_then(|m| m.as_str()).map(String::from))
+        .unwrap_or_else(|| body.to_string())
+}
+
+/// Map known GitHub/Gitea merge refusal messages to an explanation. Branch
+/// state is checked first: GitHub's "Head branch was modified. Review and try
+/// the merge again." mentions reviewing but is not about reviews.
+fn merge_block_reason(message: &str) -> Option<&'static str> {
+    let msg = message.to_lowercase();
+    let has = |phrases: &[&str]| phrases.iter().any(|p| msg.contains(p));
+    let reason = if has(&["branch was modified"]) {
+        "the branch changed while the merge was attempted. Review the new commits, then try again."
+    } else if has(&["behind the base", "out of date", "not up to date"]) {
+        "the head branch is out of date with the base branch. Update the branch and try again."
+    } else if has(&["merge conflict", "has conflicts"]) {
+        "the pull request has merge conflicts with the base branch. Resolve them (or update the branch) and try again."
+    } else if has(&["approving review", "approval", "changes requested", "requested changes"]) {
+        "required reviews are missing. The PR needs more approving reviews (or has requested changes) before it can be merged."
+    } else if has(&["required status check", "status checks"]) {
+        "required status checks are failing or have not completed yet. Check CI with actions_run_list."
+    } else if has(&["try again later"]) {
+        "mergeability is still being computed. Call pr_get with wait_for_mergeable=true, then retry."
+    } else if has(&["not allowed"]) {
+        "this merge method or user is not allowed by the repository settings or branch protection."
+    } else {
+        return None;
+    };
+    Some(reason)
+}
+
+/// How many times `pr_get` checks `mergeable` when asked to wait.
+const MERGEABLE_POLL_ATTEMPTS: u32 = 5;
+/// Delay between `mergeable` checks.
+const MERGEABLE_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(2);
+
+pub async fn pr_create(client: &dyn GitClient, params: PrCreateParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let body = build_pr_create_body(client.platform(), &params);
+
     let pr = client
-        .get_json(&format!("/repos/{owner}/{repo}/pulls/{}", params.index))
+        .post_json(&format!("/repos/{owner}/{repo}/pulls"), &body)
         .await?;
 
     Ok(CallToolResult::success(vec![Content::text(
-        response::format_pull_request(&pr),
+        response::format_pull_request(&pr, client.settings()),
     )]))
 }
 
-pub async fn pr_create(client: &dyn GitClient, params: PrCreateParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+/// Build the `POST /pulls` body for `pr_create`.
+fn build_pr_create_body(platform: Platform, params: &PrCreateParams) -> serde_json::Value {
+    let draft = params.draft.unwrap_or(false);
+    let title = if draft && platform == Platform::Gitea {
+        wip_title(&params.title)
+    } else {
+        params.title.clone()
+    };
     let mut body = serde_json::json!({
-        "title": params.title,
+        "title": title,
         "head": params.head,
         "base": params.base,
     });
@@ -151,18 +366,18 @@ pub async fn pr_create(client: &dyn GitClient, params: PrCreateParams, default_r
     if let Some(assignees) = &params.assignees {
         body["assignees"] = serde_json::json!(assignees);
     }
-
-    let pr = client
-        .post_json(&format!("/repos/{owner}/{repo}/pulls"), &body)
-        .await?;
-
-    Ok(CallToolResult::success(vec![Content::text(
-        response::format_pull_request(&pr),
-    )]))
+    if draft && platform == Platform::GitHub {
+        body["draft"] = serde_json::Value::Bool(true);
+    }
+    // Gitea has no equivalent; maintainers there can push according to repo permissions.
+    if let (Some(allow), Platform::GitHub) = (params.maintainer_can_modify, platform) {
+        body["maintainer_can_modify"] = serde_json::Value::Bool(allow);
+    }
+    body
 }
 
 pub async fn pr_edit(client: &dyn GitClient, params: PrEditParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
     let mut body = serde_json::json!({});
 
     if let Some(title) = &params.title {
@@ -189,14 +404,36 @@ pub async fn pr_edit(client: &dyn GitClient, params: PrEditParams, default_repo:
         .await?;
 
     Ok(CallToolResult::success(vec![Content::text(
-        response::format_pull_request(&pr),
+        response::format_pull_request(&pr, client.settings()),
     )]))
 }
 
-pub async fn pr_merge(client: &dyn GitClient, params: PrMergeParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
-    use crate::platform::Platform;
+pub async fn pr_close(client: &dyn GitClient, params: PrStateParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
+    pr_edit(client, state_edit(params, "closed"), default_repo).await
+}
+
+pub async fn pr_reopen(client: &dyn GitClient, params: PrStateParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
+    pr_edit(client, state_edit(params, "open"), default_repo).await
+}
 
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+/// A `pr_edit` that only changes the state.
+fn state_edit(params: PrStateParams, state: &str) -> PrEditParams {
+    PrEditParams {
+        owner: params.owner,
+        repo: params.repo,
+        directory: params.directory,
+        remote: params.remote,
+        index: params.index,
+        title: None,
+        body: None,
+        state: Some(state.to_string()),
+        labels: None,
+        assignees: None,
+    }
+}
+
+pub async fn pr_merge(client: &dyn GitClient, params: PrMergeParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
     let style = params.merge_style.unwrap_or_else(|| "merge".to_string());
 
     let mut body = match client.platform() {
@@ -207,6 +444,7 @@ pub async fn pr_merge(client: &dyn GitClient, params: PrMergeParams, default_rep
             }
             b
         }
+        Platform::GitLab => return Err(crate::client::gitlab_unsupported("pr_merge")),
         Platform::GitHub => {
             let mut b = serde_json::json!({ "merge_method": style });
             if let Some(msg) = &params.merge_message {
@@ -225,10 +463,767 @@ pub async fn pr_merge(client: &dyn GitClient, params: PrMergeParams, default_rep
             &format!("/repos/{owner}/{repo}/pulls/{}/merge", params.index),
             &body,
         )
-        .await?;
+        .await
+        .map_err(explain_merge_failure)?;
 
     Ok(CallToolResult::success(vec![Content::text(format!(
         "Pull request #{} merged successfully.",
         params.index
     ))]))
 }
+
+pub async fn pr_merge_check(
+    client: &dyn GitClient,
+    params: PrMergeCheckParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let platform = client.platform();
+    if platform == Platform::GitLab {
+        return Err(crate::client::gitlab_unsupported("pr_merge_check"));
+    }
+    let pr = client
+        .get_json(&format!("/repos/{owner}/{repo}/pulls/{}", params.index))
+        .await?;
+    let base = pr.get("base").and_then(|b| b.get("ref")).and_then(|v| v.as_str()).unwrap_or("");
+    let head_sha = pr.get("head").and_then(|h| h.get("sha")).and_then(|v| v.as_str()).unwrap_or("");
+
+    // Everything past the PR itself is best effort: a token without admin
+    // scope or a flaky endpoint leaves that part of the report unknown (and
+    // the verdict with it) instead of failing the check.
+    let reviews = client
+        .get_json(&format!("/repos/{owner}/{repo}/pulls/{}/reviews", params.index))
+        .await
+        .ok()
+        .and_then(|v| v.as_array().cloned());
+    let rules = if base.is_empty() { None } else { merge_rules(client, &owner, &repo, base).await };
+    let ci = if head_sha.is_empty() {
+        None
+    } else {
+        let status = client
+            .get_json(&format!("/repos/{owner}/{repo}/commits/{head_sha}/status"))
+            .await;
+        // GitHub Actions reports check runs, not commit statuses; Gitea has only statuses.
+        let runs = match platform {
+            Platform::Gitea => Ok(None),
+            Platform::GitHub | Platform::GitLab => client
+                .get_json_with_query(
+                    &format!("/repos/{owner}/{repo}/commits/{head_sha}/check-runs"),
+                    &[("per_page", "100")],
+                )
+                .await
+                .map(Some),
+        };
+        match (status, runs) {
+            (Ok(status), Ok(runs)) => Some(ci_checks(&status, runs.as_ref())),
+            _ => None,
+        }
+    };
+    // How many commits the base branch has that the head lacks. GitHub
+    // reports `behind_by` directly; Gitea needs the reverse comparison.
+    let behind = if head_sha.is_empty() || base.is_empty() {
+        None
+    } else {
+        match platform {
+            Platform::Gitea => client
+                .get_json(&format!("/repos/{owner}/{repo}/compare/{head_sha}...{base}"))
+                .await
+                .ok()
+                .and_then(|c| {
+                    c.get("total_commits")
+                        .and_then(|v| v.as_i64())
+                        .or_else(|| c.get("commits").and_then(|v| v.as_array()).map(|a| a.len() as i64))
+                }),
+            Platform::GitHub | Platform::GitLab => client
+                .get_json(&format!("/repos/{owner}/{repo}/compare/{base}...{head_sha}"))
+                .await
+                .ok()
+                .and_then(|c| c.get("behind_by").and_then(|v| v.as_i64())),
+        }
+    };
+
+    Ok(CallToolResult::success(vec![Content::text(
+        format_merge_check(&pr, reviews.as_deref(), rules.as_ref(), ci.as_deref(), behind),
+    )]))
+}
+
+/// The protection rules in force on `base`: default rules when the branch is
+/// unprotected, `None` when they could not be read. Gitea rules are looked up
+/// by the rule that applies to the branch, which may be a wildcard pattern.
+async fn merge_rules(client: &dyn GitClient, owner: &str, repo: &str, base: &str) -> Option<MergeRules> {
+    let platform = client.platform();
+    let branch = client.get_json(&format!("/repos/{owner}/{repo}/branches/{base}")).await.ok()?;
+    if !branch.get("protected").and_then(|v| v.as_bool()).unwrap_or(false) {
+        return Some(MergeRules::default());
+    }
+    let path = match platform {
+        Platform::Gitea => {
+            let rule = branch.get("effective_branch_protection_name").and_then(|v| v.as_str())?;
+            let rule: String = url::form_urlencoded::byte_serialize(rule.as_bytes()).collect();
+            format!("/repos/{owner}/{repo}/branch_protections/{}", rule.replace('+', "%20"))
+        }
+        Platform::GitHub | Platform::GitLab => format!("/repos/{owner}/{repo}/branches/{base}/protection"),
+    };
+    let protection = client.get_json(&path).await.ok()?;
+    Some(MergeRules::from_protection(platform, &protection))
+}
+
+/// The parts of a base branch's protection rule that decide mergeability.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct MergeRules {
+    required_approvals: i64,
+    require_up_to_date: bool,
+    /// Status check / check run names that must pass.
+    required_checks: Vec<String>,
+}
+
+impl MergeRules {
+    fn from_protection(platform: Platform, protection: &serde_json::Value) -> Self {
+        let strings = |v: Option<&serde_json::Value>| -> Vec<String> {
+            v.and_then(|v| v.as_array())
+                .map(|a| a.iter().filter_map(|s| s.as_str().map(String::from)).collect())
+                .unwrap_or_default()
+        };
+        match platform {
+            Platform::Gitea => MergeRules {
+                required_approvals: protection.get("required_approvals").and_then(|v| v.as_i64()).unwrap_or(0),
+                require_up_to_date: protection
+                    .get("block_on_outdated_branch")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false),
+                required_checks: if protection.get("enable_status_check").and_then(|v| v.as_bool()).unwrap_or(false) {
+                    strings(protection.get("status_check_contexts"))
+                } else {
+                    Vec::new()
+                },
+            },
+            Platform::GitHub | Platform::GitLab => MergeRules {
+                required_approvals: protection
+                    .pointer("/required_pull_request_reviews/required_approving_review_count")
+                    .and_then(|v| v.as_i64())
+                    .unwrap_or(0),
+                require_up_to_date: protection
+                    .pointer("/required_status_checks/strict")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false),
+                required_checks: strings(protection.pointer("/required_status_checks/contexts")),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CiState {
+    Success,
+    Pending,
+    Failure,
+}
+
+/// One commit status or check run on the PR head.
+#[derive(Debug, Clone, PartialEq)]
+struct CiCheck {
+    name: String,
+    state: CiState,
+}
+
+/// Flatten the combined commit status and (GitHub) check runs into one list.
+fn ci_checks(status: &serde_json::Value, check_runs: Option<&serde_json::Value>) -> Vec<CiCheck> {
+    let statuses = status.get("statuses").and_then(|v| v.as_array()).into_iter().flatten();
+    let mut checks: Vec<CiCheck> = statuses
+        .map(|s| CiCheck {
+            name: s.get("context").and_then(|v| v.as_str()).unwrap_or("?").to_string(),
+            // GitHub names the field `state`, Gitea `status`.
+            state: match s.get("state").or_else(|| s.get("status")).and_then(|v| v.as_str()) {
+                Some("success") => CiState::Success,
+                Some("pending") | Some("running") => CiState::Pending,
+                _ => CiState::Failure,
+            },
+        })
+        .collect();
+
+    let runs = check_runs.and_then(|r| r.get("check_runs")).and_then(|v| v.as_array()).into_iter().flatten();
+    checks.extend(runs.map(|r| CiCheck {
+        name: r.get("name").and_then(|v| v.as_str()).unwrap_or("?").to_string(),
+        state: match (
+            r.get("status").and_then(|v| v.as_str()),
+            r.get("conclusion").and_then(|v| v.as_str()),
+        ) {
+            (Some("completed"), Some("success" | "neutral" | "skipped")) => CiState::Success,
+            (Some("completed"), _) => CiState::Failure,
+            _ => CiState::Pending,
+        },
+    }));
+    checks
+}
+
+/// Build the `pr_merge_check` report: a verdict line, the blockers that
+/// decided it, and the individual checks behind them. The verdict is only
+/// "yes" when every input could be read.
+fn format_merge_check(
+    pr: &serde_json::Value,
+    reviews: Option<&[serde_json::Value]>,
+    rules: Option<&MergeRules>,
+    ci: Option<&[CiCheck]>,
+    behind: Option<i64>,
+) -> String {
+    let mut blockers = Vec::new();
+    let mut unknown = Vec::new();
+    let mut checks = Vec::new();
+
+    let number = pr.get("number").and_then(|v| v.as_i64()).unwrap_or(0);
+    let title = pr.get("title").and_then(|v| v.as_str()).unwrap_or("");
+    let state = pr.get("state").and_then(|v| v.as_str()).unwrap_or("open");
+    if pr.get("merged").and_then(|v| v.as_bool()).unwrap_or(false) {
+        blockers.push("already merged".to_string());
+    } else if state != "open" {
+        blockers.push(format!("PR is {state}"));
+    }
+    if pr.get("draft").and_then(|v| v.as_bool()).unwrap_or(false) || strip_wip_prefix(title) != title {
+        blockers.push("PR is a draft".to_string());
+    }
+
+    match pr.get("mergeable").and_then(|v| v.as_bool()) {
+        Some(true) => checks.push("- **Conflicts:** none".to_string()),
+        Some(false) => {
+            checks.push("- **Conflicts:** yes".to_string());
+            blockers.push("merge conflicts with the base branch".to_string());
+        }
+        None => {
+            checks.push("- **Conflicts:** unknown (mergeability still being computed)".to_string());
+            unknown.push("mergeability is still being computed (call pr_get with wait_for_mergeable=true)");
+        }
+    }
+    if let Some(merge_state) = pr.get("mergeable_state").and_then(|v| v.as_str()) {
+        checks.push(format!("- **Merge state:** {merge_state}"));
+    }
+
+    match rules {
+        Some(rules) if *rules == MergeRules::default() => checks.push("- **Branch protection:** none".to_string()),
+        Some(_) => {}
+        None => {
+            checks.push("- **Branch protection:** unknown (could not read the base branch's rules)".to_string());
+            unknown.push("branch protection could not be read");
+        }
+    }
+
+    match reviews {
+        Some(reviews) => {
+            let (approved, changes_requested) = latest_review_verdicts(reviews);
+            let mut line = format!("- **Approvals:** {}", approved.len());
+            match rules.map(|r| r.required_approvals) {
+                Some(required) if required > 0 => {
+                    line.push_str(&format!(" of {required} required"));
+                    let missing = required - approved.len() as i64;
+                    if missing > 0 {
+                        let s = if missing == 1 { "" } else { "s" };
+                        blockers.push(format!("{missing} required approval{s} missing"));
+                    }
+                }
+                Some(_) => {}
+                None => line.push_str(" (required count unknown)"),
+            }
+            checks.push(line);
+            if !changes_requested.is_empty() {
+                let who = changes_requested.join(", ");
+                checks.push(format!("- **Changes requested by:** {who}"));
+                blockers.push(format!("changes requested by {who}"));
+            }
+        }
+        None => {
+            checks.push("- **Approvals:** unknown (could not read reviews)".to_string());
+            unknown.push("reviews could not be read");
+        }
+    }
+
+    match ci {
+        Some(ci) => {
+            let named = |state: CiState| -> Vec<&str> {
+                ci.iter().filter(|c| c.state == state).map(|c| c.name.as_str()).collect()
+            };
+            let (failing, pending) = (named(CiState::Failure), named(CiState::Pending));
+            let missing: Vec<&str> = rules
+                .map(|r| r.required_checks.iter().map(String::as_str).filter(|n| !ci.iter().any(|c| c.name == *n)).collect())
+                .unwrap_or_default();
+            if ci.is_empty() && missing.is_empty() {
+                checks.push("- **CI:** no checks reported".to_string());
+            } else if failing.is_empty() && pending.is_empty() && missing.is_empty() {
+                checks.push(format!("- **CI:** passing ({} checks)", ci.len()));
+            }
+            if !failing.is_empty() {
+                checks.push(format!("- **CI failing:** {}", failing.join(", ")));
+                blockers.push("CI failing".to_string());
+            }
+            if !pending.is_empty() {
+                checks.push(format!("- **CI pending:** {}", pending.join(", ")));
+                blockers.push("CI pending".to_string());
+            }
+            if !missing.is_empty() {
+                checks.push(format!("- **Required checks not reported:** {}", missing.join(", ")));
+                blockers.push("required checks not reported".to_string());
+            }
+        }
+        None => {
+            checks.push("- **CI:** unknown (could not read commit statuses or check runs)".to_string());
+            unknown.push("CI results could not be read");
+        }
+    }
+
+    let require_up_to_date = rules.is_some_and(|r| r.require_up_to_date)
+        || pr.get("mergeable_state").and_then(|v| v.as_str()) == Some("behind");
+    match behind {
+        Some(0) => checks.push("- **Up to date with base:** yes".to_string()),
+        Some(n) => {
+            checks.push(format!("- **Up to date with base:** no (behind by {n})"));
+            if require_up_to_date {
+                blockers.push(format!("head branch is {n} commit{} behind base", if n == 1 { "" } else { "s" }));
+            }
+        }
+        None => {
+            checks.push("- **Up to date with base:** unknown".to_string());
+            if require_up_to_date {
+                unknown.push("whether the head is up to date could not be read");
+            }
+        }
+    }
+
+    let verdict = if !blockers.is_empty() {
+        "no".to_string()
+    } else if !unknown.is_empty() {
+        format!("unknown ({})", unknown.join("; "))
+    } else {
+        "yes".to_string()
+    };
+    let blocker_line = if blockers.is_empty() {
+        "none".to_string()
+    } else {
+        blockers.join("; ")
+    };
+
+    format!(
+        "**PR #{number}:** {title}\n**Ready to merge:** {verdict}\n**Blockers:** {blocker_line}\n\n{}",
+        checks.join("\n")
+    )
+}
+
+/// Reviewers whose latest decisive review approves, and those whose latest
+/// requests changes. Comments don't override an earlier decision, and
+/// dismissed or stale reviews are ignored.
+fn latest_review_verdicts(reviews: &[serde_json::Value]) -> (Vec<String>, Vec<String>) {
+    let mut latest: Vec<(String, String)> = Vec::new();
+    for r in reviews {
+        if r.get("dismissed").and_then(|v| v.as_bool()).unwrap_or(false)
+            || r.get("stale").and_then(|v| v.as_bool()).unwrap_or(false)
+        {
+            continue;
+        }
+        let state = canonical_review_state(r.get("state").and_then(|v| v.as_str()).unwrap_or(""));
+        if state != "APPROVED" && state != "CHANGES_REQUESTED" {
+            continue;
+        }
+        let Some(user) = r.get("user").and_then(|u| u.get("login")).and_then(|v| v.as_str()) else {
+            continue;
+        };
+        match latest.iter_mut().find(|(u, _)| u == user) {
+            Some(entry) => entry.1 = state,
+            None => latest.push((user.to_string(), state)),
+        }
+    }
+    let pick = |wanted: &str| {
+        latest
+            .iter()
+            .filter(|(_, s)| s == wanted)
+            .map(|(u, _)| u.clone())
+            .collect::<Vec<_>>()
+    };
+    (pick("APPROVED"), pick("CHANGES_REQUESTED"))
+}
+
+pub async fn pr_mark_ready(
+    client: &dyn GitClient,
+    params: PrMarkReadyParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    if client.platform() == Platform::GitHub {
+        return Ok(CallToolResult::success(vec![Content::text(
+            "Marking a draft PR as ready for review is not available on GitHub through the REST API \
+             (it requires the GraphQL markPullRequestReadyForReview mutation). \
+             Use the GitHub web UI or `gh pr ready` instead.",
+        )]));
+    }
+
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let path = format!("/repos/{owner}/{repo}/pulls/{}", params.index);
+    let pr = client.get_json(&path).await?;
+    let title = pr.get("title").and_then(|v| v.as_str()).unwrap_or("");
+
+    let ready_title = strip_wip_prefix(title);
+    if ready_title == title {
+        return Ok(CallToolResult::success(vec![Content::text(format!(
+            "PR #{} is not a draft (no WIP prefix in title).",
+            params.index
+        ))]));
+    }
+
+    let pr = client
+        .patch_json(&path, &serde_json::json!({ "title": ready_title }))
+        .await?;
+
+    Ok(CallToolResult::success(vec![Content::text(
+        response::format_pull_request(&pr, client.settings()),
+    )]))
+}
+
+/// Gitea's work-in-progress title prefixes (case-insensitive).
+const WIP_PREFIXES: &[&str] = &["wip:", "[wip]"];
+
+/// Prefix a title with Gitea's "WIP:" draft marker unless it already has one.
+fn wip_title(title: &str) -> String {
+    if strip_wip_prefix(title) != title {
+        title.to_string()
+    } else {
+        format!("WIP: {title}")
+    }
+}
+
+/// Remove a leading Gitea work-in-progress marker from a title.
+fn strip_wip_prefix(title: &str) -> String {
+    let trimmed = title.trim_start();
+    let lower = trimmed.to_lowercase();
+    for prefix in WIP_PREFIXES {
+        if lower.starts_with(prefix) {
+            return trimmed[prefix.len()..].trim_start().to_string();
+        }
+    }
+    title.to_string()
+}
+
+/// Query parameters for `pr_list`; branch filters are sent where the pulls endpoint accepts them.
+fn pr_list_query(platform: Platform, limits: PageLimits, owner: &str, params: &PrListParams) -> Vec<(&'static str, String)> {
+    let (base, head) = match platform {
+        Platform::Gitea => (None, None),
+        // GitHub matches `head` as "user:branch"; a bare branch means one in this repo.
+        Platform::GitHub => (
+            params.base.clone(),
+            params.head.as_ref().map(|h| if h.contains(':') { h.clone() } else { format!("{owner}:{h}") }),
+        ),
+        Platform::GitLab => (params.base.clone(), params.head.clone()),
+    };
+    let query = QueryBuilder::new(platform, limits)
+        .param("state", params.state.as_deref().unwrap_or("open"))
+        .opt("base", base)
+        .opt("head", head);
+    let query = match platform {
+        Platform::Gitea => query.opt("sort", gitea_pull_sort(params.sort.as_deref(), params.direction.as_deref())),
+        Platform::GitHub | Platform::GitLab => query
+            .opt("sort", params.sort.as_ref())
+            .opt("direction", params.direction.as_ref()),
+    };
+    query.page(params.page).limit(params.limit).build()
+}
+
+/// Sort keys `pr_list` accepts, matching GitHub's pulls `sort` values.
+const PULL_SORTS: &[&str] = &["created", "updated", "popularity"];
+
+/// Gitea's single `sort` value for a GitHub-style sort and direction.
+/// `None` is newest first, Gitea's own default.
+fn gitea_pull_sort(sort: Option<&str>, direction: Option<&str>) -> Option<&'static str> {
+    let asc = direction == Some("asc");
+    match sort.unwrap_or("created") {
+        "updated" if asc => Some("leastupdate"),
+        "updated" => Some("recentupdate"),
+        "popularity" if asc => Some("leastcomment"),
+        "popularity" => Some("mostcomment"),
+        _ if asc => Some("oldest"),
+        _ => None,
+    }
+}
+
+/// Whether a listed PR satisfies the `base`, `head`, and `author` filters that are set.
+fn pr_matches_filters(pr: &serde_json::Value, params: &PrListParams) -> bool {
+    let field = |obj: &str, key: &str| pr.get(obj).and_then(|o| o.get(key)).and_then(|v| v.as_str()).unwrap_or("");
+    // Strip a "user:" prefix so fork heads compare by branch name.
+    let head = params.head.as_deref().map(|h| h.rsplit(':').next().unwrap_or(h));
+    params.base.as_deref().is_none_or(|b| field("base", "ref") == b)
+        && head.is_none_or(|h| field("head", "ref") == h)
+        && params.author.as_deref().is_none_or(|a| field("user", "login").eq_ignore_ascii_case(a))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn waited_json_keeps_a_single_object() {
+        let pr = serde_json::json!({"number": 7, "title": "Fix", "mergeable": null});
+        let out = render_with_mergeable(pr.clone(), 5, Some("json"), ToolSettings::default());
+        let body = out.trim_start_matches("```json\n").trim_end_matches("\n```");
+        let parsed: serde_json::Value = serde_json::from_str(body).unwrap();
+        assert_eq!(parsed["mergeable_status"], "unknown");
+        assert_eq!(parsed["mergeable_checks"], 5);
+
+        let out = render_with_mergeable(pr, 5, None, ToolSettings::default());
+        assert!(out.starts_with("**Mergeable:** unknown (still computing after 5 checks)\n\n"));
+    }
+
+    #[test]
+    fn local_filters_report_scanned_and_matched() {
+        let mut params = PrListParams { base: None, head: None, ..list_params() };
+        assert_eq!(local_filter_note(Platform::Gitea, &params, 20, 20), None);
+
+        params.base = Some("main".to_string());
+        assert_eq!(
+            local_filter_note(Platform::Gitea, &params, 20, 3).as_deref(),
+            Some("\n\n_Filtered the 20 pull requests on page 1 locally; 3 matched. Other pages may hold more matches._")
+        );
+        // GitHub filters base itself.
+        assert_eq!(local_filter_note(Platform::GitHub, &params, 20, 3), None);
+        params.author = Some("alice".to_string());
+        assert!(local_filter_note(Platform::GitHub, &params, 20, 3).is_some());
+    }
+
+    fn list_params() -> PrListParams {
+        PrListParams {
+            owner: None,
+            repo: None,
+            directory: None,
+            remote: None,
+            instance: InstanceOverride::default(),
+            state: None,
+            base: Some("release/2.0".to_string()),
+            head: Some("fix-typo".to_string()),
+            author: None,
+            sort: None,
+            direction: None,
+            page: None,
+            limit: None,
+        }
+    }
+
+    #[test]
+    fn pr_list_sort_maps_to_gitea_values() {
+        let mut params = list_params();
+        params.sort = Some("updated".to_string());
+        assert!(pr_list_query(Platform::Gitea, PageLimits::default(), "acme", &params).contains(&("sort", "recentupdate".to_string())));
+        params.direction = Some("asc".to_string());
+        assert!(pr_list_query(Platform::Gitea, PageLimits::default(), "acme", &params).contains(&("sort", "leastupdate".to_string())));
+        let github = pr_list_query(Platform::GitHub, PageLimits::default(), "acme", &params);
+        assert!(github.contains(&("sort", "updated".to_string())));
+        assert!(github.contains(&("direction", "asc".to_string())));
+        assert_eq!(gitea_pull_sort(None, None), None);
+        assert_eq!(gitea_pull_sort(Some("popularity"), None), Some("mostcomment"));
+    }
+
+    #[test]
+    fn pr_list_sends_branch_filters_where_supported() {
+        let params = list_params();
+        let github = pr_list_query(Platform::GitHub, PageLimits::default(), "acme", &params);
+        assert!(github.contains(&("base", "release/2.0".to_string())));
+        assert!(github.contains(&("head", "acme:fix-typo".to_string())));
+        let gitea = pr_list_query(Platform::Gitea, PageLimits::default(), "acme", &params);
+        assert!(!gitea.iter().any(|(k, _)| *k == "base" || *k == "head"));
+    }
+
+    #[test]
+    fn pr_list_filters_branches_and_author_locally() {
+        let pr = serde_json::json!({
+            "base": { "ref": "release/2.0" },
+            "head": { "ref": "fix-typo" },
+            "user": { "login": "Alice" },
+        });
+        let mut params = list_params();
+        assert!(pr_matches_filters(&pr, &params));
+        params.author = Some("alice".to_string());
+        assert!(pr_matches_filters(&pr, &params));
+        params.head = Some("contributor:fix-typo".to_string());
+        assert!(pr_matches_filters(&pr, &params));
+        params.base = Some("main".to_string());
+        assert!(!pr_matches_filters(&pr, &params));
+        params.base = None;
+        params.author = Some("bob".to_string());
+        assert!(!pr_matches_filters(&pr, &params));
+    }
+
+    #[test]
+    fn maintainer_can_modify_sent_on_github_only() {
+        let params = PrCreateParams {
+            owner: None,
+            repo: None,
+            directory: None,
+            remote: None,
+            title: "Fix typo".to_string(),
+            head: "contributor:fix-typo".to_string(),
+            base: "main".to_string(),
+            body: None,
+            labels: None,
+            milestone: None,
+            assignees: None,
+            draft: None,
+            maintainer_can_modify: Some(true),
+        };
+        let body = build_pr_create_body(Platform::GitHub, &params);
+        assert_eq!(body["maintainer_can_modify"], true);
+        assert_eq!(body["head"], "contributor:fix-typo");
+        assert!(build_pr_create_body(Platform::Gitea, &params).get("maintainer_can_modify").is_none());
+    }
+
+    #[test]
+    fn wip_title_adds_prefix_once() {
+        assert_eq!(wip_title("Add feature"), "WIP: Add feature");
+        assert_eq!(wip_title("WIP: Add feature"), "WIP: Add feature");
+        assert_eq!(wip_title("[WIP] Add feature"), "[WIP] Add feature");
+    }
+
+    #[test]
+    fn merge_block_reason_recognizes_causes() {
+        let cases = [
+            ("{\"message\":\"Head branch was modified. Review and try the merge again.\"}", "branch changed"),
+            ("{\"message\":\"Base branch was modified. Review and try the merge again.\"}", "branch changed"),
+            ("{\"message\":\"At least 1 approving review is required by reviewers with write access.\"}", "required reviews"),
+            ("{\"message\":\"Required status check \\\"build\\\" is expected.\"}", "status checks"),
+            ("{\"message\":\"2 of 3 required status checks are expected.\"}", "status checks"),
+            ("{\"message\":\"Merge commits are not allowed on this repository.\"}", "not allowed"),
+            ("{\"message\":\"The head branch is behind the base branch\"}", "out of date"),
+            ("Does not have enough approvals", "required reviews"),
+            ("This pull request has merge conflicts", "merge conflicts"),
+        ];
+        for (message, expected) in cases {
+            let reason = merge_block_reason(message).unwrap();
+            assert!(reason.contains(expected), "{message} -> {reason}");
+        }
+        assert!(merge_block_reason("{\"message\":\"Pull Request is not mergeable\"}").is_none());
+        assert!(merge_block_reason("HTTP 500: internal error").is_none());
+    }
+
+    #[test]
+    fn explain_merge_failure_wraps_known_reasons() {
+        let err = explain_merge_failure(GitxError::Status {
+            status: 405,
+            body: "Does not have enough approvals".to_string(),
+        });
+        assert!(matches!(err, GitxError::MergeBlocked(ref m) if m.contains("enough approvals")));
+
+        let err = explain_merge_failure(GitxError::Status {
+            status: 405,
+            body: "{\"message\":\"Pull Request is not mergeable\"}".to_string(),
+        });
+        assert_eq!(err.to_string(), "Merge blocked: Pull Request is not mergeable (HTTP 405)");
+
+        let err = explain_merge_failure(GitxError::Status { status: 500, body: "oops".to_string() });
+        assert!(matches!(err, GitxError::Status { status: 500, .. }));
+        let err = explain_merge_failure(GitxError::Auth);
+        assert!(matches!(err, GitxError::Auth));
+    }
+
+    #[test]
+    fn strip_wip_prefix_handles_variants() {
+        assert_eq!(strip_wip_prefix("WIP: Add feature"), "Add feature");
+        assert_eq!(strip_wip_prefix("wip:Add feature"), "Add feature");
+        assert_eq!(strip_wip_prefix("[WIP] Add feature"), "Add feature");
+        assert_eq!(strip_wip_prefix("Add feature"), "Add feature");
+    }
+
+    fn check(name: &str, state: CiState) -> CiCheck {
+        CiCheck { name: name.to_string(), state }
+    }
+
+    #[test]
+    fn merge_check_reports_blockers() {
+        let pr = serde_json::json!({
+            "number": 7, "title": "Add feature", "state": "open", "mergeable": true,
+        });
+        let reviews = vec![
+            serde_json::json!({"user": {"login": "alice"}, "state": "APPROVED"}),
+            serde_json::json!({"user": {"login": "bob"}, "state": "REQUEST_CHANGES"}),
+            serde_json::json!({"user": {"login": "bob"}, "state": "COMMENT"}),
+        ];
+        let rules = MergeRules { required_approvals: 2, ..MergeRules::default() };
+        let ci = [check("build", CiState::Pending)];
+        let out = format_merge_check(&pr, Some(&reviews), Some(&rules), Some(&ci), Some(3));
+        assert!(out.contains("**Ready to merge:** no"));
+        assert!(out.contains("**Blockers:** 1 required approval missing; changes requested by bob; CI pending"));
+        assert!(out.contains("- **Approvals:** 1 of 2 required"));
+        assert!(out.contains("- **Up to date with base:** no (behind by 3)"));
+    }
+
+    #[test]
+    fn merge_check_ready_when_clear() {
+        let pr = serde_json::json!({"number": 7, "title": "Fix", "state": "open", "mergeable": true});
+        let out = format_merge_check(&pr, Some(&[]), Some(&MergeRules::default()), Some(&[]), Some(0));
+        assert!(out.contains("**Ready to merge:** yes\n**Blockers:** none"));
+        assert!(out.contains("- **CI:** no checks reported"));
+    }
+
+    #[test]
+    fn merge_check_unknown_when_inputs_missing() {
+        let pr = serde_json::json!({"number": 7, "title": "Fix", "state": "open", "mergeable": true});
+        let out = format_merge_check(&pr, Some(&[]), None, Some(&[]), Some(0));
+        assert!(out.contains("**Ready to merge:** unknown (branch protection could not be read)"), "{out}");
+        assert!(out.contains("- **Approvals:** 0 (required count unknown)"));
+
+        let out = format_merge_check(&pr, None, Some(&MergeRules::default()), None, Some(0));
+        assert!(out.contains("**Ready to merge:** unknown (reviews could not be read; CI results could not be read)"), "{out}");
+    }
+
+    #[test]
+    fn merge_check_requires_protected_checks() {
+        let pr = serde_json::json!({"number": 7, "title": "Fix", "state": "open", "mergeable": true});
+        let rules = MergeRules { required_checks: vec!["build".to_string(), "lint".to_string()], ..MergeRules::default() };
+        let out = format_merge_check(&pr, Some(&[]), Some(&rules), Some(&[check("build", CiState::Success)]), Some(0));
+        assert!(out.contains("**Ready to merge:** no"));
+        assert!(out.contains("- **Required checks not reported:** lint"));
+    }
+
+    #[test]
+    fn ci_checks_include_check_runs() {
+        // A repo on GitHub Actions only: no commit statuses, a failing check run.
+        let status = serde_json::json!({"state": "pending", "total_count": 0, "statuses": []});
+        let runs = serde_json::json!({"check_runs": [
+            {"name": "test", "status": "completed", "conclusion": "failure"},
+            {"name": "lint", "status": "completed", "conclusion": "skipped"},
+            {"name": "e2e", "status": "in_progress", "conclusion": null},
+        ]});
+        assert_eq!(
+            ci_checks(&status, Some(&runs)),
+            vec![check("test", CiState::Failure), check("lint", CiState::Success), check("e2e", CiState::Pending)]
+        );
+        let pr = serde_json::json!({"number": 7, "title": "Fix", "state": "open", "mergeable": true});
+        let out = format_merge_check(&pr, Some(&[]), Some(&MergeRules::default()), Some(&ci_checks(&status, Some(&runs))), Some(0));
+        assert!(out.contains("**Ready to merge:** no"));
+        assert!(out.contains("- **CI failing:** test"));
+
+        let gitea = serde_json::json!({"statuses": [{"context": "ci/build", "status": "success"}]});
+        assert_eq!(ci_checks(&gitea, None), vec![check("ci/build", CiState::Success)]);
+    }
+
+    #[test]
+    fn latest_review_overrides_earlier_decision() {
+        let reviews = vec![
+            serde_json::json!({"user": {"login": "alice"}, "state": "CHANGES_REQUESTED"}),
+            serde_json::json!({"user": {"login": "alice"}, "state": "APPROVED"}),
+            serde_json::json!({"user": {"login": "carol"}, "state": "APPROVED", "stale": true}),
+        ];
+        let (approved, changes) = latest_review_verdicts(&reviews);
+        assert_eq!(approved, vec!["alice"]);
+        assert!(changes.is_empty());
+    }
+
+    #[test]
+    fn merge_rules_read_per_platform() {
+        let gitea = serde_json::json!({
+            "required_approvals": 2, "block_on_outdated_branch": true,
+            "enable_status_check": true, "status_check_contexts": ["ci/build"],
+        });
+        assert_eq!(
+            MergeRules::from_protection(Platform::Gitea, &gitea),
+            MergeRules { required_approvals: 2, require_up_to_date: true, required_checks: vec!["ci/build".to_string()] }
+        );
+        let github = serde_json::json!({
+            "required_pull_request_reviews": {"required_approving_review_count": 1},
+            "required_status_checks": {"strict": false, "contexts": ["test"]},
+        });
+        assert_eq!(
+            MergeRules::from_protection(Platform::GitHub, &github),
+            MergeRules { required_approvals: 1, require_up_to_date: false, required_checks: vec!["test".to_string()] }
+        );
+    }
+}
@@ -0,0 +1,179 @@
+use rmcp::model::{CallToolResult, Content};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::client::GitClient;
+use crate::error::{GitxError, Result};
+use crate::repo_resolver::RepoInfo;
+use crate::server::resolve_owner_repo;
+
+/// Reaction contents accepted by both GitHub and Gitea.
+const REACTIONS: &[&str] = &["+1", "-1", "laugh", "confused", "heart", "hooray", "rocket", "eyes"];
+
+#[derive(Debug, Clone, Copy, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ReactionTarget {
+    /// An issue or pull request, addressed by its number.
+    Issue,
+    /// An issue or pull request comment, addressed by its comment ID.
+    Comment,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ReactionAddParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// What to react to: "issue" (issue or PR number) or "comment" (comment ID).
+    pub target_type: ReactionTarget,
+    /// Issue/PR number or comment ID, depending on `target_type`.
+    pub id: i64,
+    /// Reaction: "+1", "-1", "laugh", "confused", "heart", "hooray", "rocket", or "eyes".
+    pub content: String,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ReactionListParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// What to read reactions from: "issue" (issue or PR number) or "comment" (comment ID).
+    pub target_type: ReactionTarget,
+    /// Issue/PR number or comment ID, depending on `target_type`.
+    pub id: i64,
+}
+
+fn reactions_path(owner: &str, repo: &str, target: ReactionTarget, id: i64) -> String {
+    match target {
+        ReactionTarget::Issue => format!("/repos/{owner}/{repo}/issues/{id}/reactions"),
+        ReactionTarget::Comment => format!("/repos/{owner}/{repo}/issues/comments/{id}/reactions"),
+    }
+}
+
+pub async fn reaction_add(
+    client: &dyn GitClient,
+    params: ReactionAddParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+
+    check_reaction(&params.content)?;
+
+    client
+        .post_json(
+            &reactions_path(&owner, &repo, params.target_type, params.id),
+            &serde_json::json!({ "content": params.content }),
+        )
+        .await?;
+
+    let target = match params.target_type {
+        ReactionTarget::Issue => format!("issue/PR #{}", params.id),
+        ReactionTarget::Comment => format!("comment #{}", params.id),
+    };
+
+    Ok(CallToolResult::success(vec![Content::text(format!(
+        "Reacted with {} on {target}",
+        params.content
+    ))]))
+}
+
+pub async fn reaction_list(
+    client: &dyn GitClient,
+    params: ReactionListParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let val = client
+        .get_json(&reactions_path(&owner, &repo, params.target_type, params.id))
+        .await?;
+    let reactions = val.as_array().cloned().unwrap_or_default();
+
+    Ok(CallToolResult::success(vec![Content::text(
+        format_reaction_summary(&reactions),
+    )]))
+}
+
+/// Group reactions by content: `- +1 ×2 (alice, bob)`.
+fn format_reaction_summary(reactions: &[serde_json::Value]) -> String {
+    if reactions.is_empty() {
+        return "No reactions found.".to_string();
+    }
+
+    let mut groups: Vec<(&str, Vec<&str>)> = Vec::new();
+    for r in reactions {
+        let content = r.get("content").and_then(|v| v.as_str()).unwrap_or("?");
+        let user = r
+            .get("user")
+            .and_then(|v| v.get("login"))
+            .and_then(|v| v.as_str())
+            .unwrap_or("?");
+        match groups.iter_mut().find(|(c, _)| *c == content) {
+            Some((_, users)) => users.push(user),
+            None => groups.push((content, vec![user])),
+        }
+    }
+
+    groups
+        .iter()
+        .map(|(content, users)| format!("- {content} ×{} ({})", users.len(), users.join(", ")))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Refuse a `content` outside the reactions both platforms accept.
+fn check_reaction(content: &str) -> Result<()> {
+    if !REACTIONS.contains(&content) {
+        return Err(GitxError::InvalidParam(format!(
+            "content must be one of: {} (got \"{content}\")",
+            REACTIONS.join(", ")
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reaction_summary_groups_by_content() {
+        let reactions = vec![
+            serde_json::json!({ "content": "+1", "user": { "login": "alice" } }),
+            serde_json::json!({ "content": "heart", "user": { "login": "bob" } }),
+            serde_json::json!({ "content": "+1", "user": { "login": "carol" } }),
+        ];
+        assert_eq!(
+            format_reaction_summary(&reactions),
+            "- +1 ×2 (alice, carol)\n- heart ×1 (bob)"
+        );
+        assert_eq!(format_reaction_summary(&[]), "No reactions found.");
+    }
+
+    #[test]
+    fn unknown_reaction_is_invalid() {
+        assert!(check_reaction("heart").is_ok());
+        assert!(matches!(check_reaction("thumbsup"), Err(GitxError::InvalidParam(_))));
+    }
+
+    #[test]
+    fn reaction_paths() {
+        assert_eq!(
+            reactions_path("o", "r", ReactionTarget::Issue, 5),
+            "/repos/o/r/issues/5/reactions"
+        );
+        assert_eq!(
+            reactions_path("o", "r", ReactionTarget::Comment, 99),
+            "/repos/o/r/issues/comments/99/reactions"
+        );
+    }
+}
@@ -6,7 +6,8 @@ use crate::client::GitClient;
 use crate::error::Result;
 use crate::response;
 use crate::repo_resolver::RepoInfo;
-use crate::server::resolve_owner_repo;
+use crate::server::{resolve_owner_repo, InstanceOverride};
+use crate::types::common::{query_refs, QueryBuilder};
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct ReleaseListParams {
@@ -16,6 +17,11 @@ pub struct ReleaseListParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Query a different instance (or with a different token) for this call only.
+    #[serde(flatten)]
+    pub instance: InstanceOverride,
     /// Page number (1-based). Defaults to 1.
     pub page: Option<i64>,
     /// Items per page (max 50). Defaults to 20.
@@ -30,8 +36,24 @@ pub struct ReleaseGetParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
     /// Release ID (from release_list).
     pub id: i64,
+    /// Output format: "markdown" (default) or "json" for the raw API object.
+    pub format: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ReleaseLatestParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -42,6 +64,8 @@ pub struct ReleaseCreateParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
     /// Tag name for the release.
     pub tag_name: String,
     /// Release title.
@@ -61,14 +85,10 @@ pub async fn release_list(
     params: ReleaseListParams,
     default_repo: Option<&RepoInfo>,
 ) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
-    let mut query: Vec<(&str, String)> = Vec::new();
-    query.push(("page", params.page.unwrap_or(1).to_string()));
-    query.push(("limit", params.limit.unwrap_or(20).min(50).to_string()));
-
-    let query_refs: Vec<(&str, &str)> = query.iter().map(|(k, v)| (*k, v.as_str())).collect();
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let query = QueryBuilder::for_client(client).page(params.page).limit(params.limit).build();
     let val = client
-        .get_json_with_query(&format!("/repos/{owner}/{repo}/releases"), &query_refs)
+        .get_json_with_query(&format!("/repos/{owner}/{repo}/releases"), &query_refs(&query))
         .await?;
     let releases = val.as_array().cloned().unwrap_or_default();
 
@@ -118,13 +138,28 @@ pub async fn release_get(
     params: ReleaseGetParams,
     default_repo: Option<&RepoInfo>,
 ) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
     let release = client
         .get_json(&format!("/repos/{owner}/{repo}/releases/{}", params.id))
         .await?;
 
     Ok(CallToolResult::success(vec![Content::text(
-        response::format_value(&release),
+        response::render(&release, params.format.as_deref(), |v| response::format_value(v, client.settings())),
+    )]))
+}
+
+pub async fn release_latest(
+    client: &dyn GitClient,
+    params: ReleaseLatestParams,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let release = client
+        .get_json(&format!("/repos/{owner}/{repo}/releases/latest"))
+        .await?;
+
+    Ok(CallToolResult::success(vec![Content::text(
+        response::format_value(&release, client.settings()),
     )]))
 }
 
@@ -133,7 +168,7 @@ pub async fn release_create(
     params: ReleaseCreateParams,
     default_repo: Option<&RepoInfo>,
 ) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
     let mut body = serde_json::json!({ "tag_name": params.tag_name });
 
     if let Some(name) = &params.name {
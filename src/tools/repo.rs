@@ -3,9 +3,12 @@ use schemars::JsonSchema;
 use serde::Deserialize;
 
 use crate::client::GitClient;
-use crate::error::Result;
+use crate::error::{GitxError, Result};
+use crate::platform::Platform;
+use crate::response;
 use crate::repo_resolver::RepoInfo;
-use crate::server::resolve_owner_repo;
+use crate::server::{resolve_owner_repo, InstanceOverride};
+use crate::types::common::{query_refs, QueryBuilder};
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct RepoGetParams {
@@ -15,6 +18,13 @@ pub struct RepoGetParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Query a different instance (or with a different token) for this call only.
+    #[serde(flatten)]
+    pub instance: InstanceOverride,
+    /// Output format: "markdown" (default) or "json" for the raw API object.
+    pub format: Option<String>,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -27,12 +37,202 @@ pub struct RepoSearchParams {
     pub limit: Option<i64>,
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RepoListParams {
+    /// User or organization whose repositories to list. Defaults to the authenticated user.
+    pub owner: Option<String>,
+    /// Page number (1-based). Defaults to 1.
+    pub page: Option<i64>,
+    /// Items per page (max 50). Defaults to 20.
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RepoEditParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// New repository description.
+    pub description: Option<String>,
+    /// New default branch (must already exist).
+    pub default_branch: Option<String>,
+    /// Make the repository private (true) or public (false).
+    pub private: Option<bool>,
+    /// Enable or disable the issue tracker.
+    pub has_issues: Option<bool>,
+    /// Enable or disable the wiki.
+    pub has_wiki: Option<bool>,
+    /// Archive (true) or unarchive (false) the repository.
+    pub archived: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RepoTransferParams {
+    /// Current repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// User or organization to transfer the repository to.
+    pub new_owner: String,
+    /// IDs of teams in the new organization to give access (Gitea only; see org_teams).
+    pub team_ids: Option<Vec<i64>>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RepoArchiveParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RepoWatchParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Ignore the repository instead of watching it: no notifications at all, not even
+    /// for mentions (GitHub only). Defaults to false.
+    pub ignored: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RepoUnwatchParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RepoStarParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RepoTopicsGetParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RepoLanguagesParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RepoUserListParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Page number (1-based). Defaults to 1.
+    pub page: Option<i64>,
+    /// Items per page (max 50). Defaults to 30.
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RepoTopicsSetParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Complete list of topics. Replaces all existing topics; pass an empty list to clear them.
+    pub topics: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RepoForkParams {
+    /// Owner of the repository to fork. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Name of the repository to fork. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Organization to fork into. Defaults to the authenticated user's account.
+    pub organization: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ForkSyncParams {
+    /// Owner of the fork. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Name of the fork. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Branch of the fork to update from the same branch of its parent. Defaults to the fork's default branch.
+    pub branch: Option<String>,
+}
+
 pub async fn repo_get(client: &dyn GitClient, params: RepoGetParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
     let repo_info = client
         .get_json(&format!("/repos/{owner}/{repo}"))
         .await?;
 
+    Ok(CallToolResult::success(vec![Content::text(
+        response::render(&repo_info, params.format.as_deref(), format_repo),
+    )]))
+}
+
+/// Format repository metadata as used by `repo_get` and `repo_edit`.
+fn format_repo(repo_info: &serde_json::Value) -> String {
     let mut parts = Vec::new();
 
     let full_name = repo_info
@@ -81,9 +281,11 @@ pub async fn repo_get(client: &dyn GitClient, params: RepoGetParams, default_rep
         }
     }
 
-    Ok(CallToolResult::success(vec![Content::text(
-        parts.join("\n"),
-    )]))
+    if repo_info.get("archived").and_then(|v| v.as_bool()).unwrap_or(false) {
+        parts.push("**Archived:** yes".to_string());
+    }
+
+    parts.join("\n")
 }
 
 pub async fn repo_search(
@@ -92,24 +294,19 @@ pub async fn repo_search(
 ) -> Result<CallToolResult> {
     use crate::platform::Platform;
 
-    let mut query: Vec<(&str, String)> = Vec::new();
-    query.push(("q", params.q.clone()));
-    query.push(("page", params.page.unwrap_or(1).to_string()));
-
-    let (path, wrapper_key, stars_key) = match client.platform() {
-        Platform::Gitea => {
-            query.push(("limit", params.limit.unwrap_or(20).min(50).to_string()));
-            ("/repos/search", "data", "stars_count")
-        }
-        Platform::GitHub => {
-            query.push(("per_page", params.limit.unwrap_or(20).min(50).to_string()));
-            ("/search/repositories", "items", "stargazers_count")
-        }
+    let (path, wrapper_key) = match client.platform() {
+        Platform::Gitea => ("/repos/search", "data"),
+        Platform::GitLab => return Err(crate::client::gitlab_unsupported("repo_search")),
+        Platform::GitHub => ("/search/repositories", "items"),
     };
+    let query = QueryBuilder::for_client(client)
+        .param("q", &params.q)
+        .page(params.page)
+        .limit(params.limit)
+        .build();
 
-    let query_refs: Vec<(&str, &str)> = query.iter().map(|(k, v)| (*k, v.as_str())).collect();
     let result = client
-        .get_json_with_query(path, &query_refs)
+        .get_json_with_query(path, &query_refs(&query))
         .await?;
 
     let repos = result
@@ -124,30 +321,546 @@ pub async fn repo_search(
         )]));
     }
 
-    let formatted: Vec<String> = repos
-        .iter()
-        .map(|r| {
-            let full_name = r
-                .get("full_name")
-                .and_then(|v| v.as_str())
-                .unwrap_or("?");
-            let desc = r
-                .get("description")
-                .and_then(|v| v.as_str())
-                .unwrap_or("");
-            let stars = r
-                .get(stars_key)
-                .and_then(|v| v.as_i64())
-                .unwrap_or(0);
-            if desc.is_empty() {
-                format!("- {full_name} ({stars} stars)")
-            } else {
-                format!("- {full_name} ({stars} stars) - {desc}")
+    let formatted: Vec<String> = repos.iter().map(response::format_repo_line).collect();
+
+    Ok(CallToolResult::success(vec![Content::text(
+        formatted.join("\n"),
+    )]))
+}
+
+pub async fn repo_list(client: &dyn GitClient, params: RepoListParams) -> Result<CallToolResult> {
+    let query = QueryBuilder::for_client(client).page(params.page).limit(params.limit).build();
+    let query_refs = query_refs(&query);
+
+    let val = match &params.owner {
+        None => client.get_json_with_query("/user/repos", &query_refs).await?,
+        // Try the org endpoint first; fall back to the user endpoint for personal accounts.
+        Some(owner) => match client
+            .get_json_with_query(&format!("/orgs/{owner}/repos"), &query_refs)
+            .await
+        {
+            Ok(v) => v,
+            Err(crate::error::GitxError::NotFound(_)) => {
+                client
+                    .get_json_with_query(&format!("/users/{owner}/repos"), &query_refs)
+                    .await?
             }
-        })
+            Err(e) => return Err(e),
+        },
+    };
+    let repos = val.as_array().cloned().unwrap_or_default();
+
+    if repos.is_empty() {
+        return Ok(CallToolResult::success(vec![Content::text(
+            "No repositories found.",
+        )]));
+    }
+
+    let formatted: Vec<String> = repos.iter().map(response::format_repo_line).collect();
+
+    Ok(CallToolResult::success(vec![Content::text(
+        formatted.join("\n"),
+    )]))
+}
+
+pub async fn repo_fork(client: &dyn GitClient, params: RepoForkParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let mut body = serde_json::json!({});
+
+    if let Some(org) = &params.organization {
+        body["organization"] = serde_json::Value::String(org.clone());
+    }
+
+    let fork = client
+        .post_json(&format!("/repos/{owner}/{repo}/forks"), &body)
+        .await?;
+
+    let full_name = fork
+        .get("full_name")
+        .and_then(|v| v.as_str())
+        .unwrap_or("unknown");
+
+    Ok(CallToolResult::success(vec![Content::text(format!(
+        "Forked {owner}/{repo} to {full_name}"
+    ))]))
+}
+
+pub async fn fork_sync(client: &dyn GitClient, params: ForkSyncParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let repo_info = client.get_json(&format!("/repos/{owner}/{repo}")).await?;
+    let Some(parent) = repo_info.get("parent").and_then(|p| p.get("full_name")).and_then(|v| v.as_str()) else {
+        return Ok(CallToolResult::success(vec![Content::text(format!(
+            "{owner}/{repo} is not a fork, so there is no upstream to sync from."
+        ))]));
+    };
+    let branch = match &params.branch {
+        Some(branch) => branch.clone(),
+        None => repo_info
+            .get("default_branch")
+            .and_then(|v| v.as_str())
+            .unwrap_or("main")
+            .to_string(),
+    };
+
+    let result = match client.platform() {
+        Platform::Gitea => client
+            .post_json(&format!("/repos/{owner}/{repo}/sync_fork/{branch}"), &serde_json::json!({}))
+            .await
+            .map(|_| None),
+        Platform::GitLab => return Err(crate::client::gitlab_unsupported("fork_sync")),
+        Platform::GitHub => client
+            .post_json(&format!("/repos/{owner}/{repo}/merge-upstream"), &serde_json::json!({ "branch": branch }))
+            .await
+            .map(|val| val.get("merge_type").and_then(|v| v.as_str()).map(String::from)),
+    };
+
+    let text = match result {
+        Ok(merge_type) => format_fork_sync(&owner, &repo, &branch, parent, merge_type.as_deref()),
+        Err(GitxError::Status { status: 409, .. }) => format!(
+            "Could not sync {owner}/{repo}:{branch} with {parent}: the branches have conflicting changes. \
+             Merge upstream into a local clone, resolve the conflicts, and push."
+        ),
+        // The fork itself was just fetched, so a 404 here means the endpoint or branch is missing.
+        Err(GitxError::NotFound(_)) if client.platform() == Platform::Gitea => format!(
+            "Could not sync {owner}/{repo}:{branch}: this instance has no sync_fork API (it needs a recent \
+             Gitea or Forgejo) or the branch does not exist. Update the fork from a local clone instead: \
+             fetch {parent}, merge its {branch} branch, and push."
+        ),
+        Err(e) => return Err(e),
+    };
+    Ok(CallToolResult::success(vec![Content::text(text)]))
+}
+
+/// Describe a successful sync. `merge_type` is GitHub's "fast-forward", "merge", or "none";
+/// Gitea does not report one.
+fn format_fork_sync(owner: &str, repo: &str, branch: &str, parent: &str, merge_type: Option<&str>) -> String {
+    match merge_type {
+        Some("fast-forward") => format!("Fast-forwarded {owner}/{repo}:{branch} to {parent}:{branch}."),
+        Some("merge") => format!("Merged {parent}:{branch} into {owner}/{repo}:{branch} with a merge commit."),
+        Some("none") => format!("{owner}/{repo}:{branch} is already up to date with {parent}:{branch}."),
+        _ => format!("Synced {owner}/{repo}:{branch} with {parent}:{branch}."),
+    }
+}
+
+pub async fn repo_edit(client: &dyn GitClient, params: RepoEditParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let mut body = serde_json::json!({});
+
+    if let Some(desc) = &params.description {
+        body["description"] = serde_json::Value::String(desc.clone());
+    }
+    if let Some(branch) = &params.default_branch {
+        body["default_branch"] = serde_json::Value::String(branch.clone());
+    }
+    if let Some(private) = params.private {
+        body["private"] = serde_json::Value::Bool(private);
+    }
+    if let Some(has_issues) = params.has_issues {
+        body["has_issues"] = serde_json::Value::Bool(has_issues);
+    }
+    if let Some(has_wiki) = params.has_wiki {
+        body["has_wiki"] = serde_json::Value::Bool(has_wiki);
+    }
+    if let Some(archived) = params.archived {
+        body["archived"] = serde_json::Value::Bool(archived);
+    }
+
+    let repo_info = client
+        .patch_json(&format!("/repos/{owner}/{repo}"), &body)
+        .await?;
+    if params.default_branch.is_some() {
+        client.forget_default_branch(&owner, &repo);
+    }
+
+    Ok(CallToolResult::success(vec![Content::text(format!(
+        "Repository updated.\n\n{}",
+        format_repo(&repo_info)
+    ))]))
+}
+
+pub async fn repo_transfer(client: &dyn GitClient, params: RepoTransferParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let mut body = serde_json::json!({ "new_owner": params.new_owner });
+    if let (Some(team_ids), Platform::Gitea) = (&params.team_ids, client.platform()) {
+        body["team_ids"] = serde_json::json!(team_ids);
+    }
+
+    let repo_info = client
+        .post_json(&format!("/repos/{owner}/{repo}/transfer"), &body)
+        .await?;
+
+    Ok(CallToolResult::success(vec![Content::text(
+        format_transfer(&owner, &repo, &params.new_owner, &repo_info),
+    )]))
+}
+
+/// Describe a transfer response. The returned repository only sits under the
+/// new owner once the transfer is done; while the recipient still has to
+/// accept it (or GitHub is still processing it) it keeps the old owner.
+fn format_transfer(owner: &str, repo: &str, new_owner: &str, repo_info: &serde_json::Value) -> String {
+    let current_owner = repo_info
+        .get("owner")
+        .and_then(|o| o.get("login"))
+        .and_then(|v| v.as_str())
+        .unwrap_or(owner);
+    if current_owner.eq_ignore_ascii_case(new_owner) {
+        let full_name = repo_info
+            .get("full_name")
+            .and_then(|v| v.as_str())
+            .map(String::from)
+            .unwrap_or_else(|| format!("{new_owner}/{repo}"));
+        format!("Transferred {owner}/{repo} to {full_name}.")
+    } else {
+        format!(
+            "Transfer of {owner}/{repo} to {new_owner} is pending. {new_owner} must accept it \
+             (or the server is still processing it); until then the repository stays at {owner}/{repo}."
+        )
+    }
+}
+
+pub async fn repo_archive(client: &dyn GitClient, params: RepoArchiveParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
+    set_archived(client, params, true, default_repo).await
+}
+
+pub async fn repo_unarchive(client: &dyn GitClient, params: RepoArchiveParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
+    set_archived(client, params, false, default_repo).await
+}
+
+/// PATCH the repository's `archived` flag and report the state the server returns.
+async fn set_archived(
+    client: &dyn GitClient,
+    params: RepoArchiveParams,
+    archived: bool,
+    default_repo: Option<&RepoInfo>,
+) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let repo_info = client
+        .patch_json(
+            &format!("/repos/{owner}/{repo}"),
+            &serde_json::json!({ "archived": archived }),
+        )
+        .await?;
+
+    Ok(CallToolResult::success(vec![Content::text(
+        format_archived_state(&owner, &repo, &repo_info, archived),
+    )]))
+}
+
+/// Report the `archived` flag from the edit response, falling back to the requested value.
+fn format_archived_state(owner: &str, repo: &str, repo_info: &serde_json::Value, requested: bool) -> String {
+    let archived = repo_info
+        .get("archived")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(requested);
+    let state = if archived { "archived (read-only)" } else { "unarchived" };
+    let mut text = format!("Repository {owner}/{repo} is now {state}.");
+    if archived != requested {
+        text.push_str(" The server did not apply the requested change.");
+    }
+    text
+}
+
+pub async fn repo_watch(client: &dyn GitClient, params: RepoWatchParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
+    let ignored = params.ignored.unwrap_or(false);
+    if ignored && client.platform() == Platform::Gitea {
+        return Ok(CallToolResult::success(vec![Content::text(
+            "Ignoring a repository is not available on Gitea. Use repo_unwatch to stop watching it instead.",
+        )]));
+    }
+
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    // Gitea ignores the body and always subscribes; GitHub reads both flags.
+    let subscription = client
+        .put_json(
+            &format!("/repos/{owner}/{repo}/subscription"),
+            &serde_json::json!({ "subscribed": !ignored, "ignored": ignored }),
+        )
+        .await?;
+
+    Ok(CallToolResult::success(vec![Content::text(
+        format_subscription(&owner, &repo, &subscription),
+    )]))
+}
+
+pub async fn repo_unwatch(client: &dyn GitClient, params: RepoUnwatchParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    client
+        .delete(&format!("/repos/{owner}/{repo}/subscription"))
+        .await?;
+
+    Ok(CallToolResult::success(vec![Content::text(format!(
+        "Not watching {owner}/{repo}. You will only get notifications for threads you participate in or are mentioned in."
+    ))]))
+}
+
+/// Describe a `/subscription` response: watching, ignoring, or neither.
+fn format_subscription(owner: &str, repo: &str, subscription: &serde_json::Value) -> String {
+    let flag = |key: &str| subscription.get(key).and_then(|v| v.as_bool()).unwrap_or(false);
+    if flag("ignored") {
+        format!("Ignoring {owner}/{repo}. You will not get any notifications from it.")
+    } else if flag("subscribed") {
+        format!("Watching {owner}/{repo}. You will get notifications for all its activity.")
+    } else {
+        format!("Not watching {owner}/{repo}.")
+    }
+}
+
+pub async fn repo_star(client: &dyn GitClient, params: RepoStarParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    // Both platforms answer 204 with no body; put_json maps that to Null.
+    client
+        .put_json(&format!("/user/starred/{owner}/{repo}"), &serde_json::json!({}))
+        .await?;
+
+    Ok(CallToolResult::success(vec![Content::text(format!(
+        "Starred {owner}/{repo}."
+    ))]))
+}
+
+pub async fn repo_unstar(client: &dyn GitClient, params: RepoStarParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    client
+        .delete(&format!("/user/starred/{owner}/{repo}"))
+        .await?;
+
+    Ok(CallToolResult::success(vec![Content::text(format!(
+        "Unstarred {owner}/{repo}."
+    ))]))
+}
+
+pub async fn repo_topics_get(client: &dyn GitClient, params: RepoTopicsGetParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let topics = fetch_topics(client, &owner, &repo).await?;
+
+    Ok(CallToolResult::success(vec![Content::text(format_topics(&owner, &repo, &topics))]))
+}
+
+pub async fn repo_topics_set(client: &dyn GitClient, params: RepoTopicsSetParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+
+    let names: Vec<String> = params.topics.iter().map(|t| t.trim().to_string()).collect();
+    if let Some(bad) = names.iter().find(|t| !is_valid_topic(t)) {
+        return Err(GitxError::InvalidParam(format!(
+            "topics: \"{bad}\" is invalid; topics must be lowercase letters, digits, and hyphens, \
+             start with a letter or digit, and be at most 35 characters"
+        )));
+    }
+
+    // Gitea names the field `topics`, GitHub names it `names`.
+    let body = match client.platform() {
+        Platform::Gitea => serde_json::json!({ "topics": names }),
+        Platform::GitHub | Platform::GitLab => serde_json::json!({ "names": names }),
+    };
+    client
+        .put_json(&format!("/repos/{owner}/{repo}/topics"), &body)
+        .await?;
+
+    // Gitea answers 204 with no body, so read the list back on both platforms.
+    let topics = fetch_topics(client, &owner, &repo).await?;
+
+    Ok(CallToolResult::success(vec![Content::text(format!(
+        "Topics updated.\n\n{}",
+        format_topics(&owner, &repo, &topics)
+    ))]))
+}
+
+async fn fetch_topics(client: &dyn GitClient, owner: &str, repo: &str) -> Result<Vec<String>> {
+    let val = client
+        .get_json(&format!("/repos/{owner}/{repo}/topics"))
+        .await?;
+    Ok(topics_from_response(&val))
+}
+
+/// Extract the topic list from either platform's response
+/// (`{"topics": [...]}` on Gitea, `{"names": [...]}` on GitHub).
+fn topics_from_response(val: &serde_json::Value) -> Vec<String> {
+    val.get("topics")
+        .or_else(|| val.get("names"))
+        .and_then(|v| v.as_array())
+        .map(|arr| arr.iter().filter_map(|t| t.as_str().map(String::from)).collect())
+        .unwrap_or_default()
+}
+
+fn format_topics(owner: &str, repo: &str, topics: &[String]) -> String {
+    if topics.is_empty() {
+        return format!("No topics set on {owner}/{repo}.");
+    }
+    format!("**Topics ({owner}/{repo}):** {}", topics.join(", "))
+}
+
+/// Topic names accepted by both platforms: lowercase alphanumerics and
+/// hyphens, starting with an alphanumeric, at most 35 characters (Gitea's
+/// limit, which is stricter than GitHub's 50).
+fn is_valid_topic(topic: &str) -> bool {
+    !topic.is_empty()
+        && topic.len() <= 35
+        && topic
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+        && topic
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+}
+
+pub async fn repo_languages(client: &dyn GitClient, params: RepoLanguagesParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let languages = client
+        .get_json(&format!("/repos/{owner}/{repo}/languages"))
+        .await?;
+
+    Ok(CallToolResult::success(vec![Content::text(format_languages(&languages))]))
+}
+
+pub async fn repo_stargazers(client: &dyn GitClient, params: RepoUserListParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
+    list_repo_users(client, params, default_repo, "stargazers").await
+}
+
+pub async fn repo_subscribers(client: &dyn GitClient, params: RepoUserListParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
+    list_repo_users(client, params, default_repo, "subscribers").await
+}
+
+/// List the users behind `/repos/{owner}/{repo}/{endpoint}` as username bullets.
+async fn list_repo_users(
+    client: &dyn GitClient,
+    params: RepoUserListParams,
+    default_repo: Option<&RepoInfo>,
+    endpoint: &str,
+) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let query = QueryBuilder::for_client(client)
+        .page(params.page)
+        .limit(params.limit.or(Some(30)))
+        .build();
+
+    let val = client
+        .get_json_with_query(&format!("/repos/{owner}/{repo}/{endpoint}"), &query_refs(&query))
+        .await?;
+    let users = val.as_array().cloned().unwrap_or_default();
+
+    if users.is_empty() {
+        return Ok(CallToolResult::success(vec![Content::text(format!(
+            "No {endpoint} found."
+        ))]));
+    }
+
+    let formatted: Vec<String> = users
+        .iter()
+        .filter_map(|u| u.get("login").and_then(|v| v.as_str()))
+        .map(|login| format!("- {login}"))
         .collect();
 
     Ok(CallToolResult::success(vec![Content::text(
         formatted.join("\n"),
     )]))
 }
+
+/// Render a language → bytes map as a percentage breakdown, largest first.
+fn format_languages(languages: &serde_json::Value) -> String {
+    let mut entries: Vec<(&str, i64)> = languages
+        .as_object()
+        .map(|map| map.iter().filter_map(|(name, bytes)| Some((name.as_str(), bytes.as_i64()?))).collect())
+        .unwrap_or_default();
+    let total: i64 = entries.iter().map(|(_, bytes)| bytes).sum();
+    if total == 0 {
+        return "No languages detected.".to_string();
+    }
+
+    entries.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+    entries
+        .iter()
+        .map(|(name, bytes)| format!("- {name}: {:.1}% ({bytes} bytes)", *bytes as f64 * 100.0 / total as f64))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fork_sync_reports_merge_type() {
+        assert_eq!(
+            format_fork_sync("me", "widgets", "main", "acme/widgets", Some("fast-forward")),
+            "Fast-forwarded me/widgets:main to acme/widgets:main."
+        );
+        assert_eq!(
+            format_fork_sync("me", "widgets", "main", "acme/widgets", Some("none")),
+            "me/widgets:main is already up to date with acme/widgets:main."
+        );
+        assert_eq!(
+            format_fork_sync("me", "widgets", "dev", "acme/widgets", None),
+            "Synced me/widgets:dev with acme/widgets:dev."
+        );
+    }
+
+    #[test]
+    fn languages_sorted_by_share() {
+        let languages = serde_json::json!({ "Shell": 250, "Rust": 7000, "TOML": 2750 });
+        assert_eq!(
+            format_languages(&languages),
+            "- Rust: 70.0% (7000 bytes)\n- TOML: 27.5% (2750 bytes)\n- Shell: 2.5% (250 bytes)"
+        );
+        assert_eq!(format_languages(&serde_json::json!({})), "No languages detected.");
+    }
+
+    #[test]
+    fn topic_validation() {
+        assert!(is_valid_topic("rust"));
+        assert!(is_valid_topic("mcp-server"));
+        assert!(is_valid_topic("2024"));
+        assert!(!is_valid_topic(""));
+        assert!(!is_valid_topic("Rust"));
+        assert!(!is_valid_topic("-leading"));
+        assert!(!is_valid_topic("has space"));
+        assert!(!is_valid_topic("under_score"));
+        assert!(!is_valid_topic(&"a".repeat(36)));
+    }
+
+    #[test]
+    fn topics_from_either_platform() {
+        let gitea = serde_json::json!({ "topics": ["cli", "rust"] });
+        let github = serde_json::json!({ "names": ["cli", "rust"] });
+        assert_eq!(topics_from_response(&gitea), vec!["cli", "rust"]);
+        assert_eq!(topics_from_response(&github), vec!["cli", "rust"]);
+        assert!(topics_from_response(&serde_json::json!({})).is_empty());
+    }
+
+    #[test]
+    fn archived_state_reports_server_value() {
+        let archived = serde_json::json!({ "archived": true });
+        assert_eq!(
+            format_archived_state("acme", "old", &archived, true),
+            "Repository acme/old is now archived (read-only)."
+        );
+        assert_eq!(
+            format_archived_state("acme", "old", &archived, false),
+            "Repository acme/old is now archived (read-only). The server did not apply the requested change."
+        );
+        assert_eq!(
+            format_archived_state("acme", "old", &serde_json::json!({}), false),
+            "Repository acme/old is now unarchived."
+        );
+    }
+
+    #[test]
+    fn subscription_state_described() {
+        let watching = serde_json::json!({ "subscribed": true, "ignored": false });
+        assert_eq!(
+            format_subscription("acme", "app", &watching),
+            "Watching acme/app. You will get notifications for all its activity."
+        );
+        let ignoring = serde_json::json!({ "subscribed": false, "ignored": true });
+        assert!(format_subscription("acme", "app", &ignoring).starts_with("Ignoring acme/app."));
+        assert_eq!(format_subscription("acme", "app", &serde_json::json!({})), "Not watching acme/app.");
+    }
+
+    #[test]
+    fn transfer_reports_done_or_pending() {
+        let moved = serde_json::json!({ "full_name": "acme/tool", "owner": { "login": "acme" } });
+        assert_eq!(format_transfer("me", "tool", "acme", &moved), "Transferred me/tool to acme/tool.");
+
+        let pending = serde_json::json!({ "full_name": "me/tool", "owner": { "login": "me" } });
+        assert!(format_transfer("me", "tool", "bob", &pending).starts_with("Transfer of me/tool to bob is pending."));
+    }
+}
@@ -3,9 +3,13 @@ use schemars::JsonSchema;
 use serde::Deserialize;
 
 use crate::client::GitClient;
+use crate::config::ToolSettings;
 use crate::error::Result;
+use crate::platform::Platform;
 use crate::repo_resolver::RepoInfo;
+use crate::response;
 use crate::server::resolve_owner_repo;
+use crate::types::common::{query_refs, QueryBuilder};
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct TagListParams {
@@ -15,12 +19,28 @@ pub struct TagListParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
     /// Page number (1-based). Defaults to 1.
     pub page: Option<i64>,
     /// Items per page (max 50). Defaults to 20.
     pub limit: Option<i64>,
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct TagGetParams {
+    /// Repository owner. Optional if `directory` is provided.
+    pub owner: Option<String>,
+    /// Repository name. Optional if `directory` is provided.
+    pub repo: Option<String>,
+    /// Local directory to auto-detect owner/repo from .git/config.
+    pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
+    /// Tag name (e.g. "v1.2.0").
+    pub tag: String,
+}
+
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct TagCreateParams {
     /// Repository owner. Optional if `directory` is provided.
@@ -29,6 +49,8 @@ pub struct TagCreateParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
     /// Tag name.
     pub tag_name: String,
     /// Commit SHA or branch to tag.
@@ -38,14 +60,10 @@ pub struct TagCreateParams {
 }
 
 pub async fn tag_list(client: &dyn GitClient, params: TagListParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
-    let mut query: Vec<(&str, String)> = Vec::new();
-    query.push(("page", params.page.unwrap_or(1).to_string()));
-    query.push(("limit", params.limit.unwrap_or(20).min(50).to_string()));
-
-    let query_refs: Vec<(&str, &str)> = query.iter().map(|(k, v)| (*k, v.as_str())).collect();
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let query = QueryBuilder::for_client(client).page(params.page).limit(params.limit).build();
     let val = client
-        .get_json_with_query(&format!("/repos/{owner}/{repo}/tags"), &query_refs)
+        .get_json_with_query(&format!("/repos/{owner}/{repo}/tags"), &query_refs(&query))
         .await?;
     let tags = val.as_array().cloned().unwrap_or_default();
 
@@ -74,8 +92,86 @@ pub async fn tag_list(client: &dyn GitClient, params: TagListParams, default_rep
     )]))
 }
 
+pub async fn tag_get(client: &dyn GitClient, params: TagGetParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let str_at = |v: &serde_json::Value, a: &str, b: &str| v.get(a).and_then(|v| v.get(b)).and_then(|v| v.as_str()).map(String::from);
+
+    // Both platforms describe annotated tags with the same git tag object
+    // (tagger, message, object); they differ in how to find it.
+    let (target, tag_object) = match client.platform() {
+        Platform::Gitea => {
+            let tag = client
+                .get_json(&format!("/repos/{owner}/{repo}/tags/{}", params.tag))
+                .await?;
+            let target = str_at(&tag, "commit", "sha").unwrap_or_default();
+            // `id` is the tag object's SHA; it equals the commit for lightweight tags.
+            let tag_object = match tag.get("id").and_then(|v| v.as_str()) {
+                Some(id) if id != target => client
+                    .get_json(&format!("/repos/{owner}/{repo}/git/tags/{id}"))
+                    .await
+                    .ok(),
+                _ => None,
+            };
+            (target, tag_object)
+        }
+        Platform::GitLab => return Err(crate::client::gitlab_unsupported("tag_get")),
+        Platform::GitHub => {
+            let git_ref = client
+                .get_json(&format!("/repos/{owner}/{repo}/git/ref/tags/{}", params.tag))
+                .await?;
+            let sha = str_at(&git_ref, "object", "sha").unwrap_or_default();
+            if str_at(&git_ref, "object", "type").as_deref() == Some("tag") {
+                let tag_object = client
+                    .get_json(&format!("/repos/{owner}/{repo}/git/tags/{sha}"))
+                    .await?;
+                let target = str_at(&tag_object, "object", "sha").unwrap_or(sha);
+                (target, Some(tag_object))
+            } else {
+                (sha, None)
+            }
+        }
+    };
+
+    Ok(CallToolResult::success(vec![Content::text(format_tag(
+        &params.tag,
+        &target,
+        tag_object.as_ref(),
+        client.settings(),
+    ))]))
+}
+
+/// Format a tag; `tag_object` is the git tag object of an annotated tag.
+fn format_tag(name: &str, target: &str, tag_object: Option<&serde_json::Value>, settings: ToolSettings) -> String {
+    let mut parts = vec![format!("## Tag {name}"), format!("**Target commit:** {target}")];
+
+    let Some(tag) = tag_object else {
+        parts.push("**Type:** lightweight (no tagger or message)".to_string());
+        return parts.join("\n");
+    };
+
+    parts.push("**Type:** annotated".to_string());
+    let tagger = tag.get("tagger");
+    let field = |key: &str| tagger.and_then(|t| t.get(key)).and_then(|v| v.as_str());
+    match (field("name"), field("email")) {
+        (Some(name), Some(email)) => parts.push(format!("**Tagger:** {name} <{email}>")),
+        (Some(name), None) => parts.push(format!("**Tagger:** {name}")),
+        _ => {}
+    }
+    if let Some(date) = field("date") {
+        parts.push(format!("**Date:** {}", response::format_timestamp(date, settings)));
+    }
+    if let Some(verified) = tag.get("verification").and_then(|v| v.get("verified")).and_then(|v| v.as_bool()) {
+        parts.push(format!("**Signature:** {}", if verified { "verified" } else { "unverified" }));
+    }
+    let message = tag.get("message").and_then(|v| v.as_str()).unwrap_or("").trim();
+    if !message.is_empty() {
+        parts.push(format!("\n{message}"));
+    }
+    parts.join("\n")
+}
+
 pub async fn tag_create(client: &dyn GitClient, params: TagCreateParams, default_repo: Option<&RepoInfo>) -> Result<CallToolResult> {
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
     let mut body = serde_json::json!({ "tag_name": params.tag_name });
 
     if let Some(target) = &params.target {
@@ -98,3 +194,24 @@ pub async fn tag_create(client: &dyn GitClient, params: TagCreateParams, default
         "Tag created: {name}"
     ))]))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn annotated_tag_shows_tagger_and_message() {
+        let tag = serde_json::json!({
+            "tag": "v1.2.0",
+            "message": "Release 1.2.0\n",
+            "tagger": { "name": "Alice", "email": "alice@example.com", "date": "2024-05-01T10:00:00Z" },
+            "object": { "type": "commit", "sha": "abc123" },
+        });
+        let out = format_tag("v1.2.0", "abc123", Some(&tag), ToolSettings::default());
+        assert!(out.contains("**Target commit:** abc123"));
+        assert!(out.contains("**Tagger:** Alice <alice@example.com>"));
+        assert!(out.contains("**Date:** 2024-05-01T10:00:00Z"));
+        assert!(out.ends_with("\nRelease 1.2.0"));
+        assert!(format_tag("v1", "abc", None, ToolSettings::default()).contains("lightweight"));
+    }
+}
@@ -4,16 +4,97 @@ use serde::Deserialize;
 
 use crate::client::GitClient;
 use crate::error::Result;
+use crate::platform::Platform;
+use crate::response;
+use crate::types::common::{query_refs, QueryBuilder};
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct UserGetMeParams {}
 
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct StatusParams {}
+
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct UserGetParams {
     /// Username to look up.
     pub username: String,
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct UserReposParams {
+    /// Username whose public repositories to list.
+    pub username: String,
+    /// Page number (1-based). Defaults to 1.
+    pub page: Option<i64>,
+    /// Items per page (max 50). Defaults to 20.
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct UserStarredParams {
+    /// Page number (1-based). Defaults to 1.
+    pub page: Option<i64>,
+    /// Items per page (max 50). Defaults to 20.
+    pub limit: Option<i64>,
+}
+
+pub async fn status(client: &dyn GitClient, base_url: &str) -> Result<CallToolResult> {
+    let user = client.get_json("/user").await?;
+    let login = user.get("login").and_then(|v| v.as_str()).unwrap_or("unknown");
+
+    let platform = match client.platform() {
+        Platform::Gitea => "Gitea/Forgejo",
+        Platform::GitHub => "GitHub",
+        Platform::GitLab => "GitLab",
+    };
+    let mut parts = vec![
+        format!("**Authenticated as:** {login}"),
+        format!("**Platform:** {platform}"),
+        format!("**Base URL:** {base_url}"),
+    ];
+
+    match client.platform() {
+        Platform::Gitea => {
+            if let Some(version) = client
+                .get_json("/version")
+                .await
+                .ok()
+                .and_then(|v| v.get("version").and_then(|v| v.as_str()).map(String::from))
+            {
+                parts.push(format!("**Server version:** {version}"));
+            }
+            parts.push("**Rate limit:** not reported (Gitea does not rate-limit API requests by default)".to_string());
+        }
+        Platform::GitLab => parts.push("**Rate limit:** not reported (not supported on GitLab yet)".to_string()),
+        Platform::GitHub => {
+            // /rate_limit doesn't count against the budget itself. GitHub
+            // Enterprise answers 404 when rate limiting is disabled.
+            let limits = client.get_json("/rate_limit").await.ok();
+            let core = limits
+                .as_ref()
+                .and_then(|l| l.get("resources").and_then(|r| r.get("core")).or_else(|| l.get("rate")));
+            match core.and_then(format_rate_limit) {
+                Some(line) => parts.push(line),
+                None => parts.push("**Rate limit:** unknown".to_string()),
+            }
+        }
+    }
+
+    Ok(CallToolResult::success(vec![Content::text(parts.join("\n"))]))
+}
+
+/// Render GitHub's `{limit, remaining, reset}` with the reset time in absolute and relative form.
+fn format_rate_limit(rate: &serde_json::Value) -> Option<String> {
+    let limit = rate.get("limit")?.as_i64()?;
+    let remaining = rate.get("remaining")?.as_i64()?;
+    let reset = rate.get("reset").and_then(|v| v.as_u64()).unwrap_or(0);
+    let reset_at = response::rfc3339_utc(reset);
+    Some(format!(
+        "**Rate limit:** {remaining}/{limit} requests remaining, resets at {reset_at} ({})",
+        response::format_relative_time(&reset_at)
+    ))
+}
+
 pub async fn user_get_me(client: &dyn GitClient) -> Result<CallToolResult> {
     let user = client.get_json("/user").await?;
 
@@ -67,11 +148,83 @@ pub async fn user_get(client: &dyn GitClient, params: UserGetParams) -> Result<C
         }
     }
 
-    if let Some(created) = user.get("created").and_then(|v| v.as_str()) {
+    if let Some(created) = user
+        .get("created")
+        .or_else(|| user.get("created_at"))
+        .and_then(|v| v.as_str())
+    {
         parts.push(format!("**Created:** {created}"));
     }
 
+    // GitHub reports `public_repos`/`followers`; Gitea uses `followers_count`
+    // and has no repo count on the user object.
+    if let Some(repos) = user.get("public_repos").and_then(|v| v.as_i64()) {
+        parts.push(format!("**Public repos:** {repos}"));
+    }
+
+    if let Some(followers) = user
+        .get("followers")
+        .or_else(|| user.get("followers_count"))
+        .and_then(|v| v.as_i64())
+    {
+        parts.push(format!("**Followers:** {followers}"));
+    }
+
     Ok(CallToolResult::success(vec![Content::text(
         parts.join("\n"),
     )]))
 }
+
+pub async fn user_repos(client: &dyn GitClient, params: UserReposParams) -> Result<CallToolResult> {
+    let query = QueryBuilder::for_client(client).page(params.page).limit(params.limit).build();
+    let val = client
+        .get_json_with_query(&format!("/users/{}/repos", params.username), &query_refs(&query))
+        .await?;
+    let repos = val.as_array().cloned().unwrap_or_default();
+
+    if repos.is_empty() {
+        return Ok(CallToolResult::success(vec![Content::text(
+            "No repositories found.",
+        )]));
+    }
+
+    let formatted: Vec<String> = repos.iter().map(response::format_repo_line).collect();
+
+    Ok(CallToolResult::success(vec![Content::text(
+        formatted.join("\n"),
+    )]))
+}
+
+pub async fn user_starred(client: &dyn GitClient, params: UserStarredParams) -> Result<CallToolResult> {
+    let query = QueryBuilder::for_client(client).page(params.page).limit(params.limit).build();
+    let val = client
+        .get_json_with_query("/user/starred", &query_refs(&query))
+        .await?;
+    let repos = val.as_array().cloned().unwrap_or_default();
+
+    if repos.is_empty() {
+        return Ok(CallToolResult::success(vec![Content::text(
+            "No starred repositories found.",
+        )]));
+    }
+
+    let formatted: Vec<String> = repos.iter().map(response::format_repo_line).collect();
+
+    Ok(CallToolResult::success(vec![Content::text(
+        formatted.join("\n"),
+    )]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rate_limit_shows_budget_and_reset() {
+        let rate = serde_json::json!({ "limit": 5000, "remaining": 4321, "reset": 1_714_557_600 });
+        let line = format_rate_limit(&rate).unwrap();
+        assert!(line.starts_with("**Rate limit:** 4321/5000 requests remaining, resets at 2024-05-01T10:00:00Z ("));
+        assert!(line.ends_with("ago)"));
+        assert!(format_rate_limit(&serde_json::json!({})).is_none());
+    }
+}
@@ -6,6 +6,7 @@ use crate::client::GitClient;
 use crate::error::Result;
 use crate::repo_resolver::RepoInfo;
 use crate::server::resolve_owner_repo;
+use crate::types::common::{query_refs, QueryBuilder};
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct WikiListParams {
@@ -15,6 +16,8 @@ pub struct WikiListParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
     /// Page number (1-based). Defaults to 1.
     pub page: Option<i64>,
     /// Items per page (max 50). Defaults to 20.
@@ -29,6 +32,8 @@ pub struct WikiGetParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
     /// Wiki page slug (URL-encoded page name).
     pub slug: String,
 }
@@ -41,6 +46,8 @@ pub struct WikiCreateParams {
     pub repo: Option<String>,
     /// Local directory to auto-detect owner/repo from .git/config.
     pub directory: Option<String>,
+    /// Git remote to read when auto-detecting (defaults to "origin", else the first remote).
+    pub remote: Option<String>,
     /// Wiki page title.
     pub title: String,
     /// Wiki page content in markdown.
@@ -54,14 +61,10 @@ pub async fn wiki_list(client: &dyn GitClient, params: WikiListParams, default_r
         )]));
     }
 
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
-    let mut query: Vec<(&str, String)> = Vec::new();
-    query.push(("page", params.page.unwrap_or(1).to_string()));
-    query.push(("limit", params.limit.unwrap_or(20).min(50).to_string()));
-
-    let query_refs: Vec<(&str, &str)> = query.iter().map(|(k, v)| (*k, v.as_str())).collect();
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
+    let query = QueryBuilder::for_client(client).page(params.page).limit(params.limit).build();
     let val = match client
-        .get_json_with_query(&format!("/repos/{owner}/{repo}/wiki/pages"), &query_refs)
+        .get_json_with_query(&format!("/repos/{owner}/{repo}/wiki/pages"), &query_refs(&query))
         .await
     {
         Ok(v) => v,
@@ -101,7 +104,7 @@ pub async fn wiki_get(client: &dyn GitClient, params: WikiGetParams, default_rep
         )]));
     }
 
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
     let page = client
         .get_json(&format!(
             "/repos/{owner}/{repo}/wiki/page/{}",
@@ -146,7 +149,7 @@ pub async fn wiki_create(
         )]));
     }
 
-    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, default_repo)?;
+    let (owner, repo) = resolve_owner_repo(&params.owner, &params.repo, &params.directory, &params.remote, default_repo, client.settings().strict_repo)?;
     use base64::Engine;
     let encoded = base64::engine::general_purpose::STANDARD.encode(params.content.as_bytes());
     let body = serde_json::json!({
@@ -1,6 +1,49 @@
+use std::time::Duration;
+
 use schemars::JsonSchema;
 use serde::Deserialize;
 
+use crate::client::GitClient;
+use crate::error::{GitxError, Result};
+use crate::platform::Platform;
+
+/// Page size used when a list tool is called without `limit`, unless `GITX_DEFAULT_LIMIT` is set.
+pub const DEFAULT_PAGE_LIMIT: i64 = 20;
+
+/// Largest page size a list tool will request, unless `GITX_MAX_LIMIT` is set.
+pub const MAX_PAGE_LIMIT: i64 = 50;
+
+/// Seconds an API request may take before it is abandoned, unless `GITX_REQUEST_TIMEOUT` is set.
+pub const DEFAULT_REQUEST_TIMEOUT_SECS: u64 = 30;
+
+/// Largest `timeout_secs` a tool call may ask for.
+pub const MAX_TIMEOUT_SECS: u64 = 600;
+
+/// Default and maximum page sizes for list tools (`GITX_DEFAULT_LIMIT` / `GITX_MAX_LIMIT`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageLimits {
+    pub default: i64,
+    pub max: i64,
+}
+
+impl PageLimits {
+    /// A default above the maximum is lowered to it.
+    pub fn new(default: i64, max: i64) -> Self {
+        Self { default: default.min(max), max }
+    }
+
+    /// Resolve a requested page size: the default when unset, capped at the maximum.
+    pub fn resolve(self, limit: Option<i64>) -> i64 {
+        limit.unwrap_or(self.default).min(self.max)
+    }
+}
+
+impl Default for PageLimits {
+    fn default() -> Self {
+        Self::new(DEFAULT_PAGE_LIMIT, MAX_PAGE_LIMIT)
+    }
+}
+
 /// Parameters identifying a repository, shared by most tools.
 /// Either provide `owner` + `repo`, or `directory` to auto-detect from `.git/config`.
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -23,13 +66,165 @@ pub struct PaginationParams {
 }
 
 impl PaginationParams {
-    pub fn to_query(&self) -> Vec<(&str, String)> {
-        let mut q = Vec::new();
-        if let Some(page) = self.page {
-            q.push(("page", page.to_string()));
+    pub fn to_query(&self, platform: Platform, limits: PageLimits) -> Vec<(&'static str, String)> {
+        QueryBuilder::new(platform, limits).page(self.page).limit(self.limit).build()
+    }
+}
+
+/// Builds the query string for a list request.
+///
+/// The page size goes out as `limit` on Gitea and `per_page` elsewhere, and is
+/// always resolved through [`PageLimits::resolve`] so every tool clamps it the same way.
+#[derive(Debug)]
+pub struct QueryBuilder {
+    platform: Platform,
+    limits: PageLimits,
+    pairs: Vec<(&'static str, String)>,
+}
+
+impl QueryBuilder {
+    pub fn new(platform: Platform, limits: PageLimits) -> Self {
+        Self { platform, limits, pairs: Vec::new() }
+    }
+
+    /// A builder for `client`'s platform and configured page limits.
+    pub fn for_client(client: &dyn GitClient) -> Self {
+        Self::new(client.platform(), client.settings().page_limits)
+    }
+
+    /// Add `key=value`.
+    pub fn param(mut self, key: &'static str, value: impl ToString) -> Self {
+        self.pairs.push((key, value.to_string()));
+        self
+    }
+
+    /// Add `key=value` only when `value` is set.
+    pub fn opt(self, key: &'static str, value: Option<impl ToString>) -> Self {
+        match value {
+            Some(value) => self.param(key, value),
+            None => self,
         }
-        let limit = self.limit.unwrap_or(20).min(50);
-        q.push(("limit", limit.to_string()));
-        q
+    }
+
+    /// Add the 1-based page number, defaulting to the first page.
+    pub fn page(self, page: Option<i64>) -> Self {
+        self.param("page", page.unwrap_or(1))
+    }
+
+    /// Add the page size under the platform's parameter name.
+    pub fn limit(self, limit: Option<i64>) -> Self {
+        let key = match self.platform {
+            Platform::Gitea => "limit",
+            Platform::GitHub | Platform::GitLab => "per_page",
+        };
+        let limit = self.limits.resolve(limit);
+        self.param(key, limit)
+    }
+
+    pub fn build(self) -> Vec<(&'static str, String)> {
+        self.pairs
+    }
+}
+
+/// Check `sort` against the keys a list tool supports and `direction` against `asc`/`desc`.
+pub fn validate_sort(sort: Option<&str>, direction: Option<&str>, allowed: &[&str]) -> Result<()> {
+    if let Some(sort) = sort.filter(|s| !allowed.contains(s)) {
+        return Err(GitxError::InvalidParam(format!(
+            "sort must be one of: {} (got \"{sort}\")",
+            allowed.join(", ")
+        )));
+    }
+    if let Some(direction) = direction.filter(|d| !matches!(*d, "asc" | "desc")) {
+        return Err(GitxError::InvalidParam(format!(
+            "direction must be asc or desc (got \"{direction}\")"
+        )));
+    }
+    Ok(())
+}
+
+/// Borrow a built query as the `&[(&str, &str)]` slice [`crate::client::GitClient`] expects.
+pub fn query_refs<'a>(query: &'a [(&'static str, String)]) -> Vec<(&'a str, &'a str)> {
+    query.iter().map(|(k, v)| (*k, v.as_str())).collect()
+}
+
+/// Turn a tool's `timeout_secs` into a per-request timeout; `None` keeps the client's default.
+pub fn request_timeout(timeout_secs: Option<u64>) -> Result<Option<Duration>> {
+    match timeout_secs {
+        Some(secs) if secs == 0 || secs > MAX_TIMEOUT_SECS => Err(GitxError::InvalidParam(format!(
+            "timeout_secs must be between 1 and {MAX_TIMEOUT_SECS}"
+        ))),
+        secs => Ok(secs.map(Duration::from_secs)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn page_limit_defaults_and_caps() {
+        let limits = PageLimits::default();
+        assert_eq!(limits.resolve(None), DEFAULT_PAGE_LIMIT);
+        assert_eq!(limits.resolve(Some(5)), 5);
+        assert_eq!(limits.resolve(Some(500)), MAX_PAGE_LIMIT);
+    }
+
+    #[test]
+    fn page_limits_follow_the_configured_values() {
+        let limits = PageLimits::new(10, 100);
+        assert_eq!(limits.resolve(None), 10);
+        assert_eq!(limits.resolve(Some(80)), 80);
+        assert_eq!(PageLimits::new(80, 40).resolve(None), 40);
+    }
+
+    #[test]
+    fn query_builder_uses_platform_limit_key() {
+        let query = QueryBuilder::new(Platform::Gitea, PageLimits::default()).page(None).limit(Some(10)).build();
+        assert_eq!(query, vec![("page", "1".to_string()), ("limit", "10".to_string())]);
+
+        let query = QueryBuilder::new(Platform::GitHub, PageLimits::default()).page(Some(3)).limit(Some(500)).build();
+        assert_eq!(query, vec![("page", "3".to_string()), ("per_page", MAX_PAGE_LIMIT.to_string())]);
+    }
+
+    #[test]
+    fn query_builder_skips_unset_optionals() {
+        let query = QueryBuilder::new(Platform::Gitea, PageLimits::default())
+            .param("state", "open")
+            .opt("labels", Some("bug"))
+            .opt("milestones", None::<&str>)
+            .build();
+        assert_eq!(query, vec![("state", "open".to_string()), ("labels", "bug".to_string())]);
+        assert_eq!(query_refs(&query), vec![("state", "open"), ("labels", "bug")]);
+    }
+
+    #[test]
+    fn validate_sort_rejects_unknown_keys() {
+        let allowed = &["created", "updated"];
+        assert!(validate_sort(None, None, allowed).is_ok());
+        assert!(validate_sort(Some("updated"), Some("asc"), allowed).is_ok());
+        let err = validate_sort(Some("stars"), None, allowed).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid parameter: sort must be one of: created, updated (got \"stars\")");
+        assert!(validate_sort(None, Some("up"), allowed).is_err());
+    }
+
+    #[test]
+    fn request_timeout_rejects_out_of_range() {
+        assert_eq!(request_timeout(None).unwrap(), None);
+        assert_eq!(request_timeout(Some(90)).unwrap(), Some(Duration::from_secs(90)));
+        assert_eq!(
+            request_timeout(Some(0)).unwrap_err().to_string(),
+            "Invalid parameter: timeout_secs must be between 1 and 600"
+        );
+        assert!(request_timeout(Some(MAX_TIMEOUT_SECS)).is_ok());
+        assert!(request_timeout(Some(MAX_TIMEOUT_SECS + 1)).is_err());
+    }
+
+    #[test]
+    fn pagination_params_to_query() {
+        let params = PaginationParams { page: Some(2), limit: None };
+        assert_eq!(
+            params.to_query(Platform::GitLab, PageLimits::default()),
+            vec![("page", "2".to_string()), ("per_page", DEFAULT_PAGE_LIMIT.to_string())]
+        );
     }
 }